@@ -0,0 +1,210 @@
+use crate::{
+    bits::{Bits, BitsSpan},
+    utils::{const_eval_tokens, parse_braces, parse_brackets, parse_terminated},
+};
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream, Result},
+    punctuated::Punctuated,
+    token, Error, Ident, LitInt, Token, Type,
+};
+
+mod kw {
+    syn::custom_keyword!(nested);
+
+    syn::custom_keyword!(read_only);
+    syn::custom_keyword!(ro);
+    syn::custom_keyword!(write_only);
+    syn::custom_keyword!(wo);
+}
+
+struct FieldAssertion {
+    ident: Ident,
+    is_nested: bool,
+    array_len: Option<(usize, Span)>,
+    is_readable: bool,
+    is_writable: bool,
+    bits: Bits,
+}
+
+struct LayoutAssertion {
+    ty: Type,
+    fields: Punctuated<FieldAssertion, Token![,]>,
+}
+
+impl Parse for FieldAssertion {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let is_nested = input.parse::<kw::nested>().is_ok();
+        let ident = input.parse()?;
+
+        let array_len = if input.peek(token::Bracket)
+            && input
+                .fork()
+                .call(parse_brackets)
+                .is_ok_and(|content| content.peek(LitInt))
+        {
+            let content = parse_brackets(input)?;
+            let len_lit = content.parse::<LitInt>()?;
+            if !content.is_empty() {
+                return Err(content.error("unexpected extra tokens"));
+            }
+            let len = len_lit.base10_parse::<usize>()?;
+            if is_nested {
+                return Err(Error::new(
+                    len_lit.span(),
+                    "nested fields can't be field arrays",
+                ));
+            }
+            Some((len, len_lit.span()))
+        } else {
+            None
+        };
+
+        let mut is_readable = true;
+        let mut is_writable = true;
+        if input.peek(token::Bracket) {
+            let options_content = parse_brackets(input)?;
+            while !options_content.is_empty() {
+                let lookahead = options_content.lookahead1();
+                if lookahead.peek(kw::read_only) || lookahead.peek(kw::ro) {
+                    let span = options_content
+                        .parse::<kw::read_only>()
+                        .map(|kw| kw.span)
+                        .or_else(|_| options_content.parse::<kw::ro>().map(|kw| kw.span))?;
+                    if !is_readable || !is_writable {
+                        return Err(Error::new(span, "Conflicting access restrictions"));
+                    }
+                    is_writable = false;
+                } else if lookahead.peek(kw::write_only) || lookahead.peek(kw::wo) {
+                    let span = options_content
+                        .parse::<kw::write_only>()
+                        .map(|kw| kw.span)
+                        .or_else(|_| options_content.parse::<kw::wo>().map(|kw| kw.span))?;
+                    if !is_readable || !is_writable {
+                        return Err(Error::new(span, "Conflicting access restrictions"));
+                    }
+                    is_readable = false;
+                } else {
+                    return Err(lookahead.error());
+                }
+
+                let had_comma = options_content.parse::<Token![,]>().is_ok();
+                if !options_content.is_empty() && !had_comma {
+                    return Err(options_content.error("expected comma between field options"));
+                }
+            }
+        }
+
+        input.parse::<Token![@]>()?;
+        let bits = input.parse()?;
+
+        Ok(FieldAssertion {
+            ident,
+            is_nested,
+            array_len,
+            is_readable,
+            is_writable,
+            bits,
+        })
+    }
+}
+
+impl Parse for LayoutAssertion {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ty = input.parse()?;
+        let content = parse_braces(input)?;
+        let fields = parse_terminated(&content, FieldAssertion::parse)?;
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after layout assertion"));
+        }
+        Ok(LayoutAssertion { ty, fields })
+    }
+}
+
+/// Describes a resolved bit span for error messages, if its bounds are known at expansion time.
+fn describe_bits(start: &proc_macro2::TokenStream, end: &proc_macro2::TokenStream) -> String {
+    match (const_eval_tokens(start), const_eval_tokens(end)) {
+        (Some(start), Some(end)) => format!("bits {start}..{end}"),
+        _ => "the asserted bits".to_string(),
+    }
+}
+
+pub fn assert_layout(input: TokenStream) -> TokenStream {
+    let LayoutAssertion { ty, fields } = syn::parse_macro_input!(input);
+
+    let ty_name = quote! { #ty }.to_string().replace(' ', "");
+    let full_bits = quote! {
+        ::core::mem::size_of::<<#ty as ::proc_bitfield::Bitfield>::Storage>() << 3
+    };
+
+    let mut last_bits_span = None;
+    let checks = fields
+        .into_iter()
+        .map(|field| {
+            let FieldAssertion {
+                ident,
+                is_nested,
+                array_len,
+                is_readable,
+                is_writable,
+                bits,
+            } = field;
+            let (bits_span, _) = match bits.into_spans(last_bits_span.as_ref(), array_len) {
+                Ok(spans) => spans,
+                Err(err) => return err.to_compile_error(),
+            };
+            last_bits_span = Some(bits_span.clone());
+            let (start, end) = bits_span.to_start_end_or_full(&full_bits);
+            let len = array_len.map_or(1, |(len, _)| len);
+
+            let layout_ident = format_ident!("__layout_{}", ident);
+            let name = format!("field `{ident}` of `{ty_name}`");
+            let bits_msg = match array_len {
+                Some((len, _)) => format!(
+                    "{name} isn't at {} as a {len}-element field array",
+                    describe_bits(&start, &end)
+                ),
+                None if matches!(bits_span, BitsSpan::Full) => {
+                    format!("{name} doesn't span the full storage type")
+                }
+                None => format!("{name} isn't at {}", describe_bits(&start, &end)),
+            };
+            let nested_msg = if is_nested {
+                format!("{name} isn't a nested bitfield field")
+            } else {
+                format!("{name} is a nested bitfield field")
+            };
+            let access_msg = format!(
+                "{name} isn't {}",
+                match (is_readable, is_writable) {
+                    (true, true) => "readable and writable",
+                    (true, false) => "read-only",
+                    _ => "write-only",
+                }
+            );
+
+            quote_spanned! {
+                ident.span() =>
+                const _: () = {
+                    let layout = <#ty>::#layout_ident;
+                    ::core::assert!(
+                        layout.start == (#start) && layout.end == (#end) && layout.len == #len,
+                        #bits_msg,
+                    );
+                    ::core::assert!(layout.is_nested == #is_nested, #nested_msg);
+                    ::core::assert!(
+                        layout.is_readable == #is_readable && layout.is_writable == #is_writable,
+                        #access_msg,
+                    );
+                };
+            }
+        })
+        .collect::<Vec<_>>();
+
+    quote! {
+        #(#checks)*
+    }
+    .into()
+}
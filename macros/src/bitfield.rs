@@ -3,8 +3,8 @@ use crate::utils::{add_const_bounds, const_param, doc_hidden, min, sized_pred, t
 use crate::{
     bits::{Bits, BitsSpan},
     utils::{
-        maybe_const_assert, parse_braces, parse_brackets, parse_parens, parse_terminated,
-        MaybeRepeat,
+        atomic_ty, const_eval_tokens, const_size_of, is_int_ty, parse_braces, parse_brackets,
+        parse_parens, parse_terminated, type_name, zero_value,
     },
 };
 use proc_macro::TokenStream;
@@ -14,10 +14,11 @@ use quote::{format_ident, quote, quote_spanned, ToTokens};
 use std::borrow::Cow;
 use syn::{
     parse::{Parse, ParseStream, Result},
+    parse_quote,
     punctuated::Punctuated,
     spanned::Spanned,
-    token, Attribute, Error, Expr, ExprParen, ExprPath, Generics, Ident, Token, Type, TypeParam,
-    Visibility, WhereClause,
+    token, Attribute, Error, Expr, ExprParen, ExprPath, GenericParam, Generics, Ident, LitInt,
+    Token, Type, TypeParam, Visibility, WhereClause,
 };
 #[cfg(feature = "gce")]
 use syn::{
@@ -28,6 +29,9 @@ use syn::{
 mod kw {
     syn::custom_keyword!(nested);
 
+    syn::custom_keyword!(read);
+    syn::custom_keyword!(write);
+
     syn::custom_keyword!(get);
     syn::custom_keyword!(set);
 
@@ -63,10 +67,35 @@ mod kw {
 
     syn::custom_keyword!(no_const);
 
+    syn::custom_keyword!(w1c);
+    syn::custom_keyword!(w1s);
+    syn::custom_keyword!(rc);
+
+    syn::custom_keyword!(reserved);
+    syn::custom_keyword!(fixed);
+
+    syn::custom_keyword!(alias);
+
     syn::custom_keyword!(Debug);
     syn::custom_keyword!(FromStorage);
     syn::custom_keyword!(IntoStorage);
     syn::custom_keyword!(DerefStorage);
+    syn::custom_keyword!(Bytes);
+    syn::custom_keyword!(Volatile);
+    syn::custom_keyword!(Atomic);
+    syn::custom_keyword!(Cell);
+    syn::custom_keyword!(Views);
+    syn::custom_keyword!(Msb0);
+    syn::custom_keyword!(WriteZero);
+    syn::custom_keyword!(FieldConsts);
+    syn::custom_keyword!(TryFromStorage);
+    syn::custom_keyword!(Exhaustive);
+    syn::custom_keyword!(Reflect);
+    syn::custom_keyword!(Descriptors);
+    syn::custom_keyword!(Unpacked);
+    syn::custom_keyword!(New);
+    syn::custom_keyword!(Builder);
+    syn::custom_keyword!(Default);
 }
 
 fn parse_accessor_fn(input: ParseStream) -> Result<Expr> {
@@ -76,6 +105,7 @@ fn parse_accessor_fn(input: ParseStream) -> Result<Expr> {
         .or_else(|_| input.parse::<ExprPath>().map(Expr::Path))
 }
 
+#[allow(clippy::large_enum_variant)]
 enum AccessorKind {
     Default,
     ConvTy(Type),
@@ -125,9 +155,18 @@ impl AccessorKind {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SideEffect {
+    None,
+    WriteOneToClear,
+    WriteOneToSet,
+    ReadToClear,
+}
+
 struct SingleField {
     get_kind: AccessorKind,
     set_kind: AccessorKind,
+    side_effect: SideEffect,
 }
 
 struct NestedField {
@@ -135,9 +174,25 @@ struct NestedField {
     is_writable: bool,
 }
 
+struct ReservedField {
+    fixed_value: Option<Expr>,
+}
+
+#[allow(clippy::large_enum_variant)]
 enum FieldContent {
     Single(SingleField),
     Nested(NestedField),
+    Reserved(ReservedField),
+}
+
+struct SetConversion<'a> {
+    input_ty: &'a Type,
+    raw_value: proc_macro2::TokenStream,
+    set_ok: proc_macro2::TokenStream,
+    set_output_ty: proc_macro2::TokenStream,
+    with_ok: proc_macro2::TokenStream,
+    with_output_ty: proc_macro2::TokenStream,
+    is_fallible: bool,
 }
 
 struct Field {
@@ -146,9 +201,12 @@ struct Field {
     ident: Ident,
     bits: Bits,
     ty: Type,
+    array_len: Option<(usize, Span)>,
     content: FieldContent,
+    is_alias: bool,
+    union_group: Option<usize>,
+    default: Option<Expr>,
     // TODO: Allow specifying constness for getters and setters separately?
-    #[cfg(feature = "nightly")]
     uses_const_fns: bool,
 }
 
@@ -157,69 +215,430 @@ impl Field {
         match &self.content {
             FieldContent::Single(content) => !matches!(content.get_kind, AccessorKind::Disabled),
             FieldContent::Nested(content) => content.is_readable,
+            FieldContent::Reserved(_) => false,
         }
     }
 
     fn has_const_getter(&self) -> bool {
-        #[cfg(feature = "nightly")]
-        {
-            self.uses_const_fns
-        }
-        #[cfg(not(feature = "nightly"))]
-        false
+        self.uses_const_fns
     }
 
     fn has_const_setter(&self) -> bool {
-        #[cfg(feature = "nightly")]
-        {
-            self.uses_const_fns
+        self.uses_const_fns
+    }
+
+    /// Returns whether the field's accessors can be `const fn`s without the `nightly` feature,
+    /// i.e. whether they can be implemented by shifting and masking the storage directly, and only
+    /// call conversion functions that may themselves be `const fn`s.
+    fn has_stable_const_accessors(&self, storage_ty: &Type) -> bool {
+        let FieldContent::Single(SingleField {
+            get_kind, set_kind, ..
+        }) = &self.content
+        else {
+            return self.is_reserved();
+        };
+        self.uses_direct_bits(storage_ty)
+            && matches!(
+                get_kind,
+                AccessorKind::Default
+                    | AccessorKind::ConvFn { .. }
+                    | AccessorKind::UnsafeConvFn { .. }
+                    | AccessorKind::TryGetFn { .. }
+                    | AccessorKind::Disabled
+            )
+            && matches!(
+                set_kind,
+                AccessorKind::Default
+                    | AccessorKind::ConvFn { .. }
+                    | AccessorKind::UnsafeConvFn { .. }
+                    | AccessorKind::Disabled
+            )
+    }
+
+    /// Returns whether the field's bits are read and written by shifting and masking the storage
+    /// directly instead of going through the `Bit(s)`, `WithBit(s)` and `SetBit(s)` traits, which
+    /// is possible for integer and `bool` fields of bitfields with builtin integer storage.
+    fn uses_direct_bits(&self, storage_ty: &Type) -> bool {
+        is_int_ty(storage_ty)
+            && (is_int_ty(&self.ty)
+                || matches!(&self.ty, Type::Path(ty) if ty.path.is_ident("bool")))
+    }
+
+    /// Returns the `inline` attribute for the field's main accessors, which are always inlined when
+    /// they shift and mask the storage directly.
+    fn inline_attr(&self, storage_ty: &Type) -> proc_macro2::TokenStream {
+        if self.uses_direct_bits(storage_ty) {
+            quote! { #[inline(always)] }
+        } else {
+            quote! { #[inline] }
         }
-        #[cfg(not(feature = "nightly"))]
-        false
     }
 
     fn has_unsafe_getter(&self) -> bool {
         match &self.content {
             FieldContent::Single(content) => content.get_kind.is_unsafe(),
-            FieldContent::Nested(_) => false,
+            FieldContent::Nested(_) | FieldContent::Reserved(_) => false,
+        }
+    }
+
+    fn side_effect(&self) -> SideEffect {
+        match &self.content {
+            FieldContent::Single(content) => content.side_effect,
+            FieldContent::Nested(_) | FieldContent::Reserved(_) => SideEffect::None,
+        }
+    }
+
+    fn is_reserved(&self) -> bool {
+        matches!(self.content, FieldContent::Reserved(_))
+    }
+
+    fn is_writable(&self) -> bool {
+        match &self.content {
+            FieldContent::Single(content) => !matches!(content.set_kind, AccessorKind::Disabled),
+            FieldContent::Nested(content) => content.is_writable,
+            FieldContent::Reserved(_) => false,
+        }
+    }
+
+    fn is_plain_writable(&self) -> bool {
+        self.is_writable() && self.side_effect() == SideEffect::None
+    }
+
+    /// Returns the identifiers of the field's `set_*` and `with_*` functions, which are named
+    /// `clear_*` and `with_clear_*` for write-1-to-clear fields.
+    fn setter_idents(&self) -> (Ident, Ident) {
+        let ident = &self.ident;
+        if self.side_effect() == SideEffect::WriteOneToClear {
+            (
+                format_ident!("clear_{}", ident),
+                format_ident!("with_clear_{}", ident),
+            )
+        } else {
+            (
+                format_ident!("set_{}", ident),
+                format_ident!("with_{}", ident),
+            )
+        }
+    }
+
+    /// Returns whether the field's setters take a value; single-bit write-1-to-clear fields
+    /// without conversions are cleared without one.
+    fn setters_take_value(&self) -> bool {
+        match &self.content {
+            FieldContent::Single(SingleField {
+                set_kind: AccessorKind::Default,
+                side_effect: SideEffect::WriteOneToClear,
+                ..
+            }) => !matches!(&self.ty, Type::Path(ty) if ty.path.is_ident("bool")),
+            _ => true,
+        }
+    }
+
+    /// Returns the storage expression that `with_*` setters should modify, along with the
+    /// statements that `with_*` and `set_*` setters should run before modifying it.
+    ///
+    /// Setters for fields without side effects zero (or, with `WriteZero`, start from zero for)
+    /// every bit whose write would have side effects, so that values read from the hardware can be
+    /// modified and written back safely; all setters also force reserved and fixed bits to their
+    /// values.
+    fn sanitize_stmts(
+        &self,
+        storage: &proc_macro2::TokenStream,
+        sanitizes: bool,
+        fixes: bool,
+    ) -> (
+        proc_macro2::TokenStream,
+        proc_macro2::TokenStream,
+        proc_macro2::TokenStream,
+    ) {
+        let sanitize_fn = if sanitizes && self.side_effect() == SideEffect::None {
+            quote! { __sanitize_storage }
+        } else if fixes {
+            quote! { __fix_storage }
+        } else {
+            return (storage.clone(), quote! {}, quote! {});
+        };
+        (
+            quote! { storage },
+            quote! {
+                let mut storage = #storage;
+                Self::#sanitize_fn(&mut storage);
+            },
+            quote! { Self::#sanitize_fn(&mut #storage); },
+        )
+    }
+
+    fn bits_spans(&self, last: Option<&BitsSpan>) -> Result<(BitsSpan, Option<Vec<BitsSpan>>)> {
+        self.bits.clone().into_spans(last, self.array_len)
+    }
+
+    /// Returns the identifier of the field's associated constant with the given suffix, i.e.
+    /// `X_MASK` for `MASK`.
+    fn layout_const_ident(&self, suffix: &str) -> Ident {
+        let name = self.ident.to_string();
+        let name = name.trim_start_matches("r#");
+        Ident::new(
+            &format!("{}_{suffix}", name.to_uppercase()),
+            self.ident.span(),
+        )
+    }
+
+    /// Returns the field's `X_MASK` (for builtin integer storage types), `X_SHIFT`, `X_RANGE`,
+    /// `X_BITS` and (for field arrays) `X_LEN` associated constants, describing its position in
+    /// the storage type with LSB-0 numbering.
+    fn layout_consts(
+        &self,
+        bits_span: &BitsSpan,
+        element_spans: Option<&[BitsSpan]>,
+        storage_ty: &Type,
+        full_bits: &proc_macro2::TokenStream,
+        has_masks: bool,
+    ) -> proc_macro2::TokenStream {
+        let Field { vis, ident, .. } = self;
+        let name = ident.to_string();
+        let name = name.trim_start_matches("r#");
+        let const_ident = |suffix: &str| self.layout_const_ident(suffix);
+        let (mask_ident, shift_ident, range_ident, bits_ident, len_ident) = (
+            const_ident("MASK"),
+            const_ident("SHIFT"),
+            const_ident("RANGE"),
+            const_ident("BITS"),
+            const_ident("LEN"),
+        );
+
+        let (start, end) = bits_span.to_start_end_or_full(full_bits);
+        let (start, end) = (fold_const(&start), fold_const(&end));
+        let mask = has_masks.then(|| {
+            let mask = span_mask(bits_span, storage_ty);
+            let doc = format!("A mask of the bits of the `{name}` field in the storage type.");
+            quote! {
+                #[doc = #doc]
+                #vis const #mask_ident: #storage_ty = #mask;
+            }
+        });
+        let (element_start, element_end) = element_spans
+            .map_or(bits_span, |spans| &spans[0])
+            .to_start_end_or_full(full_bits);
+        let bits = fold_const(&quote! { (#element_end) - (#element_start) });
+        let len = self.array_len.map(|(len, _)| {
+            let doc = format!("The number of elements in the `{name}` field array.");
+            quote! {
+                #[doc = #doc]
+                #vis const #len_ident: usize = #len;
+            }
+        });
+
+        let shift_doc = format!("The index of the lowest bit of the `{name}` field.");
+        let range_doc = format!("The range of bits occupied by the `{name}` field.");
+        let bits_doc = if self.array_len.is_some() {
+            format!("The width in bits of each element of the `{name}` field array.")
+        } else {
+            format!("The width in bits of the `{name}` field.")
+        };
+        quote! {
+            #mask
+
+            #[doc = #shift_doc]
+            #vis const #shift_ident: usize = #start;
+
+            #[doc = #range_doc]
+            #vis const #range_ident: ::core::ops::Range<usize> = #start..#end;
+
+            #[doc = #bits_doc]
+            #vis const #bits_ident: usize = #bits;
+
+            #len
+        }
+    }
+
+    /// Returns the type of the values taken and returned by the field's descriptor, if it can have
+    /// one: only single readable and writable fields without side effects, whose accessors are safe
+    /// and infallible and use the same type, have descriptors.
+    fn descriptor_value_ty(&self) -> Option<proc_macro2::TokenStream> {
+        if self.array_len.is_some() || !self.is_readable() || !self.is_plain_writable() {
+            return None;
+        }
+        match &self.content {
+            FieldContent::Single(SingleField {
+                get_kind, set_kind, ..
+            }) => {
+                if get_kind.is_unsafe()
+                    || set_kind.is_unsafe()
+                    || matches!(
+                        get_kind,
+                        AccessorKind::TryConvTy(_) | AccessorKind::TryGetFn { .. }
+                    )
+                {
+                    return None;
+                }
+                let (_, output_ty) = self.get_conversion(get_kind);
+                let SetConversion {
+                    input_ty,
+                    is_fallible,
+                    ..
+                } = self.set_conversion(set_kind);
+                let input_ty = input_ty.to_token_stream();
+                (!is_fallible && output_ty.to_string() == input_ty.to_string()).then_some(input_ty)
+            }
+            FieldContent::Nested(_) => Some(self.ty.to_token_stream()),
+            FieldContent::Reserved(_) => None,
+        }
+    }
+
+    /// Returns the type of the value that constructors and builders take for the field, along with
+    /// whether setting it is fallible and unsafe, if it's a writable field without side effects.
+    fn init_value(&self) -> Option<(proc_macro2::TokenStream, bool, bool)> {
+        if !self.is_plain_writable() {
+            return None;
+        }
+        let (value_ty, is_fallible, is_unsafe) = match &self.content {
+            FieldContent::Single(SingleField { set_kind, .. }) => {
+                let SetConversion {
+                    input_ty,
+                    is_fallible,
+                    ..
+                } = self.set_conversion(set_kind);
+                (
+                    input_ty.to_token_stream(),
+                    is_fallible,
+                    set_kind.is_unsafe(),
+                )
+            }
+            FieldContent::Nested(_) => (self.ty.to_token_stream(), false, false),
+            FieldContent::Reserved(_) => return None,
+        };
+        let value_ty = match self.array_len {
+            Some((len, _)) => quote! { [#value_ty; #len] },
+            None => value_ty,
+        };
+        Some((value_ty, is_fallible, is_unsafe))
+    }
+
+    /// Returns the statements setting the field to `value` in `__bitfield` through its `with_*`
+    /// setter, evaluating `fail` with a `FieldConversionError` named `error` if it's fallible and
+    /// fails.
+    fn init_stmts(
+        &self,
+        value: &proc_macro2::TokenStream,
+        is_fallible: bool,
+        is_unsafe: bool,
+        fail: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let name = self.ident.to_string();
+        let name = name.trim_start_matches("r#");
+        let (_, with_fn_ident) = self.setter_idents();
+        let unsafe_ = is_unsafe.then(|| quote! { unsafe });
+        let with = |args: proc_macro2::TokenStream| {
+            if is_fallible {
+                quote! {
+                    let __bitfield = match ::proc_bitfield::__private::IntoOption::into_option(
+                        #unsafe_ { __bitfield.#with_fn_ident(#args) }
+                    ) {
+                        ::core::option::Option::Some(bitfield) => bitfield,
+                        ::core::option::Option::None => {
+                            let error = ::proc_bitfield::FieldConversionError::new(#name);
+                            #fail
+                        }
+                    };
+                }
+            } else {
+                quote! { let __bitfield = #unsafe_ { __bitfield.#with_fn_ident(#args) }; }
+            }
+        };
+        match self.array_len {
+            Some((len, _)) => {
+                let elements = (0..len)
+                    .map(|i| format_ident!("__element_{}", i))
+                    .collect::<Vec<_>>();
+                let withs = elements
+                    .iter()
+                    .enumerate()
+                    .map(|(i, element)| with(quote! { #i, #element }));
+                quote! {
+                    let [#(#elements),*] = #value;
+                    #(#withs)*
+                }
+            }
+            None => with(value.clone()),
         }
     }
 
-    fn asserts(
+    fn out_of_bounds_msg(&self) -> String {
+        format!("index out of bounds for field array `{}`", self.ident)
+    }
+
+    /// Returns an associated constant asserting that the field's bit range is valid, along with
+    /// the statement that accessors use to reference it, so that the assertions are evaluated once
+    /// at compile time (after monomorphization, for generic bitfields) instead of being repeated in
+    /// every accessor. Both are empty if the assertions can already be checked at expansion time.
+    fn checks(
         &self,
+        index: usize,
         bits_span: &BitsSpan,
+        element_spans: Option<&[BitsSpan]>,
         full_bits: &proc_macro2::TokenStream,
-        full_bits_are_const: bool,
-    ) -> MaybeRepeat {
+    ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
         let Field { ident, ty, .. } = self;
 
         let ty_bits = quote! { ::core::mem::size_of::<#ty>() << 3 };
 
-        let assert = maybe_const_assert(full_bits_are_const);
-        MaybeRepeat::new(
+        // Conditions of the form `lhs < rhs` (or `lhs <= rhs` if not strict)
+        let mut conditions = Vec::new();
+        let mut check_width = |start: &proc_macro2::TokenStream, end: &proc_macro2::TokenStream| {
+            conditions.push((start.clone(), end.clone(), true));
+            conditions.push((quote! { (#end) - (#start) }, ty_bits.clone(), false));
+        };
+        if let Some(element_spans) = element_spans {
+            if let BitsSpan::Range { start, end } = &element_spans[0] {
+                check_width(start, end);
+            }
+            let (start, end) = bits_span.to_start_end_or_full(full_bits);
+            conditions.push((start.into_owned(), full_bits.clone(), true));
+            conditions.push((end.into_owned(), full_bits.clone(), false));
+        } else {
             match &bits_span {
-                BitsSpan::Single(bit) => {
-                    quote_spanned! {
-                        ident.span() =>
-                        #assert((#bit) < (#full_bits));
-                    }
-                }
+                BitsSpan::Single(bit) => conditions.push((bit.clone(), full_bits.clone(), true)),
                 BitsSpan::Range { start, end } => {
-                    quote_spanned! {
-                        ident.span() =>
-                        #assert((#end) > (#start));
-                        #assert((#start) < (#full_bits) && (#end) <= (#full_bits));
-                        #assert((#end) - (#start) <= (#ty_bits));
-                    }
-                }
-                BitsSpan::Full => {
-                    quote_spanned! {
-                        ident.span() =>
-                        #assert((#full_bits) <= (#ty_bits));
-                    }
+                    check_width(start, end);
+                    conditions.push((start.clone(), full_bits.clone(), true));
+                    conditions.push((end.clone(), full_bits.clone(), false));
                 }
+                BitsSpan::Full => conditions.push((full_bits.clone(), ty_bits.clone(), false)),
+            }
+        }
+
+        let hold_at_expansion_time = conditions.iter().all(|(lhs, rhs, strict)| {
+            match (const_eval_tokens(lhs), const_eval_tokens(rhs)) {
+                (Some(lhs), Some(rhs)) => lhs < rhs || (!strict && lhs == rhs),
+                _ => false,
+            }
+        });
+        if hold_at_expansion_time {
+            return (quote! {}, quote! {});
+        }
+
+        let asserts = conditions.iter().map(|(lhs, rhs, strict)| {
+            let op = if *strict {
+                quote! { < }
+            } else {
+                quote! { <= }
+            };
+            quote_spanned! { ident.span() => ::core::assert!((#lhs) #op (#rhs)); }
+        });
+        let check_ident = if self.is_reserved() {
+            format_ident!("__check_reserved_{}", index)
+        } else {
+            format_ident!("__check_{}", ident.to_string().trim_start_matches("r#"))
+        };
+        (
+            quote! {
+                #[doc(hidden)]
+                #[allow(non_upper_case_globals, clippy::identity_op)]
+                const #check_ident: () = {
+                    #(#asserts)*
+                };
             },
-            full_bits_are_const,
+            quote! { let () = Self::#check_ident; },
         )
     }
 
@@ -249,10 +668,9 @@ impl Field {
         }
     }
 
-    fn getters(
+    fn get_raw_value(
         &self,
         bits_span: &BitsSpan,
-        asserts: &MaybeRepeat,
         storage: &proc_macro2::TokenStream,
         storage_ty: &Type,
         full_bits: &proc_macro2::TokenStream,
@@ -260,194 +678,602 @@ impl Field {
             &proc_macro2::TokenStream,
             &proc_macro2::TokenStream,
         )>,
-        #[cfg(feature = "gce")] storage_needs_const_bounds: bool,
-    ) -> Option<proc_macro2::TokenStream> {
+    ) -> proc_macro2::TokenStream {
         let Field {
-            attrs,
-            vis,
             ident,
             ty: field_ty,
-            content,
             ..
         } = self;
 
-        #[allow(unused_mut)]
-        let mut where_clause = WhereClause {
-            where_token: Default::default(),
-            predicates: Default::default(),
-        };
         #[cfg(feature = "gce")]
         if let Some((start_bit, end_bit)) = start_end_bits {
-            Self::add_sized_preds(bits_span, full_bits, start_bit, end_bit, &mut where_clause);
+            return match bits_span {
+                BitsSpan::Single(bit) => {
+                    let bit = quote! { (#start_bit) + (#bit) };
+                    quote_spanned! {
+                        ident.span() =>
+                        if (#bit) < (#end_bit) {
+                            <#storage_ty as ::proc_bitfield::Bit>::bit::<{#bit}>(&#storage)
+                        } else {
+                            false
+                        }
+                    }
+                }
+                BitsSpan::Range { start, end } => {
+                    let start = quote! { (#start_bit) + (#start) };
+                    let end = min(&quote! { (#start_bit) + (#end) }, end_bit);
+                    quote_spanned! {
+                        ident.span() =>
+                        <#storage_ty as ::proc_bitfield::Bits<#field_ty>>
+                            ::bits::<{#start}, {#end}>(&#storage)
+                    }
+                }
+                BitsSpan::Full => {
+                    let end = min(&quote! { (#start_bit) + (#full_bits) }, end_bit);
+                    quote_spanned! {
+                        ident.span() =>
+                        <#storage_ty as ::proc_bitfield::Bits<#field_ty>>
+                            ::bits::<{#start_bit}, {#end}>(&#storage)
+                    }
+                }
+            };
         }
 
-        match content {
-            FieldContent::Single(SingleField { get_kind, .. }) => {
-                if matches!(&get_kind, AccessorKind::Disabled) {
-                    return None;
+        if self.uses_direct_bits(storage_ty) {
+            if let BitsSpan::Single(_) = bits_span {
+                let mask = span_mask(bits_span, storage_ty);
+                return quote! { ((#storage) & #mask) != 0 };
+            }
+            if is_int_ty(field_ty) {
+                // Shift the field's bits to the top of the value and back down to sign-extend
+                // them for signed field types
+                let (start, end) = bits_span.to_start_end_or_full(full_bits);
+                let shift = fold_const(&quote! {
+                    ((::core::mem::size_of::<#field_ty>() << 3) - ((#end) - (#start)))
+                });
+                let start = fold_const(&start);
+                if shift.to_string() == "0" {
+                    return quote! { ((#storage) >> #start) as #field_ty };
                 }
+                return quote! {
+                    (((#storage) >> #start) as #field_ty) << #shift >> #shift
+                };
+            }
+        }
 
-                let (output, output_ty) = match get_kind {
-                    AccessorKind::Default => (quote! { raw_value }, field_ty.to_token_stream()),
+        match bits_span {
+            BitsSpan::Single(bit) => {
+                quote_spanned! {
+                    ident.span() =>
+                    <#storage_ty as ::proc_bitfield::Bit>::bit::<{#bit}>(&#storage)
+                }
+            }
+            BitsSpan::Range { start, end } => {
+                quote_spanned! {
+                    ident.span() =>
+                    <#storage_ty as ::proc_bitfield::Bits<#field_ty>>
+                        ::bits::<{#start}, {#end}>(&#storage)
+                }
+            }
+            BitsSpan::Full => {
+                quote_spanned! {
+                    ident.span() =>
+                    <#storage_ty as ::proc_bitfield::Bits<#field_ty>>
+                        ::bits::<0, {#full_bits}>(&#storage)
+                }
+            }
+        }
+    }
 
-                    AccessorKind::ConvTy(ty) => (
-                        quote! {
-                            <#ty as ::core::convert::From<#field_ty>>::from(raw_value)
+    #[allow(clippy::too_many_arguments)]
+    fn with_set_raw_value(
+        &self,
+        bits_span: &BitsSpan,
+        raw_value: &proc_macro2::TokenStream,
+        storage: &proc_macro2::TokenStream,
+        storage_ty: &Type,
+        full_bits: &proc_macro2::TokenStream,
+        #[cfg(feature = "gce")] start_end_bits: Option<(
+            &proc_macro2::TokenStream,
+            &proc_macro2::TokenStream,
+        )>,
+    ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        let Field {
+            ident,
+            ty: field_ty,
+            ..
+        } = self;
+
+        #[cfg(feature = "gce")]
+        if let Some((_start_bit, _end_bit)) = start_end_bits {
+            return match bits_span {
+                BitsSpan::Single(bit) => (
+                    quote_spanned! {
+                        ident.span() =>
+                        if (#_start_bit) + (#bit) < (#_end_bit) {
+                            <#storage_ty as ::proc_bitfield::WithBit>
+                                ::with_bit::<{#bit}>(#storage, #raw_value)
+                        } else {
+                            #storage
+                        }
+                    },
+                    quote_spanned! {
+                        ident.span() =>
+                        if (#_start_bit) + (#bit) < (#_end_bit) {
+                            <#storage_ty as ::proc_bitfield::SetBit>
+                                ::set_bit::<{#bit}>(&mut #storage, #raw_value)
+                        }
+                    },
+                ),
+                BitsSpan::Range { start, end } => {
+                    let start = quote! { (#_start_bit) + (#start) };
+                    let end = min(&quote! { (#_start_bit) + (#end) }, _end_bit);
+                    (
+                        quote_spanned! {
+                            ident.span() =>
+                            <#storage_ty as ::proc_bitfield::WithBits<#field_ty>>
+                                ::with_bits::<{#start}, {#end}>(#storage, #raw_value)
                         },
-                        ty.to_token_stream(),
-                    ),
-                    AccessorKind::UnsafeConvTy {
-                        ty,
-                        has_safe_accessor,
-                    } => {
-                        let unsafe_ = has_safe_accessor.then(|| quote! { unsafe });
-                        (
-                            quote! {
-                                #unsafe_ {
-                                    <
-                                        #ty as ::proc_bitfield::UnsafeFrom<#field_ty>
-                                    >::unsafe_from(raw_value)
-                                }
-                            },
-                            ty.to_token_stream(),
-                        )
-                    }
-                    AccessorKind::TryConvTy(ty) => (
-                        quote! {
-                            <
-                                #ty as ::core::convert::TryFrom<#field_ty>
-                            >::try_from(raw_value)
+                        quote_spanned! {
+                            ident.span() =>
+                            <#storage_ty as ::proc_bitfield::SetBits<#field_ty>>
+                                ::set_bits::<{#start}, {#end}>(&mut #storage, #raw_value)
                         },
-                        quote! {
-                            ::core::result::Result<
-                                #ty,
-                                <#ty as ::core::convert::TryFrom<#field_ty>>::Error,
-                            >
+                    )
+                }
+                BitsSpan::Full => {
+                    let end = min(&quote! { (#_start_bit) + (#full_bits) }, _end_bit);
+                    (
+                        quote_spanned! {
+                            ident.span() =>
+                            <#storage_ty as ::proc_bitfield::WithBits<#field_ty>>
+                                ::with_bits::<0, {#end}>(#storage, #raw_value)
                         },
-                    ),
-                    AccessorKind::UnwrapConvTy(ty) => (
-                        quote! {
-                            <
-                                #ty as ::core::convert::TryFrom<#field_ty>
-                            >::try_from(raw_value).unwrap()
+                        quote_spanned! {
+                            ident.span() =>
+                            <#storage_ty as ::proc_bitfield::SetBits<#field_ty>>
+                                ::set_bits::<{#_start_bit}, {#end}>(
+                                    &mut #storage,
+                                    #raw_value,
+                                )
                         },
-                        ty.to_token_stream(),
-                    ),
-
-                    AccessorKind::ConvFn { fn_, ty } => {
-                        (quote! { #fn_(raw_value) }, ty.to_token_stream())
-                    }
-                    AccessorKind::UnsafeConvFn {
-                        fn_,
-                        ty,
-                        has_safe_accessor,
-                    } => {
-                        let unsafe_ = has_safe_accessor.then(|| quote! { unsafe });
-                        (
-                            quote! { #unsafe_ { #fn_(raw_value) } },
-                            ty.to_token_stream(),
-                        )
-                    }
-                    AccessorKind::TryGetFn { fn_, result_ty } => {
-                        (quote! { #fn_(raw_value) }, result_ty.to_token_stream())
-                    }
-                    AccessorKind::UnwrapConvFn { fn_, ty } => {
-                        (quote! { #fn_(raw_value).unwrap() }, ty.to_token_stream())
-                    }
+                    )
+                }
+            };
+        }
 
-                    AccessorKind::TrySetFn { .. } | AccessorKind::Disabled => unreachable!(),
-                };
+        if self.uses_direct_bits(storage_ty)
+            && (matches!(bits_span, BitsSpan::Single(_)) || is_int_ty(field_ty))
+        {
+            let mask = span_mask(bits_span, storage_ty);
+            let (start, _) = bits_span.to_start_end_or_full(full_bits);
+            let start = fold_const(&start);
+            let with_value = quote! {
+                ((#storage) & !#mask) | ((((#raw_value) as #storage_ty) << #start) & #mask)
+            };
+            let set_value = quote! { #storage = #with_value };
+            return (with_value, set_value);
+        }
 
-                #[allow(unused_labels)]
-                let get_raw_value = 'get_raw_value: {
-                    #[cfg(feature = "gce")]
-                    if let Some((start_bit, end_bit)) = start_end_bits {
-                        break 'get_raw_value match bits_span {
-                            BitsSpan::Single(bit) => {
-                                let bit = quote! { (#start_bit) + (#bit) };
-                                quote_spanned! {
-                                    ident.span() =>
-                                    if (#bit) < (#end_bit) {
-                                        <#storage_ty as ::proc_bitfield::Bit>::bit::<{#bit}>(&#storage)
-                                    } else {
-                                        false
-                                    }
-                                }
-                            }
-                            BitsSpan::Range { start, end } => {
-                                let start = quote! { (#start_bit) + (#start) };
-                                let end = min(&quote! { (#start_bit) + (#end) }, end_bit);
-                                quote_spanned! {
-                                    ident.span() =>
-                                    <#storage_ty as ::proc_bitfield::Bits<#field_ty>>
-                                        ::bits::<{#start}, {#end}>(&#storage)
-                                }
-                            }
-                            BitsSpan::Full => {
-                                let end = min(&quote! { (#start_bit) + (#full_bits) }, end_bit);
-                                quote_spanned! {
-                                    ident.span() =>
-                                    <#storage_ty as ::proc_bitfield::Bits<#field_ty>>
-                                        ::bits::<{#start_bit}, {#end}>(&#storage)
-                                }
-                            }
-                        };
-                    }
+        match bits_span {
+            BitsSpan::Single(bit) => (
+                quote_spanned! {
+                    ident.span() =>
+                    <#storage_ty as ::proc_bitfield::WithBit>
+                        ::with_bit::<{#bit}>(#storage, #raw_value)
+                },
+                quote_spanned! {
+                    ident.span() =>
+                    <#storage_ty as ::proc_bitfield::SetBit>
+                        ::set_bit::<{#bit}>(&mut #storage, #raw_value)
+                },
+            ),
+            BitsSpan::Range { start, end } => (
+                quote_spanned! {
+                    ident.span() =>
+                    <#storage_ty as ::proc_bitfield::WithBits<#field_ty>>
+                        ::with_bits::<{#start}, {#end}>(#storage, #raw_value)
+                },
+                quote_spanned! {
+                    ident.span() =>
+                    <#storage_ty as ::proc_bitfield::SetBits<#field_ty>>
+                        ::set_bits::<{#start}, {#end}>(&mut #storage, #raw_value)
+                },
+            ),
+            BitsSpan::Full => (
+                quote_spanned! {
+                    ident.span() =>
+                    <#storage_ty as ::proc_bitfield::WithBits<#field_ty>>
+                        ::with_bits::<0, {#full_bits}>(#storage, #raw_value)
+                },
+                quote_spanned! {
+                    ident.span() =>
+                    <#storage_ty as ::proc_bitfield::SetBits<#field_ty>>
+                        ::set_bits::<0, {#full_bits}>(&mut #storage, #raw_value)
+                },
+            ),
+        }
+    }
 
-                    match bits_span {
-                        BitsSpan::Single(bit) => {
-                            quote_spanned! {
-                                ident.span() =>
-                                <#storage_ty as ::proc_bitfield::Bit>::bit::<{#bit}>(&#storage)
-                            }
-                        }
-                        BitsSpan::Range { start, end } => {
-                            quote_spanned! {
-                                ident.span() =>
-                                <#storage_ty as ::proc_bitfield::Bits<#field_ty>>
-                                    ::bits::<{#start}, {#end}>(&#storage)
-                            }
+    fn get_conversion(
+        &self,
+        get_kind: &AccessorKind,
+    ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        let field_ty = &self.ty;
+        match get_kind {
+            AccessorKind::Default => (quote! { raw_value }, field_ty.to_token_stream()),
+
+            AccessorKind::ConvTy(ty) => (
+                quote! {
+                    <#ty as ::core::convert::From<#field_ty>>::from(raw_value)
+                },
+                ty.to_token_stream(),
+            ),
+            AccessorKind::UnsafeConvTy {
+                ty,
+                has_safe_accessor,
+            } => {
+                let unsafe_ = has_safe_accessor.then(|| quote! { unsafe });
+                (
+                    quote! {
+                        #unsafe_ {
+                            <
+                                #ty as ::proc_bitfield::UnsafeFrom<#field_ty>
+                            >::unsafe_from(raw_value)
                         }
-                        BitsSpan::Full => {
-                            quote_spanned! {
-                                ident.span() =>
-                                <#storage_ty as ::proc_bitfield::Bits<#field_ty>>
-                                    ::bits::<0, {#full_bits}>(&#storage)
-                            }
+                    },
+                    ty.to_token_stream(),
+                )
+            }
+            AccessorKind::TryConvTy(ty) => (
+                quote! {
+                    <
+                        #ty as ::core::convert::TryFrom<#field_ty>
+                    >::try_from(raw_value)
+                },
+                quote! {
+                    ::core::result::Result<
+                        #ty,
+                        <#ty as ::core::convert::TryFrom<#field_ty>>::Error,
+                    >
+                },
+            ),
+            AccessorKind::UnwrapConvTy(ty) => (
+                quote! {
+                    <
+                        #ty as ::core::convert::TryFrom<#field_ty>
+                    >::try_from(raw_value).unwrap()
+                },
+                ty.to_token_stream(),
+            ),
+
+            AccessorKind::ConvFn { fn_, ty } => (quote! { #fn_(raw_value) }, ty.to_token_stream()),
+            AccessorKind::UnsafeConvFn {
+                fn_,
+                ty,
+                has_safe_accessor,
+            } => {
+                let unsafe_ = has_safe_accessor.then(|| quote! { unsafe });
+                (
+                    quote! { #unsafe_ { #fn_(raw_value) } },
+                    ty.to_token_stream(),
+                )
+            }
+            AccessorKind::TryGetFn { fn_, result_ty } => {
+                (quote! { #fn_(raw_value) }, result_ty.to_token_stream())
+            }
+            AccessorKind::UnwrapConvFn { fn_, ty } => {
+                (quote! { #fn_(raw_value).unwrap() }, ty.to_token_stream())
+            }
+
+            AccessorKind::TrySetFn { .. } | AccessorKind::Disabled => unreachable!(),
+        }
+    }
+
+    fn set_conversion<'a>(&'a self, set_kind: &'a AccessorKind) -> SetConversion<'a> {
+        let field_ty = &self.ty;
+        let (input_ty, raw_value, set_ok, set_output_ty, with_ok, with_output_ty) = match set_kind {
+            AccessorKind::Default => (
+                field_ty,
+                quote! { value },
+                quote! {},
+                quote! { () },
+                quote! { result },
+                quote! { Self },
+            ),
+
+            AccessorKind::ConvTy(ty) => (
+                ty,
+                quote! { <#ty as ::core::convert::Into<#field_ty>>::into(value) },
+                quote! {},
+                quote! { () },
+                quote! { result },
+                quote! { Self },
+            ),
+            AccessorKind::UnsafeConvTy {
+                ty,
+                has_safe_accessor,
+            } => {
+                let unsafe_ = has_safe_accessor.then(|| quote! { unsafe });
+                (
+                    ty,
+                    quote! {
+                        #unsafe_ {
+                            <
+                                #ty as ::proc_bitfield::UnsafeInto<#field_ty>
+                            >::unsafe_into(value)
                         }
-                    }
-                };
+                    },
+                    quote! {},
+                    quote! { () },
+                    quote! { result },
+                    quote! { Self },
+                )
+            }
+            AccessorKind::TryConvTy(ty) => (
+                ty,
+                quote! {
+                    <#ty as ::core::convert::TryInto<#field_ty>>::try_into(value)?
+                },
+                quote! { ::core::result::Result::Ok(()) },
+                quote! {
+                    ::core::result::Result<
+                        (),
+                        <#ty as ::core::convert::TryInto<#field_ty>>::Error
+                    >
+                },
+                quote! { ::core::result::Result::Ok(result) },
+                quote! {
+                    ::core::result::Result<
+                        Self,
+                        <#ty as ::core::convert::TryInto<#field_ty>>::Error
+                    >
+                },
+            ),
+            AccessorKind::UnwrapConvTy(ty) => (
+                ty,
+                quote! {
+                    <#ty as ::core::convert::TryInto<#field_ty>>::try_into(value)
+                        .unwrap()
+                },
+                quote! {},
+                quote! { () },
+                quote! { result },
+                quote! { Self },
+            ),
+
+            AccessorKind::ConvFn { fn_, ty } => (
+                ty,
+                quote! { #fn_(value) },
+                quote! {},
+                quote! { () },
+                quote! { result },
+                quote! { Self },
+            ),
+            AccessorKind::UnsafeConvFn {
+                fn_,
+                ty,
+                has_safe_accessor,
+            } => {
+                let unsafe_ = has_safe_accessor.then(|| quote! { unsafe });
+                (
+                    ty,
+                    quote! { #unsafe_ { #fn_(value) } },
+                    quote! {},
+                    quote! { () },
+                    quote! { result },
+                    quote! { Self },
+                )
+            }
+            AccessorKind::TrySetFn {
+                fn_,
+                input_ty,
+                result_ty,
+            } => (
+                input_ty,
+                quote! { #fn_(value)? },
+                quote! {
+                    <
+                        #result_ty as ::proc_bitfield::Try
+                    >::WithOutput::<()>::from_output(())
+                },
+                quote! { <#result_ty as ::proc_bitfield::Try>::WithOutput<()> },
+                quote! {
+                    <
+                        #result_ty as ::proc_bitfield::Try
+                    >::WithOutput::<Self>::from_output(result)
+                },
+                quote! {
+                    <#result_ty as ::proc_bitfield::Try>::WithOutput<Self>
+                },
+            ),
+            AccessorKind::UnwrapConvFn { fn_, ty } => (
+                ty,
+                quote! { #fn_(value).unwrap() },
+                quote! {},
+                quote! { () },
+                quote! { result },
+                quote! { Self },
+            ),
+
+            AccessorKind::TryGetFn { .. } | AccessorKind::Disabled => unreachable!(),
+        };
+        SetConversion {
+            input_ty,
+            raw_value,
+            set_ok,
+            set_output_ty,
+            with_ok,
+            with_output_ty,
+            is_fallible: matches!(
+                set_kind,
+                AccessorKind::TryConvTy(_) | AccessorKind::TrySetFn { .. }
+            ),
+        }
+    }
 
+    #[allow(clippy::too_many_arguments)]
+    fn getters(
+        &self,
+        bits_span: &BitsSpan,
+        element_spans: Option<&[BitsSpan]>,
+        check: &proc_macro2::TokenStream,
+        storage: &proc_macro2::TokenStream,
+        storage_ty: &Type,
+        full_bits: &proc_macro2::TokenStream,
+        #[cfg(feature = "gce")] start_end_bits: Option<(
+            &proc_macro2::TokenStream,
+            &proc_macro2::TokenStream,
+        )>,
+        #[cfg(feature = "gce")] storage_needs_const_bounds: bool,
+    ) -> Option<proc_macro2::TokenStream> {
+        let Field {
+            attrs,
+            vis,
+            ident,
+            ty: field_ty,
+            content,
+            ..
+        } = self;
+
+        #[allow(unused_mut)]
+        let mut where_clause = WhereClause {
+            where_token: Default::default(),
+            predicates: Default::default(),
+        };
+        #[cfg(feature = "gce")]
+        if let Some((start_bit, end_bit)) = start_end_bits {
+            for bits_span in element_spans.unwrap_or(core::slice::from_ref(bits_span)) {
+                Self::add_sized_preds(bits_span, full_bits, start_bit, end_bit, &mut where_clause);
+            }
+        }
+
+        match content {
+            FieldContent::Single(SingleField { get_kind, .. }) => {
+                if matches!(&get_kind, AccessorKind::Disabled) {
+                    return None;
+                }
+
+                let (output, output_ty) = self.get_conversion(get_kind);
+
+                // Iterators aren't `const`, so they can't have `[const]` bounds
+                let iter_where_clause = where_clause.clone();
                 #[cfg(feature = "gce")]
                 if self.has_const_getter() && storage_needs_const_bounds {
                     add_const_bounds(
                         ident.span(),
                         &mut where_clause,
                         storage_ty,
-                        &[if matches!(bits_span, BitsSpan::Single(_)) {
-                            quote! { ::proc_bitfield::Bit }
-                        } else {
-                            quote! { ::proc_bitfield::Bits<#field_ty> }
-                        }],
+                        &[
+                            if matches!(
+                                element_spans.map_or(bits_span, |spans| &spans[0]),
+                                BitsSpan::Single(_)
+                            ) {
+                                quote! { ::proc_bitfield::Bit }
+                            } else {
+                                quote! { ::proc_bitfield::Bits<#field_ty> }
+                            },
+                        ],
                     );
                 }
 
                 let unsafe_ = get_kind.is_unsafe().then(|| quote! { unsafe });
+                let inline = self.inline_attr(storage_ty);
                 let const_ = self.has_const_getter().then(|| quote! { const });
-                let asserts = asserts.get();
+
+                if let Some(element_spans) = element_spans {
+                    let len = element_spans.len();
+                    let arms = element_spans.iter().enumerate().map(|(i, element_span)| {
+                        let get_raw_value = self.get_raw_value(
+                            element_span,
+                            storage,
+                            storage_ty,
+                            full_bits,
+                            #[cfg(feature = "gce")]
+                            start_end_bits,
+                        );
+                        quote! { #i => #get_raw_value, }
+                    });
+                    let out_of_bounds_msg = self.out_of_bounds_msg();
+                    let checked_fn_ident = format_ident!("{}_checked", ident);
+                    let at_fn_ident = format_ident!("{}_at", ident);
+                    let iter_fn_ident = format_ident!("{}_iter", ident);
+                    let iter = (!get_kind.is_unsafe()).then(|| {
+                        quote! {
+                            #(#attrs)*
+                            #[inline]
+                            #vis fn #iter_fn_ident(&self)
+                                -> impl ::core::iter::Iterator<Item = #output_ty> + '_
+                                #iter_where_clause
+                            {
+                                (0..#len).map(move |index| self.#ident(index))
+                            }
+                        }
+                    });
+                    return Some(quote! {
+                        #(#attrs)*
+                        #inline
+                        #[allow(clippy::identity_op)]
+                        #[allow(unused_braces)]
+                        #vis #const_ #unsafe_ fn #ident(&self, index: usize)
+                            -> #output_ty #where_clause
+                        {
+                            #check
+                            let raw_value = match index {
+                                #(#arms)*
+                                _ => ::core::panic!(#out_of_bounds_msg),
+                            };
+                            #output
+                        }
+
+                        #(#attrs)*
+                        #[inline]
+                        #vis #const_ #unsafe_ fn #checked_fn_ident(&self, index: usize)
+                            -> ::core::option::Option<#output_ty> #where_clause
+                        {
+                            if index < #len {
+                                ::core::option::Option::Some(#unsafe_ { self.#ident(index) })
+                            } else {
+                                ::core::option::Option::None
+                            }
+                        }
+
+                        #(#attrs)*
+                        #[inline]
+                        #vis #const_ #unsafe_ fn #at_fn_ident<const INDEX: usize>(&self)
+                            -> #output_ty #where_clause
+                        {
+                            let () = ::proc_bitfield::__private::ArrayIndex::<INDEX, #len>::CHECK;
+                            #unsafe_ { self.#ident(INDEX) }
+                        }
+
+                        #iter
+                    });
+                }
+
+                let get_raw_value = self.get_raw_value(
+                    bits_span,
+                    storage,
+                    storage_ty,
+                    full_bits,
+                    #[cfg(feature = "gce")]
+                    start_end_bits,
+                );
                 Some(quote! {
                     #(#attrs)*
-                    #[inline]
+                    #inline
                     #[allow(clippy::identity_op)]
                     #[allow(unused_braces)]
                     #vis #const_ #unsafe_ fn #ident(&self) -> #output_ty #where_clause {
-                        #asserts
+                        #check
                         let raw_value = #get_raw_value;
                         #output
                     }
                 })
             }
 
+            FieldContent::Reserved(_) => None,
+
             FieldContent::Nested(NestedField { is_readable, .. }) => {
                 if !*is_readable {
                     return None;
@@ -463,8 +1289,10 @@ impl Field {
                     (start, end)
                 };
 
+                // Nested views borrow a sized storage value, so slice storage only gets plain
+                // getters and setters
                 #[cfg(feature = "gce")]
-                let ref_getter = {
+                let ref_getter = (!matches!(storage_ty, Type::Slice(_))).then(|| {
                     let mut where_clause = where_clause.clone();
                     let where_const = self.has_const_getter().then(|| quote! { [const] });
                     where_clause.predicates.push(
@@ -481,7 +1309,6 @@ impl Field {
 
                     let ref_fn_ident = format_ident!("{}_ref", ident);
                     let const_ = self.has_const_getter().then(|| quote! { const });
-                    let asserts = asserts.get();
                     quote! {
                         #(#attrs)*
                         #[inline]
@@ -491,11 +1318,11 @@ impl Field {
                             -> <#field_ty as ::proc_bitfield::NestableBitfield<
                                 #storage_ty, {#start}, {#end}
                             >>::Nested<'_> #where_clause {
-                            #asserts
+                            #check
                             ::proc_bitfield::__private::NestedBitfield::__from_storage(&#storage)
                         }
                     }
-                };
+                });
                 #[cfg(not(feature = "gce"))]
                 let ref_getter = quote! {};
 
@@ -514,7 +1341,6 @@ impl Field {
                 }
 
                 let const_ = self.has_const_getter().then(|| quote! { const });
-                let asserts = asserts.get();
                 Some(quote! {
                     #ref_getter
 
@@ -523,7 +1349,7 @@ impl Field {
                     #[allow(clippy::identity_op)]
                     #[allow(unused_braces)]
                     #vis #const_ fn #ident(&self) -> #field_ty #where_clause {
-                        #asserts
+                        #check
                         let raw_value = <#storage_ty as ::proc_bitfield::Bits<
                                 <#field_ty as ::proc_bitfield::Bitfield>::Storage
                             >>::bits::<{#start}, {#end}>(&#storage);
@@ -538,12 +1364,15 @@ impl Field {
     fn setters(
         &self,
         bits_span: &BitsSpan,
-        asserts: &MaybeRepeat,
+        element_spans: Option<&[BitsSpan]>,
+        check: &proc_macro2::TokenStream,
         storage: &proc_macro2::TokenStream,
         storage_ty: &Type,
         full_bits: &proc_macro2::TokenStream,
         _outer_is_readable: bool,
         outer_allows_with: bool,
+        sanitizes: bool,
+        fixes: bool,
         #[cfg(feature = "gce")] start_end_bits: Option<(
             &proc_macro2::TokenStream,
             &proc_macro2::TokenStream,
@@ -566,7 +1395,9 @@ impl Field {
         };
         #[cfg(feature = "gce")]
         if let Some((start_bit, end_bit)) = start_end_bits {
-            Self::add_sized_preds(bits_span, full_bits, start_bit, end_bit, &mut where_clause);
+            for bits_span in element_spans.unwrap_or(core::slice::from_ref(bits_span)) {
+                Self::add_sized_preds(bits_span, full_bits, start_bit, end_bit, &mut where_clause);
+            }
         }
 
         match content {
@@ -575,236 +1406,169 @@ impl Field {
                     return None;
                 }
 
-                let set_fn_ident = format_ident!("set_{}", ident);
-                let with_fn_ident = format_ident!("with_{}", ident);
-
-                let (input_ty, raw_value, set_ok, set_output_ty, with_ok, with_output_ty) =
-                    match set_kind {
-                        AccessorKind::Default => (
-                            field_ty,
-                            quote! { value },
-                            quote! {},
-                            quote! { () },
-                            quote! { result },
-                            quote! { Self },
-                        ),
+                let (set_fn_ident, with_fn_ident) = self.setter_idents();
+                let inline = self.inline_attr(storage_ty);
+
+                let SetConversion {
+                    input_ty,
+                    raw_value,
+                    set_ok,
+                    set_output_ty,
+                    with_ok,
+                    with_output_ty,
+                    ..
+                } = self.set_conversion(set_kind);
+                let (value_param, value_arg, raw_value) = if self.setters_take_value() {
+                    (quote! { value: #input_ty }, quote! { value }, raw_value)
+                } else {
+                    (quote! {}, quote! {}, quote! { true })
+                };
 
-                        AccessorKind::ConvTy(ty) => (
-                            ty,
-                            quote! { <#ty as ::core::convert::Into<#field_ty>>::into(value) },
-                            quote! {},
-                            quote! { () },
-                            quote! { result },
-                            quote! { Self },
-                        ),
-                        AccessorKind::UnsafeConvTy {
-                            ty,
-                            has_safe_accessor,
-                        } => {
-                            let unsafe_ = has_safe_accessor.then(|| quote! { unsafe });
+                let (with_storage, with_sanitize, set_sanitize) =
+                    self.sanitize_stmts(storage, sanitizes, fixes);
+
+                if let Some(element_spans) = element_spans {
+                    let len = element_spans.len();
+                    let (with_arms, set_arms): (Vec<_>, Vec<_>) = element_spans
+                        .iter()
+                        .enumerate()
+                        .map(|(i, element_span)| {
+                            let (with_raw_value, _) = self.with_set_raw_value(
+                                element_span,
+                                &quote! { raw_value },
+                                &with_storage,
+                                storage_ty,
+                                full_bits,
+                                #[cfg(feature = "gce")]
+                                start_end_bits,
+                            );
+                            let (_, set_raw_value) = self.with_set_raw_value(
+                                element_span,
+                                &quote! { raw_value },
+                                storage,
+                                storage_ty,
+                                full_bits,
+                                #[cfg(feature = "gce")]
+                                start_end_bits,
+                            );
                             (
-                                ty,
-                                quote! {
-                                    #unsafe_ {
-                                        <
-                                            #ty as ::proc_bitfield::UnsafeInto<#field_ty>
-                                        >::unsafe_into(value)
-                                    }
-                                },
-                                quote! {},
-                                quote! { () },
-                                quote! { result },
-                                quote! { Self },
+                                quote! { #i => #with_raw_value, },
+                                quote! { #i => #set_raw_value, },
                             )
-                        }
-                        AccessorKind::TryConvTy(ty) => (
-                            ty,
-                            quote! {
-                                <#ty as ::core::convert::TryInto<#field_ty>>::try_into(value)?
-                            },
-                            quote! { ::core::result::Result::Ok(()) },
-                            quote! {
-                                ::core::result::Result<
-                                    (),
-                                    <#ty as ::core::convert::TryInto<#field_ty>>::Error
-                                >
-                            },
-                            quote! { ::core::result::Result::Ok(result) },
-                            quote! {
-                                ::core::result::Result<
-                                    Self,
-                                    <#ty as ::core::convert::TryInto<#field_ty>>::Error
-                                >
-                            },
-                        ),
-                        AccessorKind::UnwrapConvTy(ty) => (
-                            ty,
-                            quote! {
-                                <#ty as ::core::convert::TryInto<#field_ty>>::try_into(value)
-                                    .unwrap()
-                            },
-                            quote! {},
-                            quote! { () },
-                            quote! { result },
-                            quote! { Self },
-                        ),
+                        })
+                        .unzip();
+                    let out_of_bounds_msg = self.out_of_bounds_msg();
+                    let set_at_fn_ident = format_ident!("{}_at", set_fn_ident);
+                    let with_at_fn_ident = format_ident!("{}_at", with_fn_ident);
 
-                        AccessorKind::ConvFn { fn_, ty } => (
-                            ty,
-                            quote! { #fn_(value) },
-                            quote! {},
-                            quote! { () },
-                            quote! { result },
-                            quote! { Self },
-                        ),
-                        AccessorKind::UnsafeConvFn {
-                            fn_,
-                            ty,
-                            has_safe_accessor,
-                        } => {
-                            let unsafe_ = has_safe_accessor.then(|| quote! { unsafe });
-                            (
-                                ty,
-                                quote! { #unsafe_ { #fn_(value) } },
-                                quote! {},
-                                quote! { () },
-                                quote! { result },
-                                quote! { Self },
-                            )
-                        }
-                        AccessorKind::TrySetFn {
-                            fn_,
-                            input_ty,
-                            result_ty,
-                        } => (
-                            input_ty,
-                            quote! { #fn_(value)? },
-                            quote! {
-                                <
-                                    #result_ty as ::proc_bitfield::Try
-                                >::WithOutput::<()>::from_output(())
-                            },
-                            quote! { <#result_ty as ::proc_bitfield::Try>::WithOutput<()> },
-                            quote! {
-                                <
-                                    #result_ty as ::proc_bitfield::Try
-                                >::WithOutput::<Self>::from_output(result)
-                            },
-                            quote! {
-                                <#result_ty as ::proc_bitfield::Try>::WithOutput<Self>
+                    #[cfg(feature = "gce")]
+                    if self.has_const_setter() && storage_needs_const_bounds {
+                        add_const_bounds(
+                            ident.span(),
+                            &mut where_clause,
+                            storage_ty,
+                            &if matches!(element_spans[0], BitsSpan::Single(_)) {
+                                [
+                                    quote! { ::proc_bitfield::WithBit },
+                                    quote! { ::proc_bitfield::SetBit },
+                                ]
+                            } else {
+                                [
+                                    quote! { ::proc_bitfield::WithBits<#field_ty> },
+                                    quote! { ::proc_bitfield::SetBits<#field_ty> },
+                                ]
                             },
-                        ),
-                        AccessorKind::UnwrapConvFn { fn_, ty } => (
-                            ty,
-                            quote! { #fn_(value).unwrap() },
-                            quote! {},
-                            quote! { () },
-                            quote! { result },
-                            quote! { Self },
-                        ),
+                        );
+                    }
 
-                        AccessorKind::TryGetFn { .. } | AccessorKind::Disabled => unreachable!(),
-                    };
+                    let unsafe_ = set_kind.is_unsafe().then(|| quote! { unsafe });
+                    let const_ = self.has_const_setter().then(|| quote! { const });
+                    let modifier = outer_allows_with.then(|| {
+                        quote! {
+                            #(#attrs)*
+                            #inline
+                            #[must_use]
+                            #[allow(clippy::identity_op)]
+                            #[allow(unused_braces)]
+                            #vis #const_ #unsafe_ fn #with_fn_ident(
+                                self,
+                                index: usize,
+                                #value_param
+                            ) -> #with_output_ty #where_clause {
+                                #check
+                                let raw_value = #raw_value;
+                                #with_sanitize
+                                let result = Self::__from_storage(match index {
+                                    #(#with_arms)*
+                                    _ => ::core::panic!(#out_of_bounds_msg),
+                                });
+                                #with_ok
+                            }
 
-                #[allow(unused_labels)]
-                let (with_raw_value, set_raw_value) = 'with_set_raw_value: {
-                    #[cfg(feature = "gce")]
-                    if let Some((_start_bit, _end_bit)) = start_end_bits {
-                        break 'with_set_raw_value match bits_span {
-                            BitsSpan::Single(bit) => (
-                                quote_spanned! {
-                                    ident.span() =>
-                                    if (#_start_bit) + (#bit) < (#_end_bit) {
-                                        <#storage_ty as ::proc_bitfield::WithBit>
-                                            ::with_bit::<{#bit}>(#storage, #raw_value)
-                                    } else {
-                                        #storage
-                                    }
-                                },
-                                quote_spanned! {
-                                    ident.span() =>
-                                    if (#_start_bit) + (#bit) < (#_end_bit) {
-                                        <#storage_ty as ::proc_bitfield::SetBit>
-                                            ::set_bit::<{#bit}>(&mut #storage, #raw_value)
-                                    }
-                                },
-                            ),
-                            BitsSpan::Range { start, end } => {
-                                let start = quote! { (#_start_bit) + (#start) };
-                                let end = min(&quote! { (#_start_bit) + (#end) }, _end_bit);
-                                (
-                                    quote_spanned! {
-                                        ident.span() =>
-                                        <#storage_ty as ::proc_bitfield::WithBits<#field_ty>>
-                                            ::with_bits::<{#start}, {#end}>(#storage, #raw_value)
-                                    },
-                                    quote_spanned! {
-                                        ident.span() =>
-                                        <#storage_ty as ::proc_bitfield::SetBits<#field_ty>>
-                                            ::set_bits::<{#start}, {#end}>(&mut #storage, #raw_value)
-                                    },
-                                )
+                            #(#attrs)*
+                            #[inline]
+                            #[must_use]
+                            #vis #const_ #unsafe_ fn #with_at_fn_ident<const INDEX: usize>(
+                                self,
+                                #value_param
+                            ) -> #with_output_ty #where_clause {
+                                let () = ::proc_bitfield::__private::ArrayIndex::<INDEX, #len>::CHECK;
+                                #unsafe_ { self.#with_fn_ident(INDEX, #value_arg) }
                             }
-                            BitsSpan::Full => {
-                                let end = min(&quote! { (#_start_bit) + (#full_bits) }, _end_bit);
-                                (
-                                    quote_spanned! {
-                                        ident.span() =>
-                                        <#storage_ty as ::proc_bitfield::WithBits<#field_ty>>
-                                            ::with_bits::<0, {#end}>(#storage, #raw_value)
-                                    },
-                                    quote_spanned! {
-                                        ident.span() =>
-                                        <#storage_ty as ::proc_bitfield::SetBits<#field_ty>>
-                                            ::set_bits::<{#_start_bit}, {#end}>(
-                                                &mut #storage,
-                                                #raw_value,
-                                            )
-                                    },
-                                )
+                        }
+                    });
+                    return Some(quote! {
+                        #modifier
+
+                        #(#attrs)*
+                        #inline
+                        #[allow(clippy::identity_op)]
+                        #[allow(unused_braces)]
+                        #vis #const_ #unsafe_ fn #set_fn_ident(
+                            &mut self,
+                            index: usize,
+                            #value_param
+                        ) -> #set_output_ty #where_clause {
+                            #check
+                            let raw_value = #raw_value;
+                            #set_sanitize
+                            match index {
+                                #(#set_arms)*
+                                _ => ::core::panic!(#out_of_bounds_msg),
                             }
-                        };
-                    }
+                            #set_ok
+                        }
 
-                    match bits_span {
-                        BitsSpan::Single(bit) => (
-                            quote_spanned! {
-                                ident.span() =>
-                                <#storage_ty as ::proc_bitfield::WithBit>
-                                    ::with_bit::<{#bit}>(#storage, #raw_value)
-                            },
-                            quote_spanned! {
-                                ident.span() =>
-                                <#storage_ty as ::proc_bitfield::SetBit>
-                                    ::set_bit::<{#bit}>(&mut #storage, #raw_value)
-                            },
-                        ),
-                        BitsSpan::Range { start, end } => (
-                            quote_spanned! {
-                                ident.span() =>
-                                <#storage_ty as ::proc_bitfield::WithBits<#field_ty>>
-                                    ::with_bits::<{#start}, {#end}>(#storage, #raw_value)
-                            },
-                            quote_spanned! {
-                                ident.span() =>
-                                <#storage_ty as ::proc_bitfield::SetBits<#field_ty>>
-                                    ::set_bits::<{#start}, {#end}>(&mut #storage, #raw_value)
-                            },
-                        ),
-                        BitsSpan::Full => (
-                            quote_spanned! {
-                                ident.span() =>
-                                <#storage_ty as ::proc_bitfield::WithBits<#field_ty>>
-                                    ::with_bits::<0, {#full_bits}>(#storage, #raw_value)
-                            },
-                            quote_spanned! {
-                                ident.span() =>
-                                <#storage_ty as ::proc_bitfield::SetBits<#field_ty>>
-                                    ::set_bits::<0, {#full_bits}>(&mut #storage, #raw_value)
-                            },
-                        ),
-                    }
-                };
+                        #(#attrs)*
+                        #[inline]
+                        #vis #const_ #unsafe_ fn #set_at_fn_ident<const INDEX: usize>(
+                            &mut self,
+                            #value_param
+                        ) -> #set_output_ty #where_clause {
+                            let () = ::proc_bitfield::__private::ArrayIndex::<INDEX, #len>::CHECK;
+                            #unsafe_ { self.#set_fn_ident(INDEX, #value_arg) }
+                        }
+                    });
+                }
+
+                let (with_raw_value, _) = self.with_set_raw_value(
+                    bits_span,
+                    &quote! { raw_value },
+                    &with_storage,
+                    storage_ty,
+                    full_bits,
+                    #[cfg(feature = "gce")]
+                    start_end_bits,
+                );
+                let (_, set_raw_value) = self.with_set_raw_value(
+                    bits_span,
+                    &quote! { raw_value },
+                    storage,
+                    storage_ty,
+                    full_bits,
+                    #[cfg(feature = "gce")]
+                    start_end_bits,
+                );
 
                 let modifier = outer_allows_with.then(|| {
                     #[cfg(feature = "gce")]
@@ -823,17 +1587,18 @@ impl Field {
 
                     let unsafe_ = set_kind.is_unsafe().then(|| quote! { unsafe });
                     let const_ = self.has_const_setter().then(|| quote! { const });
-                    let asserts = asserts.get();
                     quote! {
                         #(#attrs)*
-                        #[inline]
+                        #inline
                         #[must_use]
                         #[allow(clippy::identity_op)]
                         #[allow(unused_braces)]
-                        #vis #const_ #unsafe_ fn #with_fn_ident(self, value: #input_ty)
+                        #vis #const_ #unsafe_ fn #with_fn_ident(self, #value_param)
                             -> #with_output_ty #where_clause
                         {
-                            #asserts
+                            #check
+                            let raw_value = #raw_value;
+                            #with_sanitize
                             let result = Self::__from_storage(#with_raw_value);
                             #with_ok
                         }
@@ -856,24 +1621,27 @@ impl Field {
 
                 let unsafe_ = set_kind.is_unsafe().then(|| quote! { unsafe });
                 let const_ = self.has_const_setter().then(|| quote! { const });
-                let asserts = asserts.get();
                 Some(quote! {
                     #modifier
 
                     #(#attrs)*
-                    #[inline]
+                    #inline
                     #[allow(clippy::identity_op)]
                     #[allow(unused_braces)]
-                    #vis #const_ #unsafe_ fn #set_fn_ident(&mut self, value: #input_ty)
+                    #vis #const_ #unsafe_ fn #set_fn_ident(&mut self, #value_param)
                         -> #set_output_ty #where_clause
                     {
-                        #asserts
+                        #check
+                        let raw_value = #raw_value;
+                        #set_sanitize
                         #set_raw_value;
                         #set_ok
                     }
                 })
             }
 
+            FieldContent::Reserved(_) => None,
+
             FieldContent::Nested(NestedField {
                 is_readable: _is_readable,
                 is_writable,
@@ -886,12 +1654,16 @@ impl Field {
 
                 let set_fn_ident = format_ident!("set_{}", ident);
                 let with_fn_ident = format_ident!("with_{}", ident);
+                let (with_storage, with_sanitize, set_sanitize) =
+                    self.sanitize_stmts(storage, sanitizes, fixes);
 
                 #[cfg(feature = "gce")]
-                let mut_getter = (_outer_is_readable && *_is_readable).then(|| {
-                    let mut where_clause = where_clause.clone();
-                    let where_const = self.has_const_setter().then(|| quote! { [const] });
-                    where_clause.predicates.push(
+                let mut_getter =
+                    (_outer_is_readable && *_is_readable && !matches!(storage_ty, Type::Slice(_)))
+                        .then(|| {
+                            let mut where_clause = where_clause.clone();
+                            let where_const = self.has_const_setter().then(|| quote! { [const] });
+                            where_clause.predicates.push(
                         syn::parse(
                             quote! {
                                 #field_ty: #where_const ::proc_bitfield::NestableMutBitfield<
@@ -903,31 +1675,30 @@ impl Field {
                         .unwrap(),
                     );
 
-                    let mut_fn_ident = format_ident!("{}_mut", ident);
-                    let const_ = self.has_const_setter().then(|| quote! { const });
-                    let asserts = asserts.get();
-                    quote! {
-                        #(#attrs)*
-                        #[inline]
-                        #[allow(clippy::identity_op)]
-                        #[allow(unused_braces)]
-                        #vis #const_ fn #mut_fn_ident(&'_ mut self)
-                            -> <#field_ty as ::proc_bitfield::NestableMutBitfield<
-                                #storage_ty, {#start}, {#end}
-                            >>::NestedMut<'_> #where_clause
-                        {
-                            #asserts
-                            ::proc_bitfield::__private::NestedMutBitfield::__from_storage(
-                                &mut #storage,
-                            )
-                        }
-                    }
-                });
+                            let mut_fn_ident = format_ident!("{}_mut", ident);
+                            let const_ = self.has_const_setter().then(|| quote! { const });
+                            quote! {
+                                #(#attrs)*
+                                #[inline]
+                                #[allow(clippy::identity_op)]
+                                #[allow(unused_braces)]
+                                #vis #const_ fn #mut_fn_ident(&'_ mut self)
+                                    -> <#field_ty as ::proc_bitfield::NestableMutBitfield<
+                                        #storage_ty, {#start}, {#end}
+                                    >>::NestedMut<'_> #where_clause
+                                {
+                                    #check
+                                    ::proc_bitfield::__private::NestedMutBitfield::__from_storage(
+                                        &mut #storage,
+                                    )
+                                }
+                            }
+                        });
                 #[cfg(not(feature = "gce"))]
                 let mut_getter = quote! {};
 
                 #[cfg(feature = "gce")]
-                let writer = {
+                let writer = (!matches!(storage_ty, Type::Slice(_))).then(|| {
                     let mut where_clause = where_clause.clone();
                     let where_const = self.has_const_setter().then(|| quote! { [const] });
                     where_clause.predicates.push(
@@ -944,7 +1715,6 @@ impl Field {
 
                     let write_fn_ident = format_ident!("{}_write", ident);
                     let const_ = self.has_const_setter().then(|| quote! { const });
-                    let asserts = asserts.get();
                     quote! {
                         #(#attrs)*
                         #[inline]
@@ -955,13 +1725,13 @@ impl Field {
                                 #storage_ty, {#start}, {#end}
                             >>::NestedWrite<'_> #where_clause
                         {
-                            #asserts
+                            #check
                             ::proc_bitfield::__private::NestedWriteBitfield::__from_storage(
                                 &mut #storage,
                             )
                         }
                     }
-                };
+                });
                 #[cfg(not(feature = "gce"))]
                 let writer = quote! {};
 
@@ -981,7 +1751,6 @@ impl Field {
                     }
 
                     let const_ = self.has_const_setter().then(|| quote! { const });
-                    let asserts = asserts.get();
                     quote! {
                         #(#attrs)*
                         #[inline]
@@ -991,11 +1760,12 @@ impl Field {
                         #vis #const_ fn #with_fn_ident(self, value: #field_ty)
                             -> Self #where_clause
                         {
-                            #asserts
+                            #check
+                            #with_sanitize
                             Self::__from_storage(
                                 <#storage_ty as ::proc_bitfield::WithBits<
                                     <#field_ty as ::proc_bitfield::Bitfield>::Storage
-                                >>::with_bits::<{#start}, {#end}>(#storage, value.0),
+                                >>::with_bits::<{#start}, {#end}>(#with_storage, value.0),
                             )
                         }
                     }
@@ -1016,7 +1786,6 @@ impl Field {
                 }
 
                 let const_ = self.has_const_setter().then(|| quote! { const });
-                let asserts = asserts.get();
                 Some(quote! {
                     #mut_getter
                     #writer
@@ -1028,7 +1797,8 @@ impl Field {
                     #[allow(clippy::identity_op)]
                     #[allow(unused_braces)]
                     #vis #const_ fn #set_fn_ident(&mut self, value: #field_ty) #where_clause {
-                        #asserts
+                        #check
+                        #set_sanitize
                         <#storage_ty as ::proc_bitfield::SetBits<
                             <#field_ty as ::proc_bitfield::Bitfield>::Storage>
                         >::set_bits::<{#start}, {#end}>(&mut #storage, value.0);
@@ -1044,6 +1814,18 @@ struct AutoImpls {
     from_storage: bool,
     into_storage: bool,
     deref_storage: bool,
+    try_from_storage: bool,
+    bytes: bool,
+    volatile: bool,
+    atomic: bool,
+    cell: bool,
+    views: bool,
+    reflect: bool,
+    descriptors: bool,
+    unpacked: bool,
+    new: bool,
+    builder: bool,
+    default: bool,
 }
 
 struct Struct {
@@ -1054,23 +1836,494 @@ struct Struct {
     storage_vis: Visibility,
     storage_ty: Type,
     auto_impls: AutoImpls,
+    msb0: bool,
+    write_zero: bool,
+    field_consts: bool,
+    exhaustive: Option<Span>,
+    reset_value: Option<Expr>,
     fields: Punctuated<Field, Token![,]>,
+    write_fields: Option<Punctuated<Field, Token![,]>>,
 }
 
-fn check_has_nightly(span: Span) -> syn::Result<()> {
-    if !cfg!(feature = "nightly") {
-        return Err(Error::new(
-            span,
-            "const fns can only be generated with the \"nightly\" feature enabled",
-        ));
+fn parse_array_len(input: ParseStream) -> Result<(usize, Span)> {
+    let content = parse_brackets(input)?;
+    let len_lit = content.parse::<LitInt>()?;
+    if !content.is_empty() {
+        return Err(content.error("unexpected extra tokens"));
+    }
+    let len = len_lit.base10_parse::<usize>()?;
+    if len == 0 {
+        return Err(Error::new(len_lit.span(), "field arrays can't be empty"));
     }
-    Ok(())
+    Ok((len, len_lit.span()))
 }
 
-impl Parse for Struct {
-    fn parse(input: ParseStream) -> Result<Self> {
-        let outer_attrs = input.call(Attribute::parse_outer)?;
-        let vis = input.parse()?;
+fn parse_fields(
+    input: ParseStream,
+    fields_use_const_fns_by_default: bool,
+) -> Result<Punctuated<Field, Token![,]>> {
+    let mut union_groups = 0;
+    let groups = parse_terminated::<_, Token![,]>(input, |input: ParseStream| {
+        if input.peek(Token![union]) && input.peek2(token::Brace) {
+            input.parse::<Token![union]>()?;
+            let union_group = union_groups;
+            union_groups += 1;
+            let content = parse_braces(input)?;
+            let fields = parse_terminated::<_, Token![,]>(&content, |input: ParseStream| {
+                if input.peek(Token![union]) && input.peek2(token::Brace) {
+                    return Err(input.error("union groups can't be nested"));
+                }
+                parse_field(input, Some(union_group), fields_use_const_fns_by_default)
+            })?;
+            Ok(fields.into_iter().collect())
+        } else {
+            Ok(vec![parse_field(
+                input,
+                None,
+                fields_use_const_fns_by_default,
+            )?])
+        }
+    })?;
+    Ok(groups.into_iter().flatten().collect())
+}
+
+fn parse_field(
+    input: ParseStream,
+    union_group: Option<usize>,
+    fields_use_const_fns_by_default: bool,
+) -> Result<Field> {
+    let attrs = input.call(Attribute::parse_outer)?;
+
+    if let Ok(underscore) = input.parse::<Token![_]>() {
+        input.parse::<Token![:]>()?;
+        let lookahead = input.lookahead1();
+        let fixed_value = if lookahead.peek(kw::reserved) {
+            input.parse::<kw::reserved>()?;
+            None
+        } else if lookahead.peek(kw::fixed) {
+            input.parse::<kw::fixed>()?;
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            return Err(lookahead.error());
+        };
+        input.parse::<Token![@]>()?;
+        let bits = input.parse()?;
+        return Ok(Field {
+            attrs,
+            vis: Visibility::Inherited,
+            ident: Ident::new("__reserved", underscore.span),
+            bits,
+            ty: parse_quote! { u128 },
+            array_len: None,
+            content: FieldContent::Reserved(ReservedField { fixed_value }),
+            is_alias: false,
+            union_group,
+            default: None,
+            uses_const_fns: fields_use_const_fns_by_default,
+        });
+    }
+    let vis = input.parse()?;
+    let ident = input.parse()?;
+    input.parse::<Token![:]>()?;
+    let is_nested = input.parse::<kw::nested>().is_ok();
+    let ty = input.parse::<Type>()?;
+
+    let array_len = if input.fork().call(parse_array_len).is_ok() {
+        let (len, span) = parse_array_len(input)?;
+        if is_nested {
+            return Err(Error::new(span, "nested fields can't be field arrays"));
+        }
+        Some((len, span))
+    } else {
+        None
+    };
+
+    let mut uses_const_fns = fields_use_const_fns_by_default;
+    let mut is_alias = false;
+
+    let content = if is_nested {
+        let mut is_readable = true;
+        let mut is_writable = true;
+        if let Ok(options_content) = parse_brackets(input) {
+            macro_rules! check_accessor_conflict {
+                ($ident: ident, $name: literal, $other: ident, $span: ident) => {
+                    if !$ident {
+                        return Err(Error::new(
+                            $span,
+                            concat!("Duplicate ", $name, " specifiers"),
+                        ));
+                    }
+                    if !$other {
+                        return Err(Error::new(
+                            $span,
+                            "Conflicting read_only and write_only specifiers",
+                        ));
+                    }
+                };
+            }
+
+            while !options_content.is_empty() {
+                let lookahead = options_content.lookahead1();
+                if lookahead.peek(Token![const]) {
+                    options_content.parse::<Token![const]>()?;
+                    uses_const_fns = true;
+                } else if lookahead.peek(kw::no_const) {
+                    options_content.parse::<kw::no_const>()?;
+                    uses_const_fns = false;
+                } else if lookahead.peek(kw::read_only) || lookahead.peek(kw::ro) {
+                    let span = options_content
+                        .parse::<kw::read_only>()
+                        .map(|kw| kw.span)
+                        .or_else(|_| options_content.parse::<kw::ro>().map(|kw| kw.span))?;
+                    check_accessor_conflict!(is_writable, "read_only", is_readable, span);
+                    is_writable = false;
+                } else if lookahead.peek(kw::write_only) || lookahead.peek(kw::wo) {
+                    let span = options_content
+                        .parse::<kw::write_only>()
+                        .map(|kw| kw.span)
+                        .or_else(|_| options_content.parse::<kw::wo>().map(|kw| kw.span))?;
+                    check_accessor_conflict!(is_readable, "write_only", is_writable, span);
+                    is_readable = false;
+                } else if lookahead.peek(kw::alias) {
+                    options_content.parse::<kw::alias>()?;
+                    is_alias = true;
+                } else {
+                    return Err(lookahead.error());
+                }
+
+                let had_comma = options_content.parse::<Token![,]>().is_ok();
+                if !options_content.is_empty() && !had_comma {
+                    return Err(options_content.error("expected comma between field options"));
+                }
+            }
+        }
+        FieldContent::Nested(NestedField {
+            is_readable,
+            is_writable,
+        })
+    } else {
+        let mut get = AccessorKind::Default;
+        let mut set = AccessorKind::Default;
+        let mut side_effect = SideEffect::None;
+        let mut side_effect_span = Span::call_site();
+        if let Ok(options_content) = parse_brackets(input) {
+            macro_rules! check_conversion_ty_conflict {
+                    ($($ident: ident),*; $span: expr) => {
+                        if $(!matches!(&$ident, AccessorKind::Default))||* {
+                            return Err(Error::new(
+                                $span,
+                                "Conflicting conversion type definitions",
+                            ));
+                        }
+                    };
+                }
+
+            macro_rules! check_accessor_conflict {
+                ($ident: ident, $name: literal, $other: ident, $span: ident) => {
+                    if matches!(&$ident, AccessorKind::Disabled) {
+                        return Err(Error::new(
+                            $span,
+                            concat!("Duplicate ", $name, " specifiers"),
+                        ));
+                    }
+                    if matches!(&$other, AccessorKind::Disabled) {
+                        return Err(Error::new(
+                            $span,
+                            "Conflicting read_only and write_only specifiers",
+                        ));
+                    }
+                };
+            }
+
+            fn parse_return_ty(input: ParseStream) -> Result<Result<Type>> {
+                if let Err(err) = input.parse::<Token![->]>() {
+                    return Ok(Err(err));
+                }
+                Ok(input.parse())
+            }
+
+            fn parse_parenthesized_ty(input: ParseStream) -> Result<Result<Type>> {
+                Ok(match parse_parens(input) {
+                    Ok(content) => content.parse(),
+                    Err(err) => Err(err),
+                })
+            }
+
+            while !options_content.is_empty() {
+                if options_content.parse::<Token![const]>().is_ok() {
+                    uses_const_fns = true;
+                } else if options_content.parse::<kw::no_const>().is_ok() {
+                    uses_const_fns = false;
+                }
+                // Infallible conversions
+                else if let Ok(kw) = options_content.parse::<kw::get>() {
+                    check_conversion_ty_conflict!(get; kw.span);
+                    get = AccessorKind::ConvTy(options_content.parse()?);
+                } else if let Ok(kw) = options_content.parse::<kw::set>() {
+                    check_conversion_ty_conflict!(set; kw.span);
+                    set = AccessorKind::ConvTy(options_content.parse()?);
+                }
+                // Unsafe conversions
+                else if let Ok(kw) = options_content.parse::<kw::unsafe_get>() {
+                    check_conversion_ty_conflict!(get; kw.span);
+                    let has_safe_accessor = options_content.parse::<Token![!]>().is_ok();
+                    get = AccessorKind::UnsafeConvTy {
+                        ty: options_content.parse()?,
+                        has_safe_accessor,
+                    };
+                } else if let Ok(kw) = options_content.parse::<kw::unsafe_set>() {
+                    check_conversion_ty_conflict!(set; kw.span);
+                    let has_safe_accessor = options_content.parse::<Token![!]>().is_ok();
+                    set = AccessorKind::UnsafeConvTy {
+                        ty: options_content.parse()?,
+                        has_safe_accessor,
+                    };
+                } else if let Ok(kw) = options_content.parse::<kw::unsafe_both>() {
+                    check_conversion_ty_conflict!(get, set; kw.span);
+                    let has_safe_accessor = options_content.parse::<Token![!]>().is_ok();
+                    let ty: Type = options_content.parse()?;
+                    get = AccessorKind::UnsafeConvTy {
+                        ty: ty.clone(),
+                        has_safe_accessor,
+                    };
+                    set = AccessorKind::UnsafeConvTy {
+                        ty,
+                        has_safe_accessor,
+                    };
+                } else if let Ok(kw) = options_content.parse::<Token![unsafe]>() {
+                    check_conversion_ty_conflict!(get, set; kw.span);
+                    let has_safe_accessor = options_content.parse::<Token![!]>().is_ok();
+                    let ty: Type = options_content.parse()?;
+                    get = AccessorKind::UnsafeConvTy {
+                        ty: ty.clone(),
+                        has_safe_accessor,
+                    };
+                    set = AccessorKind::ConvTy(ty);
+                }
+                // Fallible conversions
+                else if let Ok(kw) = options_content.parse::<kw::try_get>() {
+                    check_conversion_ty_conflict!(get; kw.span);
+                    get = AccessorKind::TryConvTy(options_content.parse()?);
+                } else if let Ok(kw) = options_content.parse::<kw::try_set>() {
+                    check_conversion_ty_conflict!(set; kw.span);
+                    set = AccessorKind::TryConvTy(options_content.parse()?);
+                } else if let Ok(kw) = options_content.parse::<kw::try_both>() {
+                    check_conversion_ty_conflict!(get, set; kw.span);
+                    let ty: Type = options_content.parse()?;
+                    get = AccessorKind::TryConvTy(ty.clone());
+                    set = AccessorKind::TryConvTy(ty);
+                } else if let Ok(kw) = options_content.parse::<Token![try]>() {
+                    check_conversion_ty_conflict!(get, set; kw.span);
+                    let ty: Type = options_content.parse()?;
+                    get = AccessorKind::TryConvTy(ty.clone());
+                    set = AccessorKind::ConvTy(ty);
+                }
+                // Unwrapping conversions
+                else if let Ok(kw) = options_content.parse::<kw::unwrap_get>() {
+                    check_conversion_ty_conflict!(get; kw.span);
+                    get = AccessorKind::UnwrapConvTy(options_content.parse()?);
+                } else if let Ok(kw) = options_content.parse::<kw::unwrap_set>() {
+                    check_conversion_ty_conflict!(set; kw.span);
+                    set = AccessorKind::UnwrapConvTy(options_content.parse()?);
+                } else if let Ok(kw) = options_content.parse::<kw::unwrap_both>() {
+                    check_conversion_ty_conflict!(get, set; kw.span);
+                    let ty: Type = options_content.parse()?;
+                    get = AccessorKind::UnwrapConvTy(ty.clone());
+                    set = AccessorKind::UnwrapConvTy(ty);
+                } else if let Ok(kw) = options_content.parse::<kw::unwrap>() {
+                    check_conversion_ty_conflict!(get, set; kw.span);
+                    let ty: Type = options_content.parse()?;
+                    get = AccessorKind::UnwrapConvTy(ty.clone());
+                    set = AccessorKind::ConvTy(ty);
+                }
+                // Infallible fn conversions
+                else if let Ok(kw) = options_content.parse::<kw::get_fn>() {
+                    check_conversion_ty_conflict!(get; kw.span);
+                    let fn_ = parse_accessor_fn(&options_content)?;
+                    let ty = parse_return_ty(&options_content)?.unwrap_or_else(|_| ty.clone());
+                    get = AccessorKind::ConvFn { fn_, ty };
+                } else if let Ok(kw) = options_content.parse::<kw::set_fn>() {
+                    check_conversion_ty_conflict!(set; kw.span);
+                    let fn_ = parse_accessor_fn(&options_content)?;
+                    let ty =
+                        parse_parenthesized_ty(&options_content)?.unwrap_or_else(|_| ty.clone());
+                    set = AccessorKind::ConvFn { fn_, ty };
+                }
+                // Unsafe fn conversions
+                else if let Ok(kw) = options_content.parse::<kw::unsafe_get_fn>() {
+                    check_conversion_ty_conflict!(get; kw.span);
+                    let has_safe_accessor = options_content.parse::<Token![!]>().is_ok();
+                    let fn_ = parse_accessor_fn(&options_content)?;
+                    let ty = parse_return_ty(&options_content)?.unwrap_or_else(|_| ty.clone());
+                    get = AccessorKind::UnsafeConvFn {
+                        fn_,
+                        ty,
+                        has_safe_accessor,
+                    };
+                } else if let Ok(kw) = options_content.parse::<kw::unsafe_set_fn>() {
+                    check_conversion_ty_conflict!(set; kw.span);
+                    let has_safe_accessor = options_content.parse::<Token![!]>().is_ok();
+                    let fn_ = parse_accessor_fn(&options_content)?;
+                    let ty =
+                        parse_parenthesized_ty(&options_content)?.unwrap_or_else(|_| ty.clone());
+                    set = AccessorKind::UnsafeConvFn {
+                        fn_,
+                        ty,
+                        has_safe_accessor,
+                    };
+                }
+                // Fallible fn conversions
+                else if let Ok(kw) = options_content.parse::<kw::try_get_fn>() {
+                    check_conversion_ty_conflict!(get; kw.span);
+                    let fn_ = parse_accessor_fn(&options_content)?;
+                    let result_ty = parse_return_ty(&options_content)??;
+                    get = AccessorKind::TryGetFn { fn_, result_ty };
+                } else if let Ok(kw) = options_content.parse::<kw::try_set_fn>() {
+                    check_conversion_ty_conflict!(set; kw.span);
+                    let fn_ = parse_accessor_fn(&options_content)?;
+                    let input_ty =
+                        parse_parenthesized_ty(&options_content)?.unwrap_or_else(|_| ty.clone());
+                    let result_ty = parse_return_ty(&options_content)??;
+                    set = AccessorKind::TrySetFn {
+                        fn_,
+                        input_ty,
+                        result_ty,
+                    };
+                }
+                // Unwrapping fn conversions
+                else if let Ok(kw) = options_content.parse::<kw::unwrap_get_fn>() {
+                    check_conversion_ty_conflict!(get; kw.span);
+                    let fn_ = parse_accessor_fn(&options_content)?;
+                    let ty = parse_return_ty(&options_content)?.unwrap_or_else(|_| ty.clone());
+                    get = AccessorKind::UnwrapConvFn { fn_, ty };
+                } else if let Ok(kw) = options_content.parse::<kw::unwrap_set_fn>() {
+                    check_conversion_ty_conflict!(set; kw.span);
+                    let fn_ = parse_accessor_fn(&options_content)?;
+                    let ty =
+                        parse_parenthesized_ty(&options_content)?.unwrap_or_else(|_| ty.clone());
+                    set = AccessorKind::UnwrapConvFn { fn_, ty };
+                }
+                // Hardware access semantics
+                else if let Ok((new_side_effect, span)) = options_content
+                    .parse::<kw::w1c>()
+                    .map(|kw| (SideEffect::WriteOneToClear, kw.span))
+                    .or_else(|_| {
+                        options_content
+                            .parse::<kw::w1s>()
+                            .map(|kw| (SideEffect::WriteOneToSet, kw.span))
+                    })
+                    .or_else(|_| {
+                        options_content
+                            .parse::<kw::rc>()
+                            .map(|kw| (SideEffect::ReadToClear, kw.span))
+                    })
+                {
+                    if side_effect != SideEffect::None {
+                        return Err(Error::new(span, "Conflicting w1c, w1s and rc specifiers"));
+                    }
+                    side_effect = new_side_effect;
+                    side_effect_span = span;
+                }
+                // Access restrictions
+                else if let Ok(span) = options_content
+                    .parse::<kw::read_only>()
+                    .map(|kw| kw.span)
+                    .or_else(|_| options_content.parse::<kw::ro>().map(|kw| kw.span))
+                {
+                    check_accessor_conflict!(set, "read_only", get, span);
+                    set = AccessorKind::Disabled;
+                } else if let Ok(span) = options_content
+                    .parse::<kw::write_only>()
+                    .map(|kw| kw.span)
+                    .or_else(|_| options_content.parse::<kw::wo>().map(|kw| kw.span))
+                {
+                    check_accessor_conflict!(get, "write_only", set, span);
+                    get = AccessorKind::Disabled;
+                }
+                // Layout checks
+                else if options_content.parse::<kw::alias>().is_ok() {
+                    is_alias = true;
+                }
+                // Infallible conversion (without keywords)
+                else {
+                    let ty: Type = options_content.parse()?;
+                    check_conversion_ty_conflict!(get, set; ty.span());
+                    get = AccessorKind::ConvTy(ty.clone());
+                    set = AccessorKind::ConvTy(ty);
+                }
+
+                let had_comma = options_content.parse::<Token![,]>().is_ok();
+                if !options_content.is_empty() && !had_comma {
+                    return Err(options_content.error("expected comma between field options"));
+                }
+            }
+        }
+        match side_effect {
+            SideEffect::None => {}
+            SideEffect::WriteOneToClear | SideEffect::WriteOneToSet => {
+                if matches!(&set, AccessorKind::Disabled) {
+                    return Err(Error::new(
+                        side_effect_span,
+                        "w1c and w1s fields can't be read_only",
+                    ));
+                }
+            }
+            SideEffect::ReadToClear => {
+                if matches!(&get, AccessorKind::Disabled) {
+                    return Err(Error::new(
+                        side_effect_span,
+                        "rc fields can't be write_only",
+                    ));
+                }
+                // Writing to read-to-clear fields has no effect, so they're read-only
+                set = AccessorKind::Disabled;
+            }
+        }
+        FieldContent::Single(SingleField {
+            get_kind: get,
+            set_kind: set,
+            side_effect,
+        })
+    };
+    let default = if let Ok(eq) = input.parse::<Token![=]>() {
+        if matches!(content, FieldContent::Nested(_)) {
+            return Err(Error::new(
+                eq.span,
+                "nested fields can't have default values",
+            ));
+        }
+        if !is_int_ty(&ty) && !matches!(&ty, Type::Path(ty) if ty.path.is_ident("bool")) {
+            return Err(Error::new(
+                eq.span,
+                "default values can only be specified for fields of integer and `bool` types",
+            ));
+        }
+        Some(input.parse()?)
+    } else {
+        None
+    };
+    input.parse::<Token![@]>()?;
+    let bits = input.parse()?;
+    Ok(Field {
+        attrs,
+        vis,
+        ident,
+        bits,
+        ty,
+        array_len,
+        content,
+        is_alias,
+        union_group,
+        default,
+        uses_const_fns,
+    })
+}
+
+impl Parse for Struct {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let outer_attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
         input.parse::<Token![struct]>()?;
         let ident = input.parse::<Ident>()?;
 
@@ -1086,20 +2339,54 @@ impl Parse for Struct {
             from_storage: false,
             into_storage: false,
             deref_storage: false,
+            try_from_storage: false,
+            bytes: false,
+            volatile: false,
+            atomic: false,
+            cell: false,
+            views: false,
+            reflect: false,
+            descriptors: false,
+            unpacked: false,
+            new: false,
+            builder: false,
+            default: false,
         };
-        #[cfg(feature = "nightly")]
+        let mut reset_value = None;
+        let mut msb0 = false;
+        let mut write_zero = false;
+        let mut field_consts = false;
+        let mut exhaustive = None;
+        let mut try_from_storage_span = Span::call_site();
+        let mut single_layout_span = None;
         let mut fields_use_const_fns_by_default = false;
+        let is_slice = matches!(storage_ty, Type::Slice(_));
+        if is_slice && !generics.params.is_empty() {
+            return Err(Error::new_spanned(
+                &generics,
+                "bitfields with slice storage can't be generic",
+            ));
+        }
         if input.parse::<Token![:]>().is_ok() {
             loop {
                 if input.is_empty() {
                     break;
                 }
-                if let Ok(kw) = input.parse::<Token![const]>() {
-                    check_has_nightly(kw.span())?;
-                    #[cfg(feature = "nightly")]
-                    {
-                        fields_use_const_fns_by_default = true;
+                // Slice storage is unsized, so bitfields using it can only be accessed in place
+                if is_slice
+                    && !input.peek(kw::Debug)
+                    && !input.peek(kw::DerefStorage)
+                    && !input.peek(kw::FieldConsts)
+                {
+                    if let Ok(option) = input.fork().parse::<Ident>() {
+                        return Err(Error::new(
+                            option.span(),
+                            format!("`{option}` can't be used for bitfields with slice storage"),
+                        ));
                     }
+                }
+                if input.parse::<Token![const]>().is_ok() {
+                    fields_use_const_fns_by_default = true;
                 } else if input.parse::<kw::Debug>().is_ok() {
                     auto_impls.debug = true;
                 } else if input.parse::<kw::FromStorage>().is_ok() {
@@ -1108,6 +2395,82 @@ impl Parse for Struct {
                     auto_impls.into_storage = true;
                 } else if input.parse::<kw::DerefStorage>().is_ok() {
                     auto_impls.deref_storage = true;
+                } else if let Ok(kw) = input.parse::<kw::TryFromStorage>() {
+                    auto_impls.try_from_storage = true;
+                    try_from_storage_span = kw.span;
+                } else if let Ok(kw) = input.parse::<kw::Bytes>() {
+                    if !is_int_ty(&storage_ty) {
+                        return Err(Error::new(
+                            kw.span,
+                            "`Bytes` can only be implemented for bitfields with integer storage",
+                        ));
+                    }
+                    auto_impls.bytes = true;
+                } else if input.parse::<kw::Volatile>().is_ok() {
+                    auto_impls.volatile = true;
+                } else if let Ok(kw) = input.parse::<kw::Atomic>() {
+                    if atomic_ty(&storage_ty).is_none() {
+                        return Err(Error::new(
+                            kw.span,
+                            "`Atomic` can only be implemented for bitfields with builtin integer \
+                             storage of up to 64 bits",
+                        ));
+                    }
+                    auto_impls.atomic = true;
+                    single_layout_span = Some((kw.span, "Atomic"));
+                } else if let Ok(kw) = input.parse::<kw::Cell>() {
+                    auto_impls.cell = true;
+                    single_layout_span = Some((kw.span, "Cell"));
+                } else if let Ok(kw) = input.parse::<kw::Views>() {
+                    if !is_int_ty(&storage_ty) {
+                        return Err(Error::new(
+                            kw.span,
+                            "`Views` can only be implemented for bitfields with integer storage",
+                        ));
+                    }
+                    auto_impls.views = true;
+                    single_layout_span = Some((kw.span, "Views"));
+                } else if input.parse::<kw::Reflect>().is_ok() {
+                    auto_impls.reflect = true;
+                } else if input.parse::<kw::Descriptors>().is_ok() {
+                    auto_impls.descriptors = true;
+                } else if let Ok(kw) = input.parse::<kw::Unpacked>() {
+                    if !generics.params.is_empty() {
+                        return Err(Error::new(
+                            kw.span,
+                            "`Unpacked` can't be used for generic bitfields",
+                        ));
+                    }
+                    auto_impls.unpacked = true;
+                } else if input.parse::<kw::New>().is_ok() {
+                    auto_impls.new = true;
+                } else if input.parse::<kw::Builder>().is_ok() {
+                    auto_impls.builder = true;
+                } else if let Ok(kw) = input.parse::<kw::Default>() {
+                    if !is_int_ty(&storage_ty) {
+                        return Err(Error::new(
+                            kw.span,
+                            "`Default` can only be implemented for bitfields with integer storage",
+                        ));
+                    }
+                    auto_impls.default = true;
+                    if input.parse::<Token![=]>().is_ok() {
+                        reset_value = Some(input.call(Expr::parse_without_eager_brace)?);
+                    }
+                } else if input.parse::<kw::Msb0>().is_ok() {
+                    msb0 = true;
+                } else if let Ok(kw) = input.parse::<kw::WriteZero>() {
+                    if !is_int_ty(&storage_ty) {
+                        return Err(Error::new(
+                            kw.span,
+                            "`WriteZero` can only be used for bitfields with integer storage",
+                        ));
+                    }
+                    write_zero = true;
+                } else if input.parse::<kw::FieldConsts>().is_ok() {
+                    field_consts = true;
+                } else if let Ok(kw) = input.parse::<kw::Exhaustive>() {
+                    exhaustive = Some(kw.span);
                 } else {
                     break;
                 }
@@ -1117,6 +2480,13 @@ impl Parse for Struct {
             }
         }
 
+        if auto_impls.from_storage && auto_impls.try_from_storage {
+            return Err(Error::new(
+                try_from_storage_span,
+                "`TryFromStorage` conflicts with `FromStorage`",
+            ));
+        }
+
         if input.peek(Token![where]) {
             *generics.make_where_clause() = input.parse()?;
         };
@@ -1131,343 +2501,84 @@ impl Parse for Struct {
             content.call(Attribute::parse_inner)?.is_empty(),
             "Inner attributes are not supported right now"
         );
-        let fields = parse_terminated(&content, |input: ParseStream| {
-            let attrs = input.call(Attribute::parse_outer)?;
-            let vis = input.parse()?;
-            let ident = input.parse()?;
-            input.parse::<Token![:]>()?;
-            let is_nested = input.parse::<kw::nested>().is_ok();
-            let ty = input.parse::<Type>()?;
-
-            #[cfg(feature = "nightly")]
-            let mut uses_const_fns = fields_use_const_fns_by_default;
-
-            let content = if is_nested {
-                let mut is_readable = true;
-                let mut is_writable = true;
-                if let Ok(options_content) = parse_brackets(input) {
-                    macro_rules! check_accessor_conflict {
-                        ($ident: ident, $name: literal, $other: ident, $span: ident) => {
-                            if !$ident {
-                                return Err(Error::new(
-                                    $span,
-                                    concat!("Duplicate ", $name, " specifiers"),
-                                ));
-                            }
-                            if !$other {
-                                return Err(Error::new(
-                                    $span,
-                                    "Conflicting read_only and write_only specifiers",
-                                ));
-                            }
-                        };
-                    }
-
-                    while !options_content.is_empty() {
-                        let lookahead = options_content.lookahead1();
-                        if lookahead.peek(Token![const]) {
-                            check_has_nightly(input.span())?;
-                            #[cfg(feature = "nightly")]
-                            {
-                                uses_const_fns = true;
-                            }
-                        } else if lookahead.peek(kw::no_const) {
-                            #[cfg(feature = "nightly")]
-                            {
-                                uses_const_fns = false;
-                            }
-                        } else if lookahead.peek(kw::read_only) || lookahead.peek(kw::ro) {
-                            let span = options_content
-                                .parse::<kw::read_only>()
-                                .map(|kw| kw.span)
-                                .or_else(|_| {
-                                options_content.parse::<kw::ro>().map(|kw| kw.span)
-                            })?;
-                            check_accessor_conflict!(is_writable, "read_only", is_readable, span);
-                            is_writable = false;
-                        } else if lookahead.peek(kw::write_only) || lookahead.peek(kw::wo) {
-                            let span = options_content
-                                .parse::<kw::write_only>()
-                                .map(|kw| kw.span)
-                                .or_else(|_| options_content.parse::<kw::wo>().map(|kw| kw.span))?;
-                            check_accessor_conflict!(is_readable, "write_only", is_writable, span);
-                            is_readable = false;
-                        } else {
-                            return Err(lookahead.error());
-                        }
+        let (fields, write_fields) = if content.peek(kw::read) && content.peek2(token::Brace) {
+            content.parse::<kw::read>()?;
+            let read_fields =
+                parse_fields(&parse_braces(&content)?, fields_use_const_fns_by_default)?;
+            content.parse::<kw::write>()?;
+            let write_fields =
+                parse_fields(&parse_braces(&content)?, fields_use_const_fns_by_default)?;
+            if !content.is_empty() {
+                return Err(content.error("unexpected tokens after `write` section"));
+            }
+            (read_fields, Some(write_fields))
+        } else {
+            (
+                parse_fields(&content, fields_use_const_fns_by_default)?,
+                None,
+            )
+        };
 
-                        let had_comma = options_content.parse::<Token![,]>().is_ok();
-                        if !options_content.is_empty() && !had_comma {
-                            return Err(
-                                options_content.error("expected comma between field options")
-                            );
-                        }
-                    }
+        if is_slice {
+            if write_fields.is_some() {
+                return Err(Error::new_spanned(
+                    &storage_ty,
+                    "bitfields with slice storage can't have separate read and write layouts",
+                ));
+            }
+            let mut last_bits_span = None;
+            for field in &fields {
+                let Ok((bits_span, _)) = field.bits_spans(last_bits_span.as_ref()) else {
+                    break;
+                };
+                if matches!(bits_span, BitsSpan::Full) {
+                    return Err(Error::new(
+                        field.ident.span(),
+                        "`..` can't be used as a field range for bitfields with slice storage",
+                    ));
                 }
-                FieldContent::Nested(NestedField {
-                    is_readable,
-                    is_writable,
-                })
-            } else {
-                let mut get = AccessorKind::Default;
-                let mut set = AccessorKind::Default;
-                if let Ok(options_content) = parse_brackets(input) {
-                    macro_rules! check_conversion_ty_conflict {
-                                ($($ident: ident),*; $span: expr) => {
-                                    if $(!matches!(&$ident, AccessorKind::Default))||* {
-                                        return Err(Error::new(
-                                            $span,
-                                            "Conflicting conversion type definitions",
-                                        ));
-                                    }
-                                };
-                            }
-
-                    macro_rules! check_accessor_conflict {
-                        ($ident: ident, $name: literal, $other: ident, $span: ident) => {
-                            if matches!(&$ident, AccessorKind::Disabled) {
-                                return Err(Error::new(
-                                    $span,
-                                    concat!("Duplicate ", $name, " specifiers"),
-                                ));
-                            }
-                            if matches!(&$other, AccessorKind::Disabled) {
-                                return Err(Error::new(
-                                    $span,
-                                    "Conflicting read_only and write_only specifiers",
-                                ));
-                            }
-                        };
-                    }
-
-                    fn parse_return_ty(input: ParseStream) -> Result<Result<Type>> {
-                        if let Err(err) = input.parse::<Token![->]>() {
-                            return Ok(Err(err));
-                        }
-                        Ok(input.parse())
-                    }
+                last_bits_span = Some(bits_span);
+            }
+        }
 
-                    fn parse_parenthesized_ty(input: ParseStream) -> Result<Result<Type>> {
-                        Ok(match parse_parens(input) {
-                            Ok(content) => content.parse(),
-                            Err(err) => Err(err),
-                        })
-                    }
+        if let (Some((span, option)), Some(_)) = (single_layout_span, &write_fields) {
+            return Err(Error::new(
+                span,
+                format!("`{option}` can't be used with separate read and write layouts"),
+            ));
+        }
 
-                    while !options_content.is_empty() {
-                        if let Ok(kw) = options_content.parse::<Token![const]>() {
-                            check_has_nightly(kw.span())?;
-                            #[cfg(feature = "nightly")]
-                            {
-                                uses_const_fns = true;
-                            }
-                        } else if options_content.parse::<kw::no_const>().is_ok() {
-                            #[cfg(feature = "nightly")]
-                            {
-                                uses_const_fns = false;
-                            }
-                        }
-                        // Infallible conversions
-                        else if let Ok(kw) = options_content.parse::<kw::get>() {
-                            check_conversion_ty_conflict!(get; kw.span);
-                            get = AccessorKind::ConvTy(options_content.parse()?);
-                        } else if let Ok(kw) = options_content.parse::<kw::set>() {
-                            check_conversion_ty_conflict!(set; kw.span);
-                            set = AccessorKind::ConvTy(options_content.parse()?);
-                        }
-                        // Unsafe conversions
-                        else if let Ok(kw) = options_content.parse::<kw::unsafe_get>() {
-                            check_conversion_ty_conflict!(get; kw.span);
-                            let has_safe_accessor = options_content.parse::<Token![!]>().is_ok();
-                            get = AccessorKind::UnsafeConvTy {
-                                ty: options_content.parse()?,
-                                has_safe_accessor,
-                            };
-                        } else if let Ok(kw) = options_content.parse::<kw::unsafe_set>() {
-                            check_conversion_ty_conflict!(set; kw.span);
-                            let has_safe_accessor = options_content.parse::<Token![!]>().is_ok();
-                            set = AccessorKind::UnsafeConvTy {
-                                ty: options_content.parse()?,
-                                has_safe_accessor,
-                            };
-                        } else if let Ok(kw) = options_content.parse::<kw::unsafe_both>() {
-                            check_conversion_ty_conflict!(get, set; kw.span);
-                            let has_safe_accessor = options_content.parse::<Token![!]>().is_ok();
-                            let ty: Type = options_content.parse()?;
-                            get = AccessorKind::UnsafeConvTy {
-                                ty: ty.clone(),
-                                has_safe_accessor,
-                            };
-                            set = AccessorKind::UnsafeConvTy {
-                                ty,
-                                has_safe_accessor,
-                            };
-                        } else if let Ok(kw) = options_content.parse::<Token![unsafe]>() {
-                            check_conversion_ty_conflict!(get, set; kw.span);
-                            let has_safe_accessor = options_content.parse::<Token![!]>().is_ok();
-                            let ty: Type = options_content.parse()?;
-                            get = AccessorKind::UnsafeConvTy {
-                                ty: ty.clone(),
-                                has_safe_accessor,
-                            };
-                            set = AccessorKind::ConvTy(ty);
-                        }
-                        // Fallible conversions
-                        else if let Ok(kw) = options_content.parse::<kw::try_get>() {
-                            check_conversion_ty_conflict!(get; kw.span);
-                            get = AccessorKind::TryConvTy(options_content.parse()?);
-                        } else if let Ok(kw) = options_content.parse::<kw::try_set>() {
-                            check_conversion_ty_conflict!(set; kw.span);
-                            set = AccessorKind::TryConvTy(options_content.parse()?);
-                        } else if let Ok(kw) = options_content.parse::<kw::try_both>() {
-                            check_conversion_ty_conflict!(get, set; kw.span);
-                            let ty: Type = options_content.parse()?;
-                            get = AccessorKind::TryConvTy(ty.clone());
-                            set = AccessorKind::TryConvTy(ty);
-                        } else if let Ok(kw) = options_content.parse::<Token![try]>() {
-                            check_conversion_ty_conflict!(get, set; kw.span);
-                            let ty: Type = options_content.parse()?;
-                            get = AccessorKind::TryConvTy(ty.clone());
-                            set = AccessorKind::ConvTy(ty);
-                        }
-                        // Unwrapping conversions
-                        else if let Ok(kw) = options_content.parse::<kw::unwrap_get>() {
-                            check_conversion_ty_conflict!(get; kw.span);
-                            get = AccessorKind::UnwrapConvTy(options_content.parse()?);
-                        } else if let Ok(kw) = options_content.parse::<kw::unwrap_set>() {
-                            check_conversion_ty_conflict!(set; kw.span);
-                            set = AccessorKind::UnwrapConvTy(options_content.parse()?);
-                        } else if let Ok(kw) = options_content.parse::<kw::unwrap_both>() {
-                            check_conversion_ty_conflict!(get, set; kw.span);
-                            let ty: Type = options_content.parse()?;
-                            get = AccessorKind::UnwrapConvTy(ty.clone());
-                            set = AccessorKind::UnwrapConvTy(ty);
-                        } else if let Ok(kw) = options_content.parse::<kw::unwrap>() {
-                            check_conversion_ty_conflict!(get, set; kw.span);
-                            let ty: Type = options_content.parse()?;
-                            get = AccessorKind::UnwrapConvTy(ty.clone());
-                            set = AccessorKind::ConvTy(ty);
-                        }
-                        // Infallible fn conversions
-                        else if let Ok(kw) = options_content.parse::<kw::get_fn>() {
-                            check_conversion_ty_conflict!(get; kw.span);
-                            let fn_ = parse_accessor_fn(&options_content)?;
-                            let ty =
-                                parse_return_ty(&options_content)?.unwrap_or_else(|_| ty.clone());
-                            get = AccessorKind::ConvFn { fn_, ty };
-                        } else if let Ok(kw) = options_content.parse::<kw::set_fn>() {
-                            check_conversion_ty_conflict!(set; kw.span);
-                            let fn_ = parse_accessor_fn(&options_content)?;
-                            let ty = parse_parenthesized_ty(&options_content)?
-                                .unwrap_or_else(|_| ty.clone());
-                            set = AccessorKind::ConvFn { fn_, ty };
-                        }
-                        // Unsafe fn conversions
-                        else if let Ok(kw) = options_content.parse::<kw::unsafe_get_fn>() {
-                            check_conversion_ty_conflict!(get; kw.span);
-                            let has_safe_accessor = options_content.parse::<Token![!]>().is_ok();
-                            let fn_ = parse_accessor_fn(&options_content)?;
-                            let ty =
-                                parse_return_ty(&options_content)?.unwrap_or_else(|_| ty.clone());
-                            get = AccessorKind::UnsafeConvFn {
-                                fn_,
-                                ty,
-                                has_safe_accessor,
-                            };
-                        } else if let Ok(kw) = options_content.parse::<kw::unsafe_set_fn>() {
-                            check_conversion_ty_conflict!(set; kw.span);
-                            let has_safe_accessor = options_content.parse::<Token![!]>().is_ok();
-                            let fn_ = parse_accessor_fn(&options_content)?;
-                            let ty = parse_parenthesized_ty(&options_content)?
-                                .unwrap_or_else(|_| ty.clone());
-                            set = AccessorKind::UnsafeConvFn {
-                                fn_,
-                                ty,
-                                has_safe_accessor,
-                            };
-                        }
-                        // Fallible fn conversions
-                        else if let Ok(kw) = options_content.parse::<kw::try_get_fn>() {
-                            check_conversion_ty_conflict!(get; kw.span);
-                            let fn_ = parse_accessor_fn(&options_content)?;
-                            let result_ty = parse_return_ty(&options_content)??;
-                            get = AccessorKind::TryGetFn { fn_, result_ty };
-                        } else if let Ok(kw) = options_content.parse::<kw::try_set_fn>() {
-                            check_conversion_ty_conflict!(set; kw.span);
-                            let fn_ = parse_accessor_fn(&options_content)?;
-                            let input_ty = parse_parenthesized_ty(&options_content)?
-                                .unwrap_or_else(|_| ty.clone());
-                            let result_ty = parse_return_ty(&options_content)??;
-                            set = AccessorKind::TrySetFn {
-                                fn_,
-                                input_ty,
-                                result_ty,
-                            };
-                        }
-                        // Unwrapping fn conversions
-                        else if let Ok(kw) = options_content.parse::<kw::unwrap_get_fn>() {
-                            check_conversion_ty_conflict!(get; kw.span);
-                            let fn_ = parse_accessor_fn(&options_content)?;
-                            let ty =
-                                parse_return_ty(&options_content)?.unwrap_or_else(|_| ty.clone());
-                            get = AccessorKind::UnwrapConvFn { fn_, ty };
-                        } else if let Ok(kw) = options_content.parse::<kw::unwrap_set_fn>() {
-                            check_conversion_ty_conflict!(set; kw.span);
-                            let fn_ = parse_accessor_fn(&options_content)?;
-                            let ty = parse_parenthesized_ty(&options_content)?
-                                .unwrap_or_else(|_| ty.clone());
-                            set = AccessorKind::UnwrapConvFn { fn_, ty };
-                        }
-                        // Access restrictions
-                        else if let Ok(span) = options_content
-                            .parse::<kw::read_only>()
-                            .map(|kw| kw.span)
-                            .or_else(|_| options_content.parse::<kw::ro>().map(|kw| kw.span))
-                        {
-                            check_accessor_conflict!(set, "read_only", get, span);
-                            set = AccessorKind::Disabled;
-                        } else if let Ok(span) = options_content
-                            .parse::<kw::write_only>()
-                            .map(|kw| kw.span)
-                            .or_else(|_| options_content.parse::<kw::wo>().map(|kw| kw.span))
-                        {
-                            check_accessor_conflict!(get, "write_only", set, span);
-                            get = AccessorKind::Disabled;
-                        }
-                        // Infallible conversion (without keywords)
-                        else {
-                            let ty: Type = options_content.parse()?;
-                            check_conversion_ty_conflict!(get, set; ty.span());
-                            get = AccessorKind::ConvTy(ty.clone());
-                            set = AccessorKind::ConvTy(ty);
-                        }
+        if !is_int_ty(&storage_ty) {
+            if let Some(default) = fields
+                .iter()
+                .chain(write_fields.iter().flatten())
+                .find_map(|field| field.default.as_ref())
+            {
+                return Err(Error::new_spanned(
+                    default,
+                    "default values can only be specified for fields of bitfields with integer \
+                     storage",
+                ));
+            }
+        }
 
-                        let had_comma = options_content.parse::<Token![,]>().is_ok();
-                        if !options_content.is_empty() && !had_comma {
-                            return Err(
-                                options_content.error("expected comma between field options")
-                            );
-                        }
-                    }
-                }
-                FieldContent::Single(SingleField {
-                    get_kind: get,
-                    set_kind: set,
+        if !cfg!(feature = "nightly") {
+            if let Some(field) = fields
+                .iter()
+                .chain(write_fields.iter().flatten())
+                .find(|field| {
+                    field.has_const_getter() && !field.has_stable_const_accessors(&storage_ty)
                 })
-            };
-            input.parse::<Token![@]>()?;
-            let bits = input.parse()?;
-            Ok(Field {
-                attrs,
-                vis,
-                ident,
-                bits,
-                ty,
-                content,
-                #[cfg(feature = "nightly")]
-                uses_const_fns,
-            })
-        })?;
+            {
+                return Err(Error::new(
+                    field.ident.span(),
+                    "without the \"nightly\" feature, `const` accessors can only be generated for \
+                     integer and `bool` fields of bitfields with integer storage, using no \
+                     conversions or only `get_fn`, `set_fn`, `unsafe_get_fn`, `unsafe_set_fn` and \
+                     `try_get_fn` ones; use `no_const` to opt this field out",
+                ));
+            }
+        }
 
         Ok(Struct {
             outer_attrs,
@@ -1477,7 +2588,13 @@ impl Parse for Struct {
             storage_vis,
             storage_ty,
             auto_impls,
+            msb0,
+            write_zero,
+            field_consts,
+            exhaustive,
+            reset_value,
             fields,
+            write_fields,
         })
     }
 }
@@ -1529,7 +2646,9 @@ fn add_nested_impl_generics(
         } = field;
 
         match content {
-            FieldContent::Single(SingleField { get_kind, set_kind }) => {
+            FieldContent::Single(SingleField {
+                get_kind, set_kind, ..
+            }) => {
                 if matches!(bits, Bits::Single(_) | Bits::SinglePack { .. }) {
                     if !matches!(get_kind, AccessorKind::Disabled) && outer_is_readable {
                         bounds.push(
@@ -1608,6 +2727,8 @@ fn add_nested_impl_generics(
                     ]);
                 }
             }
+            // Reserved bits are only enforced by the top-level bitfield type
+            FieldContent::Reserved(_) => {}
         }
     }
 
@@ -1641,6 +2762,87 @@ fn add_nested_impl_generics(
     generics
 }
 
+/// Returns the given value as an integer literal if it can be evaluated at expansion time, so that
+/// the generated code doesn't have to rely on constant folding (which debug builds don't always
+/// perform).
+fn fold_const(value: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    match const_eval_tokens(value) {
+        Some(value) => proc_macro2::Literal::u128_unsuffixed(value).into_token_stream(),
+        None => quote! { (#value) },
+    }
+}
+
+/// Returns a mask of the bits in the given span, if its bounds can be evaluated at expansion time.
+fn const_span_mask(span: &BitsSpan, storage_ty: &Type) -> Option<u128> {
+    let storage_bits = const_size_of(storage_ty)?.checked_mul(8)?;
+    let (start, end) = match span {
+        BitsSpan::Single(bit) => {
+            let bit = const_eval_tokens(bit)?;
+            (bit, bit.checked_add(1)?)
+        }
+        BitsSpan::Range { start, end } => (const_eval_tokens(start)?, const_eval_tokens(end)?),
+        BitsSpan::Full => (0, storage_bits),
+    };
+    if start >= end || end > storage_bits {
+        return None;
+    }
+    let ones = u128::MAX >> (128 - (end - start));
+    Some(ones << start)
+}
+
+/// Returns a mask of the bits in all of the given spans, folding the ones whose bounds can be
+/// evaluated at expansion time into a single literal.
+fn spans_mask(spans: &[BitsSpan], storage_ty: &Type) -> proc_macro2::TokenStream {
+    let mut const_mask = 0;
+    let mut masks = Vec::new();
+    for span in spans {
+        match const_span_mask(span, storage_ty) {
+            Some(mask) => const_mask |= mask,
+            None => masks.push(span_mask(span, storage_ty)),
+        }
+    }
+    let const_mask = mask_lit(const_mask, storage_ty);
+    quote! { #const_mask #(| #masks)* }
+}
+
+/// Returns a literal of the given builtin integer storage type holding the bits of `mask`, which
+/// must fit in it; signed literals can't have their sign bit set, so they're negated instead.
+fn mask_lit(mask: u128, storage_ty: &Type) -> proc_macro2::TokenStream {
+    let suffix = match storage_ty {
+        Type::Path(ty) if is_int_ty(storage_ty) => ty.path.get_ident().map(Ident::to_string),
+        _ => None,
+    };
+    let storage_bits = const_size_of(storage_ty).map(|size| (size << 3) as u32);
+    match (suffix, storage_bits) {
+        (Some(suffix), Some(bits)) if suffix.starts_with('i') && mask >> (bits - 1) & 1 != 0 => {
+            let abs = mask.wrapping_neg() & (u128::MAX >> (128 - bits));
+            let lit = LitInt::new(&format!("{abs:#x}_{suffix}"), Span::call_site());
+            quote! { (-#lit) }
+        }
+        (Some(suffix), Some(_)) => {
+            let lit = LitInt::new(&format!("{mask:#x}_{suffix}"), Span::call_site());
+            quote! { #lit }
+        }
+        _ => {
+            let lit = LitInt::new(&format!("{mask:#x}_u128"), Span::call_site());
+            quote! { (#lit as #storage_ty) }
+        }
+    }
+}
+
+fn span_mask(span: &BitsSpan, storage_ty: &Type) -> proc_macro2::TokenStream {
+    if let Some(mask) = const_span_mask(span, storage_ty) {
+        return mask_lit(mask, storage_ty);
+    }
+    match span {
+        BitsSpan::Single(bit) => quote! { ((1 as #storage_ty) << (#bit)) },
+        BitsSpan::Range { start, end } => quote! {
+            (((1 as #storage_ty) << ((#end) - (#start) - 1) << 1).wrapping_sub(1) << (#start))
+        },
+        BitsSpan::Full => quote! { !(0 as #storage_ty) },
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn impl_bitfield_ty<'a>(
     outer_attrs: &[Attribute],
@@ -1653,11 +2855,15 @@ fn impl_bitfield_ty<'a>(
     storage_deref: &proc_macro2::TokenStream,
     storage_deref_ty: &Type,
     full_bits: &proc_macro2::TokenStream,
-    full_bits_are_const: bool,
+    msb0: bool,
+    write_zero: bool,
+    field_consts: bool,
+    checks_fixed_bits: bool,
     fields: &Punctuated<Field, Token![,]>,
     is_readable: bool,
     is_writable: bool,
     allows_with: bool,
+    reset_value: Option<&proc_macro2::TokenStream>,
     #[cfg(feature = "gce")] start_end_bits: Option<(
         &proc_macro2::TokenStream,
         &proc_macro2::TokenStream,
@@ -1671,80 +2877,2629 @@ fn impl_bitfield_ty<'a>(
     let type_params_phantom_data = if has_unused_type_params {
         quote! { , ::core::marker::PhantomData }
     } else {
-        quote! {}
+        quote! {}
+    };
+
+    #[cfg(feature = "gce")]
+    let is_nested = start_end_bits.is_some();
+    #[cfg(not(feature = "gce"))]
+    let is_nested = false;
+    let has_side_effects = fields
+        .iter()
+        .any(|field| field.side_effect() != SideEffect::None);
+    let has_fixed_bits = fields.iter().any(Field::is_reserved);
+    let sanitizes = is_writable && !is_nested && (has_side_effects || write_zero || has_fixed_bits);
+    let fixes = sanitizes && has_fixed_bits;
+    // Values read from the hardware can also be sanitized as a whole through `to_write`
+    let has_to_write =
+        sanitizes && !matches!(storage_ty, Type::Slice(_)) && (has_side_effects || write_zero);
+    let has_masks = !is_nested && is_int_ty(storage_ty);
+    let slice_elem_ty = match storage_ty {
+        Type::Slice(ty) => Some(&*ty.elem),
+        _ => None,
+    };
+
+    let mut w1c_masks = Vec::new();
+    let mut w1s_masks = Vec::new();
+    let mut rc_masks = Vec::new();
+    let mut writable_masks = Vec::new();
+    let mut all_fields_masks = Vec::new();
+    let mut sanitize_stmts = Vec::new();
+    let mut reserved_masks = Vec::new();
+    let mut fixed_masks = Vec::new();
+    let mut fixed_values = Vec::new();
+    let mut fix_stmts = Vec::new();
+    let mut fixed_bits_checks = Vec::new();
+    let mut layouts = Vec::new();
+    let mut reset_stmts = Vec::new();
+    let mut default_checks = Vec::new();
+    let mut reset_fns = Vec::new();
+    let mut field_ends = Vec::new();
+
+    let mut last_bits_span = None;
+    let field_fns = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let (bits_span, element_spans) = match field.bits_spans(last_bits_span.as_ref()) {
+                Ok(spans) => spans,
+                Err(err) => return err.to_compile_error(),
+            };
+            last_bits_span = Some(bits_span.clone());
+            if slice_elem_ty.is_some() {
+                field_ends.push(bits_span.to_start_end_or_full(full_bits).1.into_owned());
+            }
+
+            // Bit ranges are checked and packed in declaration order, and only then translated
+            // to LSB-0 numbering if needed
+            let (check_item, check) =
+                field.checks(i, &bits_span, element_spans.as_deref(), full_bits);
+            if !is_nested && !field.is_reserved() {
+                let (start, end) = bits_span.to_start_end_or_full(full_bits);
+                let (start, end) = (fold_const(&start), fold_const(&end));
+                let (lsb0_start, lsb0_end) = if msb0 {
+                    let lsb0_span = bits_span.to_msb0(full_bits);
+                    let (start, end) = lsb0_span.to_start_end_or_full(full_bits);
+                    (fold_const(&start), fold_const(&end))
+                } else {
+                    (start.clone(), end.clone())
+                };
+                let layout_ident = format_ident!("__layout_{}", field.ident);
+                let len = field.array_len.map_or(1, |(len, _)| len);
+                let field_is_nested = matches!(field.content, FieldContent::Nested(_));
+                let field_is_readable = is_readable && field.is_readable();
+                let field_is_writable = is_writable && field.is_writable();
+                let field_vis = &field.vis;
+                layouts.push(quote! {
+                    #[doc(hidden)]
+                    #[allow(non_upper_case_globals)]
+                    #field_vis const #layout_ident: ::proc_bitfield::__private::FieldLayout =
+                        ::proc_bitfield::__private::FieldLayout {
+                            start: #start,
+                            end: #end,
+                            lsb0_start: #lsb0_start,
+                            lsb0_end: #lsb0_end,
+                            len: #len,
+                            is_nested: #field_is_nested,
+                            is_readable: #field_is_readable,
+                            is_writable: #field_is_writable,
+                        };
+                });
+            }
+            let (bits_span, element_spans) = if msb0 {
+                (
+                    bits_span.to_msb0(full_bits),
+                    element_spans.map(|spans| {
+                        spans
+                            .iter()
+                            .map(|span| span.to_msb0(full_bits))
+                            .collect::<Vec<_>>()
+                    }),
+                )
+            } else {
+                (bits_span, element_spans)
+            };
+            let element_spans = element_spans.as_deref();
+
+            if let FieldContent::Reserved(ReservedField { fixed_value }) = &field.content {
+                if let (Some(value), true) = (fixed_value, checks_fixed_bits && !is_nested) {
+                    let raw_value = field.get_raw_value(
+                        &bits_span,
+                        &quote! { *storage },
+                        storage_ty,
+                        full_bits,
+                        #[cfg(feature = "gce")]
+                        start_end_bits,
+                    );
+                    fixed_bits_checks.push(if matches!(bits_span, BitsSpan::Single(_)) {
+                        quote! { (#raw_value) == (((#value) as u128) != 0) }
+                    } else {
+                        quote! { (#raw_value) == (#value) as u128 }
+                    });
+                }
+                if !fixes {
+                    return quote! {};
+                }
+                fix_stmts.push(check);
+                if has_masks {
+                    let mask = span_mask(&bits_span, storage_ty);
+                    let (bits_span_start, _) = bits_span.to_start_end_or_full(full_bits);
+                    match fixed_value {
+                        Some(value) => {
+                            fixed_values.push(quote! {
+                                ((((#value) as #storage_ty) << (#bits_span_start)) & #mask)
+                            });
+                            fixed_masks.push(bits_span.clone());
+                        }
+                        None => reserved_masks.push(bits_span.clone()),
+                    }
+                } else {
+                    let value = match (&bits_span, fixed_value) {
+                        (BitsSpan::Single(_), Some(value)) => quote! { ((#value) as u128) != 0 },
+                        (BitsSpan::Single(_), None) => quote! { false },
+                        (_, Some(value)) => quote! { (#value) as u128 },
+                        (_, None) => quote! { 0 },
+                    };
+                    fix_stmts.push({
+                        let set_raw_value = field
+                            .with_set_raw_value(
+                                &bits_span,
+                                &value,
+                                &quote! { *storage },
+                                storage_ty,
+                                full_bits,
+                                #[cfg(feature = "gce")]
+                                start_end_bits,
+                            )
+                            .1;
+                        quote! { #set_raw_value; }
+                    });
+                }
+                return check_item;
+            }
+
+            let spans = element_spans.unwrap_or(core::slice::from_ref(&bits_span));
+            if has_masks {
+                all_fields_masks.push(bits_span.clone());
+                let masks = match field.side_effect() {
+                    SideEffect::WriteOneToClear => Some(&mut w1c_masks),
+                    SideEffect::WriteOneToSet => Some(&mut w1s_masks),
+                    SideEffect::ReadToClear => Some(&mut rc_masks),
+                    SideEffect::None => {
+                        (is_writable && field.is_plain_writable()).then_some(&mut writable_masks)
+                    }
+                };
+                if let Some(masks) = masks {
+                    masks.extend(spans.iter().cloned());
+                }
+            }
+
+            if sanitizes {
+                // With `WriteZero`, all non-writable bits are cleared through `WRITABLE_MASK`
+                if !write_zero
+                    && matches!(
+                        field.side_effect(),
+                        SideEffect::WriteOneToClear | SideEffect::WriteOneToSet
+                    )
+                {
+                    sanitize_stmts.extend(spans.iter().map(|span| {
+                        let zero = if matches!(span, BitsSpan::Single(_)) {
+                            quote! { false }
+                        } else {
+                            quote! { 0 }
+                        };
+                        field
+                            .with_set_raw_value(
+                                span,
+                                &zero,
+                                &quote! { *storage },
+                                storage_ty,
+                                full_bits,
+                                #[cfg(feature = "gce")]
+                                start_end_bits,
+                            )
+                            .1
+                    }));
+                }
+            }
+
+            let layout_consts = (field_consts && !is_nested).then(|| {
+                field.layout_consts(&bits_span, element_spans, storage_ty, full_bits, has_masks)
+            });
+
+            if let (Some(_), true) = (reset_value, has_masks) {
+                let field_ty = &field.ty;
+                if let Some(default) = &field.default {
+                    for span in spans {
+                        let mask = span_mask(span, storage_ty);
+                        let (start, end) = span.to_start_end_or_full(full_bits);
+                        reset_stmts.push(quote! {
+                            storage = storage & !#mask
+                                | ((((#default) as #field_ty) as #storage_ty) << (#start)) & #mask;
+                        });
+                        let fits = if matches!(field_ty, Type::Path(ty) if ty.path.is_ident("bool"))
+                        {
+                            // Values are still type-checked
+                            quote! {{
+                                let _ = value;
+                                true
+                            }}
+                        } else if type_name(field_ty).starts_with('i') {
+                            quote! {
+                                (#end) - (#start) >= #field_ty::BITS as usize || {
+                                    let value = value as i128 >> ((#end) - (#start) - 1);
+                                    value == 0 || value == -1
+                                }
+                            }
+                        } else {
+                            quote! {
+                                (#end) - (#start) >= #field_ty::BITS as usize
+                                    || value as u128 >> ((#end) - (#start)) == 0
+                            }
+                        };
+                        let msg = format!(
+                            "the default value of field `{}` doesn't fit in its bits",
+                            field.ident
+                        );
+                        default_checks.push(quote_spanned! {
+                            default.span() =>
+                            #[allow(clippy::identity_op, unused_braces)]
+                            const _: () = {
+                                let value: #field_ty = #default;
+                                ::core::assert!(#fits, #msg);
+                            };
+                        });
+                    }
+                }
+                if is_writable && field.is_plain_writable() {
+                    let Field { vis, ident, .. } = field;
+                    let reset_fn_ident = format_ident!("reset_{}", ident);
+                    let mask = spans_mask(spans, storage_ty);
+                    let const_ = field.has_const_setter().then(|| quote! { const });
+                    let doc = format!(
+                        "Resets the `{ident}` field to its value in [`RESET`](Self::RESET)."
+                    );
+                    reset_fns.push(quote! {
+                        #[doc = #doc]
+                        #[inline]
+                        #[allow(clippy::identity_op, unused_braces)]
+                        #vis #const_ fn #reset_fn_ident(&mut self) {
+                            let mask: #storage_ty = #mask;
+                            self.0 = self.0 & !mask | Self::RESET.0 & mask;
+                        }
+                    });
+                }
+            }
+
+            let getters = is_readable.then(|| {
+                field.getters(
+                    &bits_span,
+                    element_spans,
+                    &check,
+                    storage_deref,
+                    storage_deref_ty,
+                    full_bits,
+                    #[cfg(feature = "gce")]
+                    start_end_bits,
+                    #[cfg(feature = "gce")]
+                    storage_needs_const_bounds,
+                )
+            });
+
+            let setters = is_writable.then(|| {
+                field.setters(
+                    &bits_span,
+                    element_spans,
+                    &check,
+                    storage_deref,
+                    storage_deref_ty,
+                    full_bits,
+                    is_readable,
+                    allows_with,
+                    sanitizes,
+                    fixes,
+                    #[cfg(feature = "gce")]
+                    start_end_bits,
+                    #[cfg(feature = "gce")]
+                    storage_needs_const_bounds,
+                )
+            });
+
+            quote! {
+                #check_item
+                #layout_consts
+                #getters
+                #setters
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let type_params_phantom_data_field_ty = if has_unused_type_params {
+        quote! { , ::core::marker::PhantomData<(#(#unused_type_params),*)> }
+    } else {
+        quote! {}
+    };
+
+    let masks = has_masks.then(|| {
+        // Side-effect masks are only declared for the kinds of side effects the fields have
+        let side_effect_mask = |spans: &[BitsSpan], ident: &str, doc: &str| {
+            (!spans.is_empty()).then(|| {
+                let ident = Ident::new(ident, Span::call_site());
+                let mask = spans_mask(spans, storage_ty);
+                quote! {
+                    #[doc = #doc]
+                    #vis const #ident: #storage_ty = #mask;
+                }
+            })
+        };
+        let w1c_mask = side_effect_mask(
+            &w1c_masks,
+            "W1C_MASK",
+            "A mask of the bits of the bitfield's write-1-to-clear fields.",
+        );
+        let w1s_mask = side_effect_mask(
+            &w1s_masks,
+            "W1S_MASK",
+            "A mask of the bits of the bitfield's write-1-to-set fields.",
+        );
+        let rc_mask = side_effect_mask(
+            &rc_masks,
+            "RC_MASK",
+            "A mask of the bits of the bitfield's read-to-clear fields.",
+        );
+        let [writable_masks, all_fields_masks] =
+            [&writable_masks, &all_fields_masks].map(|spans| spans_mask(spans, storage_ty));
+        quote! {
+            #w1c_mask
+
+            #w1s_mask
+
+            #rc_mask
+
+            /// A mask of the bits of the bitfield's writable fields, excluding those whose writes
+            /// have side effects.
+            #vis const WRITABLE_MASK: #storage_ty = #writable_masks;
+
+            /// A mask of the bits of all of the bitfield's fields, excluding reserved and fixed
+            /// bits.
+            #vis const ALL_FIELDS_MASK: #storage_ty = #all_fields_masks;
+        }
+    });
+
+    let fixed_masks = (has_masks && fixes).then(|| {
+        let reserved_masks = spans_mask(&reserved_masks, storage_ty);
+        let fixed_masks = spans_mask(&fixed_masks, storage_ty);
+        quote! {
+            /// A mask of the bitfield's reserved bits, which are always written as zero.
+            #vis const RESERVED_MASK: #storage_ty = #reserved_masks;
+
+            /// A mask of the bitfield's fixed bits, which are always written with the values in
+            /// `FIXED_VALUE`.
+            #vis const FIXED_MASK: #storage_ty = #fixed_masks;
+
+            /// The values of the bitfield's fixed bits.
+            #vis const FIXED_VALUE: #storage_ty = 0 #(| #fixed_values)*;
+        }
+    });
+
+    let fix_fn = fixes.then(|| {
+        let (const_, fix_stmt) = if has_masks {
+            (
+                Some(quote! { const }),
+                quote! {
+                    *storage = *storage & !(Self::RESERVED_MASK | Self::FIXED_MASK)
+                        | Self::FIXED_VALUE;
+                },
+            )
+        } else {
+            (
+                fields
+                    .iter()
+                    .any(Field::has_const_setter)
+                    .then(|| quote! { const }),
+                quote! {},
+            )
+        };
+        quote! {
+            #[inline(always)]
+            #[allow(unused_braces, clippy::identity_op)]
+            #const_ fn __fix_storage(storage: &mut #storage_ty) {
+                #(#fix_stmts)*
+                #fix_stmt
+            }
+        }
+    });
+
+    let reset = reset_value.filter(|_| has_masks).map(|reset_value| {
+        let fix_stmt = fixes.then(|| quote! { Self::__fix_storage(&mut storage); });
+        quote! {
+            /// The reset value of the bitfield, with every field set to its default value.
+            #[allow(clippy::identity_op, unused_braces)]
+            #vis const RESET: Self = {
+                #[allow(unused_mut)]
+                let mut storage: #storage_ty = #reset_value;
+                #(#reset_stmts)*
+                #fix_stmt
+                Self::__from_storage(storage)
+            };
+
+            #(#reset_fns)*
+        }
+    });
+
+    let check_fixed_bits_fn = (checks_fixed_bits && !is_nested).then(|| {
+        quote! {
+            #[inline(always)]
+            #[allow(unused_braces, clippy::identity_op)]
+            fn __has_valid_fixed_bits(storage: &#storage_ty) -> bool {
+                true #(&& #fixed_bits_checks)*
+            }
+        }
+    });
+
+    let const_ = fields
+        .iter()
+        .any(Field::has_const_setter)
+        .then(|| quote! { const });
+
+    let sanitize_fn = sanitizes.then(|| {
+        let write_zero_stmt = write_zero.then(|| quote! { *storage &= Self::WRITABLE_MASK; });
+        let fix_stmt = fixes.then(|| quote! { Self::__fix_storage(storage); });
+        quote! {
+            #[inline(always)]
+            #[allow(unused_braces)]
+            #const_ fn __sanitize_storage(storage: &mut #storage_ty) {
+                #(#sanitize_stmts;)*
+                #write_zero_stmt
+                #fix_stmt
+            }
+        }
+    });
+
+    let to_write_fn = has_to_write.then(|| {
+        let doc = if write_zero {
+            quote! {
+                /// Returns the value to write back to the hardware after reading this one, with
+                /// all bits but those of writable fields without side effects cleared.
+            }
+        } else {
+            quote! {
+                /// Returns the value to write back to the hardware after reading this one, with
+                /// the bits of write-1-to-clear and write-1-to-set fields cleared so that writing
+                /// it doesn't acknowledge pending events or trigger new ones.
+            }
+        };
+        quote! {
+            #doc
+            ///
+            /// Setters of fields without side effects already do this; it's needed before calling
+            /// `clear_x` and write-1-to-set setters on a read value, or to write it back unchanged.
+            #[inline]
+            #[must_use]
+            #vis #const_ fn to_write(self) -> Self {
+                let mut result = self;
+                Self::__sanitize_storage(&mut result.0);
+                result
+            }
+        }
+    });
+
+    // Bitfields with slice storage are unsized, so they can only be created by reference from
+    // slices long enough to hold every field
+    let constructors = match slice_elem_ty {
+        Some(elem_ty) => {
+            let min_bits = field_ends.into_iter().fold(quote! { 0 }, |max, end| {
+                quote! { ::proc_bitfield::__private::max(#max, #end) }
+            });
+            quote! {
+                /// The minimum length of the storage slice, covering the highest bit of every
+                /// field.
+                #[allow(clippy::identity_op, unused_braces)]
+                pub const MIN_LEN: usize =
+                    (#min_bits).div_ceil(::core::mem::size_of::<#elem_ty>() << 3);
+
+                /// Returns a reference to the bitfield stored in the given slice, or `None` if
+                /// it's shorter than [`MIN_LEN`](Self::MIN_LEN).
+                #[inline]
+                pub const fn from_slice(slice: &#storage_ty) -> ::core::option::Option<&Self> {
+                    if slice.len() < Self::MIN_LEN {
+                        return ::core::option::Option::None;
+                    }
+                    // SAFETY: The bitfield struct is `#[repr(transparent)]` over the slice.
+                    ::core::option::Option::Some(unsafe {
+                        &*(slice as *const #storage_ty as *const Self)
+                    })
+                }
+
+                /// Returns a mutable reference to the bitfield stored in the given slice, or
+                /// `None` if it's shorter than [`MIN_LEN`](Self::MIN_LEN).
+                #[inline]
+                pub const fn from_mut_slice(
+                    slice: &mut #storage_ty,
+                ) -> ::core::option::Option<&mut Self> {
+                    if slice.len() < Self::MIN_LEN {
+                        return ::core::option::Option::None;
+                    }
+                    // SAFETY: The bitfield struct is `#[repr(transparent)]` over the slice.
+                    ::core::option::Option::Some(unsafe {
+                        &mut *(slice as *mut #storage_ty as *mut Self)
+                    })
+                }
+            }
+        }
+        None => quote! {
+            #[inline(always)]
+            const fn __from_storage(storage: #storage_ty) -> Self {
+                Self(storage #type_params_phantom_data)
+            }
+        },
+    };
+
+    quote! {
+        #(#outer_attrs)*
+        #[repr(transparent)]
+        #vis struct #ident #generics(
+            #storage_vis #storage_ty #type_params_phantom_data_field_ty
+        ) #where_clause;
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #constructors
+
+            #masks
+
+            #fixed_masks
+
+            #sanitize_fn
+
+            #to_write_fn
+
+            #fix_fn
+
+            #check_fixed_bits_fn
+
+            #(#layouts)*
+
+            #reset
+
+            #(#field_fns)*
+        }
+
+        #(#default_checks)*
+    }
+}
+
+fn impl_volatile(
+    vis: &Visibility,
+    ident: &Ident,
+    generics: &Generics,
+    storage_ty: &Type,
+    fields: &Punctuated<Field, Token![,]>,
+    write_layout: Option<(&Ident, &Punctuated<Field, Token![,]>)>,
+    write_zero: bool,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ty = quote! { #ident #ty_generics };
+    let write_ty = match write_layout {
+        Some((write_ident, _)) => quote! { #write_ident #ty_generics },
+        None => ty.clone(),
+    };
+    let reg_ident = format_ident!("{}Reg", ident);
+
+    // With split layouts, only read layout fields are read and only write layout fields are
+    // written
+    let field_fns = match write_layout {
+        Some((_, write_fields)) => fields
+            .iter()
+            .map(|field| (field, true, false))
+            .chain(write_fields.iter().map(|field| (field, false, true)))
+            .collect::<Vec<_>>(),
+        None => fields.iter().map(|field| (field, true, true)).collect(),
+    };
+    let field_fns = field_fns
+        .into_iter()
+        .map(|(field, is_readable, is_writable)| {
+            let Field {
+                attrs,
+                vis,
+                ident,
+                content,
+                array_len,
+                ..
+            } = field;
+            let FieldContent::Single(SingleField {
+                get_kind, set_kind, ..
+            }) = content
+            else {
+                return quote! {};
+            };
+            let (index_param, index_arg) = if array_len.is_some() {
+                (quote! { index: usize, }, quote! { index, })
+            } else {
+                (quote! {}, quote! {})
+            };
+
+            let reader = (is_readable && !matches!(get_kind, AccessorKind::Disabled)).then(|| {
+                let read_fn_ident = format_ident!("read_{}", ident);
+                let (_, output_ty) = field.get_conversion(get_kind);
+                let unsafe_ = get_kind.is_unsafe().then(|| quote! { unsafe });
+                quote! {
+                    #(#attrs)*
+                    #[inline]
+                    #vis #unsafe_ fn #read_fn_ident(&self, #index_param) -> #output_ty {
+                        #unsafe_ { self.read().#ident(#index_arg) }
+                    }
+                }
+            });
+
+            let writer = (is_writable && !matches!(set_kind, AccessorKind::Disabled)).then(|| {
+                let (set_fn_ident, with_fn_ident) = field.setter_idents();
+                let write_fn_ident = if field.side_effect() == SideEffect::WriteOneToClear {
+                    set_fn_ident
+                } else {
+                    format_ident!("write_{}", ident)
+                };
+                let SetConversion {
+                    input_ty,
+                    set_ok,
+                    set_output_ty,
+                    is_fallible,
+                    ..
+                } = field.set_conversion(set_kind);
+                let unsafe_ = set_kind.is_unsafe().then(|| quote! { unsafe });
+                let try_ = is_fallible.then(|| quote! { ? });
+                let (value_param, value_arg) = if field.setters_take_value() {
+                    (quote! { value: #input_ty }, quote! { value })
+                } else {
+                    (quote! {}, quote! {})
+                };
+                quote! {
+                    #(#attrs)*
+                    #[inline]
+                    #vis #unsafe_ fn #write_fn_ident(&self, #index_param #value_param)
+                        -> #set_output_ty
+                    {
+                        let value = #unsafe_ {
+                            <#write_ty>::__from_storage(::core::default::Default::default())
+                                .#with_fn_ident(#index_arg #value_arg)
+                        }#try_;
+                        self.write(value);
+                        #set_ok
+                    }
+                }
+            });
+
+            quote! {
+                #reader
+                #writer
+            }
+        });
+
+    // Values read from a register have their reserved and fixed bits forced to their values, so
+    // that they can be written back safely, unless they're read views of split layouts
+    let (mut_, fix_stmt) = if write_layout.is_none() && fields.iter().any(Field::is_reserved) {
+        (
+            quote! { mut },
+            quote! { <#ty>::__fix_storage(&mut storage); },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    let modifier = write_layout.is_none().then(|| {
+        let has_side_effects = fields
+            .iter()
+            .any(|field| field.side_effect() != SideEffect::None);
+        if has_side_effects || write_zero {
+            quote! {
+                /// Reads the register's value, turns it into a value to write back through
+                #[doc = ::core::concat!("[`", ::core::stringify!(#ident), "::to_write`],")]
+                /// modifies it using the given function and writes the result back, performing a
+                /// single volatile read followed by a single volatile write.
+                #[inline]
+                pub fn modify(&self, f: impl ::core::ops::FnOnce(#ty) -> #ty) {
+                    self.write(f(self.read().to_write()));
+                }
+            }
+        } else {
+            quote! {
+                /// Reads the register's value, modifies it using the given function and writes
+                /// the result back, performing a single volatile read followed by a single
+                /// volatile write.
+                #[inline]
+                pub fn modify(&self, f: impl ::core::ops::FnOnce(#ty) -> #ty) {
+                    self.write(f(self.read()));
+                }
+            }
+        }
+    });
+
+    quote! {
+        /// A wrapper around a pointer to a memory-mapped register, performing volatile accesses
+        #[doc = ::core::concat!("to it as a [`", ::core::stringify!(#ident), "`].")]
+        #vis struct #reg_ident #generics(*mut #storage_ty, ::core::marker::PhantomData<#ty>)
+            #where_clause;
+
+        impl #impl_generics #reg_ident #ty_generics #where_clause {
+            /// Creates a new register wrapper accessing the given address.
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must be properly aligned and valid for volatile reads and writes for as long
+            /// as the returned value is used.
+            #[inline]
+            pub const unsafe fn new(ptr: *mut #storage_ty) -> Self {
+                Self(ptr, ::core::marker::PhantomData)
+            }
+
+            /// Returns the address of the register.
+            #[inline]
+            pub const fn as_ptr(&self) -> *mut #storage_ty {
+                self.0
+            }
+
+            /// Reads the register's value with a single volatile read.
+            #[inline]
+            pub fn read(&self) -> #ty {
+                // SAFETY: `self.0` is valid for volatile reads as required by `new`.
+                let #mut_ storage = unsafe { self.0.read_volatile() };
+                #fix_stmt
+                <#ty>::__from_storage(storage)
+            }
+
+            /// Writes the given value to the register with a single volatile write.
+            #[inline]
+            pub fn write(&self, value: #write_ty) {
+                // SAFETY: `self.0` is valid for volatile writes as required by `new`.
+                unsafe { self.0.write_volatile(value.0) }
+            }
+
+            #modifier
+
+            #(#field_fns)*
+        }
+    }
+}
+
+fn impl_atomic(
+    vis: &Visibility,
+    ident: &Ident,
+    generics: &Generics,
+    storage_ty: &Type,
+    fields: &Punctuated<Field, Token![,]>,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ty = quote! { #ident #ty_generics };
+    let atomic_ident = format_ident!("Atomic{}", ident);
+    let atomic_ty_ident = atomic_ty(storage_ty).unwrap();
+    let atomic_ty = quote! { ::core::sync::atomic::#atomic_ty_ident };
+    let ordering = quote! { ::core::sync::atomic::Ordering };
+
+    // Only fields that can be read and written back through the same type get atomic operations,
+    // which replace their bits with ones computed beforehand by a setter, so that their values
+    // don't need to be cloned
+    let field_fns = fields.iter().filter_map(|field| {
+        let Field {
+            attrs, vis, ident, ..
+        } = field;
+        let value_ty = field.descriptor_value_ty()?;
+        let (_, with_fn_ident) = field.setter_idents();
+        let layout_ident = format_ident!("__layout_{}", ident);
+        let fetch_set_fn_ident = format_ident!("fetch_set_{}", ident);
+        let swap_fn_ident = format_ident!("swap_{}", ident);
+        let compare_exchange_fn_ident = format_ident!("compare_exchange_{}", ident);
+        let mask = quote! {{
+            let layout = <#ty>::#layout_ident;
+            ((1 as #storage_ty) << (layout.lsb0_end - layout.lsb0_start - 1) << 1).wrapping_sub(1)
+                << layout.lsb0_start
+        }};
+        let field_bits = |value: &proc_macro2::TokenStream| {
+            quote! { <#ty>::__from_storage(0).#with_fn_ident(#value).0 & #mask }
+        };
+        let value_bits = field_bits(&quote! { value });
+        let current_bits = field_bits(&quote! { current });
+        let new_bits = field_bits(&quote! { new });
+        Some(quote! {
+            #(#attrs)*
+            ///
+            /// Atomically sets this field to the given value, leaving the other bits unchanged,
+            /// and returns the previous value of the bitfield.
+            #[inline]
+            #vis fn #fetch_set_fn_ident(&self, value: #value_ty, order: #ordering) -> #ty {
+                let value_bits = #value_bits;
+                let result = self.0.fetch_update(
+                    order,
+                    ::proc_bitfield::__private::failure_ordering(order),
+                    |storage| ::core::option::Option::Some(
+                        (storage & !#mask) | value_bits
+                    ),
+                );
+                match result {
+                    ::core::result::Result::Ok(storage) | ::core::result::Result::Err(storage) => {
+                        <#ty>::__from_storage(storage)
+                    }
+                }
+            }
+
+            #(#attrs)*
+            ///
+            /// Atomically sets this field to the given value, leaving the other bits unchanged,
+            /// and returns its previous value.
+            #[inline]
+            #vis fn #swap_fn_ident(&self, value: #value_ty, order: #ordering) -> #value_ty {
+                self.#fetch_set_fn_ident(value, order).#ident()
+            }
+
+            #(#attrs)*
+            ///
+            /// Atomically sets this field to `new` if its bits are the same as `current`'s,
+            /// leaving the other bits unchanged; returns the field's previous value, wrapped in
+            /// `Ok` if it was replaced and in `Err` otherwise.
+            #[inline]
+            #vis fn #compare_exchange_fn_ident(
+                &self,
+                current: #value_ty,
+                new: #value_ty,
+                success: #ordering,
+                failure: #ordering,
+            ) -> ::core::result::Result<#value_ty, #value_ty> {
+                let current_bits = #current_bits;
+                let new_bits = #new_bits;
+                let mut storage = self.0.load(failure);
+                loop {
+                    if storage & #mask != current_bits {
+                        return ::core::result::Result::Err(<#ty>::__from_storage(storage).#ident());
+                    }
+                    match self.0.compare_exchange_weak(
+                        storage,
+                        (storage & !#mask) | new_bits,
+                        success,
+                        failure,
+                    ) {
+                        ::core::result::Result::Ok(storage) => {
+                            return ::core::result::Result::Ok(
+                                <#ty>::__from_storage(storage).#ident(),
+                            );
+                        }
+                        ::core::result::Result::Err(actual) => storage = actual,
+                    }
+                }
+            }
+        })
+    });
+
+    quote! {
+        /// An atomic container for a
+        #[doc = ::core::concat!(
+            "[`", ::core::stringify!(#ident), "`], which can be safely shared between threads, ",
+            "stored as a [`", ::core::stringify!(#atomic_ty_ident), "`](",
+            "core::sync::atomic::", ::core::stringify!(#atomic_ty_ident), ")."
+        )]
+        #[repr(transparent)]
+        #vis struct #atomic_ident #generics(#atomic_ty, ::core::marker::PhantomData<#ty>)
+            #where_clause;
+
+        impl #impl_generics #atomic_ident #ty_generics #where_clause {
+            /// Creates a new atomic bitfield holding the given value.
+            #[inline]
+            pub const fn new(value: #ty) -> Self {
+                Self(<#atomic_ty>::new(value.0), ::core::marker::PhantomData)
+            }
+
+            /// Consumes the atomic bitfield and returns the value it holds.
+            #[inline]
+            pub fn into_inner(self) -> #ty {
+                <#ty>::__from_storage(self.0.into_inner())
+            }
+
+            /// Returns a reference to the underlying atomic storage.
+            #[inline]
+            pub const fn as_atomic(&self) -> &#atomic_ty {
+                &self.0
+            }
+
+            /// Loads the bitfield's value.
+            #[inline]
+            pub fn load(&self, order: #ordering) -> #ty {
+                <#ty>::__from_storage(self.0.load(order))
+            }
+
+            /// Stores the given value into the bitfield.
+            #[inline]
+            pub fn store(&self, value: #ty, order: #ordering) {
+                self.0.store(value.0, order)
+            }
+
+            /// Stores the given value into the bitfield, returning the previous one.
+            #[inline]
+            pub fn swap(&self, value: #ty, order: #ordering) -> #ty {
+                <#ty>::__from_storage(self.0.swap(value.0, order))
+            }
+
+            /// Stores `new` into the bitfield if its storage is the same as `current`'s; returns
+            /// the previous value, wrapped in `Ok` if it was replaced and in `Err` otherwise.
+            #[inline]
+            pub fn compare_exchange(
+                &self,
+                current: #ty,
+                new: #ty,
+                success: #ordering,
+                failure: #ordering,
+            ) -> ::core::result::Result<#ty, #ty> {
+                match self.0.compare_exchange(current.0, new.0, success, failure) {
+                    ::core::result::Result::Ok(storage) => {
+                        ::core::result::Result::Ok(<#ty>::__from_storage(storage))
+                    }
+                    ::core::result::Result::Err(storage) => {
+                        ::core::result::Result::Err(<#ty>::__from_storage(storage))
+                    }
+                }
+            }
+
+            /// Atomically replaces the bitfield's value with the result of calling `f` on it,
+            /// retrying with the new value if it was changed concurrently, and returns the
+            /// previous value.
+            ///
+            /// `f` may be called multiple times; `set_order` and `fetch_order` are used for the
+            /// final write and for the reads respectively.
+            #[inline]
+            pub fn fetch_update(
+                &self,
+                set_order: #ordering,
+                fetch_order: #ordering,
+                mut f: impl ::core::ops::FnMut(#ty) -> #ty,
+            ) -> #ty {
+                let result = self.0.fetch_update(set_order, fetch_order, |storage| {
+                    ::core::option::Option::Some(f(<#ty>::__from_storage(storage)).0)
+                });
+                match result {
+                    ::core::result::Result::Ok(storage) | ::core::result::Result::Err(storage) => {
+                        <#ty>::__from_storage(storage)
+                    }
+                }
+            }
+
+            #(#field_fns)*
+        }
+    }
+}
+
+fn impl_cell(
+    vis: &Visibility,
+    ident: &Ident,
+    generics: &Generics,
+    storage_ty: &Type,
+    fields: &Punctuated<Field, Token![,]>,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ty = quote! { #ident #ty_generics };
+    let cell_ident = format_ident!("{}Cell", ident);
+    let mut impl_where_clause = where_clause.cloned();
+    if !generics.params.is_empty() {
+        impl_where_clause
+            .get_or_insert_with(|| parse_quote! { where })
+            .predicates
+            .push(parse_quote! { #storage_ty: ::core::marker::Copy });
+    }
+
+    let field_fns = fields.iter().map(|field| {
+        let Field {
+            attrs,
+            vis,
+            content,
+            array_len,
+            ..
+        } = field;
+        let FieldContent::Single(SingleField { set_kind, .. }) = content else {
+            return quote! {};
+        };
+        if matches!(set_kind, AccessorKind::Disabled) {
+            return quote! {};
+        }
+        let (index_param, index_arg) = if array_len.is_some() {
+            (quote! { index: usize, }, quote! { index, })
+        } else {
+            (quote! {}, quote! {})
+        };
+        let (set_fn_ident, with_fn_ident) = field.setter_idents();
+        let SetConversion {
+            input_ty,
+            set_ok,
+            set_output_ty,
+            is_fallible,
+            ..
+        } = field.set_conversion(set_kind);
+        let unsafe_ = set_kind.is_unsafe().then(|| quote! { unsafe });
+        let try_ = is_fallible.then(|| quote! { ? });
+        let (value_param, value_arg) = if field.setters_take_value() {
+            (quote! { value: #input_ty }, quote! { value })
+        } else {
+            (quote! {}, quote! {})
+        };
+        quote! {
+            #(#attrs)*
+            #[inline]
+            #vis #unsafe_ fn #set_fn_ident(&self, #index_param #value_param) -> #set_output_ty {
+                let value = #unsafe_ { self.get().#with_fn_ident(#index_arg #value_arg) }#try_;
+                self.set(value);
+                #set_ok
+            }
+        }
+    });
+
+    quote! {
+        /// A mutable memory location holding a
+        #[doc = ::core::concat!(
+            "[`", ::core::stringify!(#ident), "`], which can be modified through shared ",
+            "references like a [`Cell`](core::cell::Cell)."
+        )]
+        #[repr(transparent)]
+        #vis struct #cell_ident #generics(
+            ::core::cell::Cell<#storage_ty>,
+            ::core::marker::PhantomData<#ty>,
+        ) #where_clause;
+
+        impl #impl_generics #cell_ident #ty_generics #impl_where_clause {
+            /// Creates a new cell holding the given value.
+            #[inline]
+            pub const fn new(value: #ty) -> Self {
+                Self(::core::cell::Cell::new(value.0), ::core::marker::PhantomData)
+            }
+
+            /// Consumes the cell and returns the value it holds.
+            #[inline]
+            pub fn into_inner(self) -> #ty {
+                <#ty>::__from_storage(self.0.into_inner())
+            }
+
+            /// Returns the cell's value.
+            #[inline]
+            pub fn get(&self) -> #ty {
+                <#ty>::__from_storage(self.0.get())
+            }
+
+            /// Sets the cell's value.
+            #[inline]
+            pub fn set(&self, value: #ty) {
+                self.0.set(value.0)
+            }
+
+            /// Sets the cell's value, returning the previous one.
+            #[inline]
+            pub fn replace(&self, value: #ty) -> #ty {
+                <#ty>::__from_storage(self.0.replace(value.0))
+            }
+
+            /// Replaces the cell's value with the result of calling `f` on it.
+            #[inline]
+            pub fn update(&self, f: impl ::core::ops::FnOnce(#ty) -> #ty) {
+                self.set(f(self.get()));
+            }
+
+            #(#field_fns)*
+        }
+    }
+}
+
+fn impl_views(
+    vis: &Visibility,
+    ident: &Ident,
+    generics: &Generics,
+    storage_ty: &Type,
+    full_bits: &proc_macro2::TokenStream,
+    fields: &Punctuated<Field, Token![,]>,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ty = quote! { #ident #ty_generics };
+    let ref_ident = format_ident!("{}Ref", ident);
+    let mut_ident = format_ident!("{}Mut", ident);
+
+    // Views borrow their storage for `'_storage` and can be placed in any `_Storage` value
+    // supporting run-time bit ranges, defaulting to the bitfield's own storage type
+    let mut view_generics = generics.clone();
+    view_generics.params.insert(0, parse_quote! { '_storage });
+    view_generics
+        .params
+        .push(parse_quote! { _Storage: ?::core::marker::Sized = #storage_ty });
+    let (view_impl_generics, view_ty_generics, _) = view_generics.split_for_impl();
+    let mut storage_ty_generics = generics.clone();
+    storage_ty_generics
+        .params
+        .insert(0, parse_quote! { '_storage });
+    let (storage_impl_generics, _, _) = storage_ty_generics.split_for_impl();
+    let generic_args = generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Type(param) => param.ident.to_token_stream(),
+            GenericParam::Const(param) => param.ident.to_token_stream(),
+            GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+        })
+        .collect::<Vec<_>>();
+    let storage_view_ty_generics = quote! { <'_storage, #(#generic_args,)* #storage_ty> };
+    let view_where_clause = |bounds: proc_macro2::TokenStream| {
+        let mut where_clause = where_clause
+            .cloned()
+            .unwrap_or_else(|| parse_quote! { where });
+        where_clause
+            .predicates
+            .push(parse_quote! { _Storage: #bounds });
+        where_clause
+    };
+    let ref_where_clause = view_where_clause(quote! {
+        ::proc_bitfield::DynBits<#storage_ty>
+    });
+    let mut_where_clause = view_where_clause(quote! {
+        ::proc_bitfield::DynBits<#storage_ty> + ::proc_bitfield::DynSetBits<#storage_ty>
+    });
+    let view_ty = |view_ident: &Ident, nested_ty: &Type, storage_ty: proc_macro2::TokenStream| {
+        let assoc_ident = if *view_ident == ref_ident {
+            quote! { Ref }
+        } else {
+            quote! { Mut }
+        };
+        quote! {
+            <#nested_ty as ::proc_bitfield::Viewable>::#assoc_ident<'_, #storage_ty>
+        }
+    };
+
+    // Nested fields get accessors returning views of the same storage, both in the bitfield itself
+    // (unless the `gce` feature provides its own) and in its views
+    let nested_accessors = |view_ident: &Ident, is_bitfield: bool| {
+        let is_mut = *view_ident == mut_ident;
+        fields
+            .iter()
+            .filter_map(|field| {
+                let Field {
+                    attrs,
+                    vis,
+                    ident,
+                    ty: field_ty,
+                    content,
+                    ..
+                } = field;
+                let FieldContent::Nested(NestedField {
+                    is_readable,
+                    is_writable,
+                }) = content
+                else {
+                    return None;
+                };
+                if !*is_readable || (is_mut && !*is_writable) {
+                    return None;
+                }
+                let (fn_ident, ref_, view_trait) = if is_mut {
+                    (
+                        format_ident!("{}_mut", ident),
+                        quote! { &mut },
+                        quote! { NestedMutView },
+                    )
+                } else {
+                    (
+                        format_ident!("{}_ref", ident),
+                        quote! { & },
+                        quote! { NestedView },
+                    )
+                };
+                let layout_ident = format_ident!("__layout_{}", ident);
+                let (storage, parent_range, view_storage_ty) = if is_bitfield {
+                    (
+                        quote! { #ref_ self.0 },
+                        quote! { 0..#full_bits },
+                        storage_ty.to_token_stream(),
+                    )
+                } else {
+                    (
+                        quote! { #ref_ *self.storage },
+                        quote! { self.start..self.end },
+                        quote! { _Storage },
+                    )
+                };
+                let output_ty = view_ty(view_ident, field_ty, view_storage_ty);
+                Some(quote! {
+                    #(#attrs)*
+                    #[inline]
+                    #vis fn #fn_ident(#ref_ self) -> #output_ty {
+                        ::proc_bitfield::__private::#view_trait::__from_storage(
+                            #storage,
+                            ::proc_bitfield::__private::nested_view_range(
+                                #parent_range,
+                                <#ty>::#layout_ident.lsb0_start..<#ty>::#layout_ident.lsb0_end,
+                                ::core::mem::size_of::<
+                                    <#field_ty as ::proc_bitfield::Bitfield>::Storage
+                                >() << 3,
+                            ),
+                        )
+                    }
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let getters = fields
+        .iter()
+        .map(|field| {
+            let Field {
+                attrs,
+                vis,
+                ident,
+                ty: field_ty,
+                content,
+                array_len,
+                ..
+            } = field;
+            match content {
+                FieldContent::Single(SingleField { get_kind, .. }) => {
+                    if matches!(get_kind, AccessorKind::Disabled) {
+                        return quote! {};
+                    }
+                    let (index_param, index_arg) = if array_len.is_some() {
+                        (quote! { index: usize }, quote! { index })
+                    } else {
+                        (quote! {}, quote! {})
+                    };
+                    let (_, output_ty) = field.get_conversion(get_kind);
+                    let unsafe_ = get_kind.is_unsafe().then(|| quote! { unsafe });
+                    quote! {
+                        #(#attrs)*
+                        #[inline]
+                        #vis #unsafe_ fn #ident(&self, #index_param) -> #output_ty {
+                            #unsafe_ { self.get().#ident(#index_arg) }
+                        }
+                    }
+                }
+                FieldContent::Nested(NestedField { is_readable, .. }) => {
+                    if !*is_readable {
+                        return quote! {};
+                    }
+                    quote! {
+                        #(#attrs)*
+                        #[inline]
+                        #vis fn #ident(&self) -> #field_ty {
+                            self.get().#ident()
+                        }
+                    }
+                }
+                FieldContent::Reserved(_) => quote! {},
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let setters = fields.iter().map(|field| {
+        let Field {
+            attrs,
+            vis,
+            ty: field_ty,
+            content,
+            array_len,
+            ..
+        } = field;
+        let (set_fn_ident, with_fn_ident) = field.setter_idents();
+        match content {
+            FieldContent::Single(SingleField { set_kind, .. }) => {
+                if matches!(set_kind, AccessorKind::Disabled) {
+                    return quote! {};
+                }
+                let (index_param, index_arg) = if array_len.is_some() {
+                    (quote! { index: usize, }, quote! { index, })
+                } else {
+                    (quote! {}, quote! {})
+                };
+                let SetConversion {
+                    input_ty,
+                    set_ok,
+                    set_output_ty,
+                    is_fallible,
+                    ..
+                } = field.set_conversion(set_kind);
+                let unsafe_ = set_kind.is_unsafe().then(|| quote! { unsafe });
+                let try_ = is_fallible.then(|| quote! { ? });
+                let (value_param, value_arg) = if field.setters_take_value() {
+                    (quote! { value: #input_ty }, quote! { value })
+                } else {
+                    (quote! {}, quote! {})
+                };
+                quote! {
+                    #(#attrs)*
+                    #[inline]
+                    #vis #unsafe_ fn #set_fn_ident(&mut self, #index_param #value_param)
+                        -> #set_output_ty
+                    {
+                        let value =
+                            #unsafe_ { self.get().#with_fn_ident(#index_arg #value_arg) }#try_;
+                        self.set(value);
+                        #set_ok
+                    }
+                }
+            }
+            FieldContent::Nested(NestedField { is_writable, .. }) => {
+                if !*is_writable {
+                    return quote! {};
+                }
+                quote! {
+                    #(#attrs)*
+                    #[inline]
+                    #vis fn #set_fn_ident(&mut self, value: #field_ty) {
+                        let value = self.get().#with_fn_ident(value);
+                        self.set(value);
+                    }
+                }
+            }
+            FieldContent::Reserved(_) => quote! {},
+        }
+    });
+
+    let ref_nested_accessors = nested_accessors(&ref_ident, false);
+    let mut_nested_accessors = nested_accessors(&ref_ident, false)
+        .into_iter()
+        .chain(nested_accessors(&mut_ident, false));
+    let bitfield_nested_accessors = if cfg!(feature = "gce") {
+        Vec::new()
+    } else {
+        nested_accessors(&ref_ident, true)
+            .into_iter()
+            .chain(nested_accessors(&mut_ident, true))
+            .collect()
+    };
+
+    let view_fns = |view_ident: &Ident, ref_: proc_macro2::TokenStream| {
+        let view_trait = if *view_ident == ref_ident {
+            quote! { NestedView }
+        } else {
+            quote! { NestedMutView }
+        };
+        quote! {
+            impl #storage_impl_generics #view_ident #storage_view_ty_generics #where_clause {
+                /// Creates a view of the bitfield stored in the given value of its storage type.
+                #[inline]
+                pub fn from_storage(storage: #ref_ #storage_ty) -> Self {
+                    Self {
+                        storage,
+                        start: 0,
+                        end: #full_bits,
+                        _bitfield: ::core::marker::PhantomData,
+                    }
+                }
+            }
+
+            impl #view_impl_generics ::proc_bitfield::__private::#view_trait<'_storage, _Storage>
+                for #view_ident #view_ty_generics #where_clause
+            {
+                #[inline(always)]
+                fn __from_storage(
+                    storage: #ref_ _Storage,
+                    range: ::core::ops::Range<usize>,
+                ) -> Self {
+                    Self {
+                        storage,
+                        start: range.start,
+                        end: range.end,
+                        _bitfield: ::core::marker::PhantomData,
+                    }
+                }
+            }
+        }
+    };
+    let new_fn = |ref_: proc_macro2::TokenStream| {
+        quote! {
+            /// Creates a view of the bitfield stored starting at bit `offset` of `storage`, or
+            /// returns a [`BitRangeError`](::proc_bitfield::BitRangeError) if it doesn't fit.
+            #[inline]
+            pub fn new(
+                storage: #ref_ _Storage,
+                offset: usize,
+            ) -> ::core::result::Result<Self, ::proc_bitfield::BitRangeError> {
+                let range =
+                    ::proc_bitfield::__private::dyn_range_offset_and_length(offset, #full_bits);
+                <_Storage as ::proc_bitfield::DynBits<#storage_ty>>::dyn_bits(
+                    &*storage,
+                    range.clone(),
+                )?;
+                ::core::result::Result::Ok(Self {
+                    storage,
+                    start: range.start,
+                    end: range.end,
+                    _bitfield: ::core::marker::PhantomData,
+                })
+            }
+
+            /// Returns the range of bits of the storage occupied by the bitfield.
+            #[inline]
+            pub fn bit_range(&self) -> ::core::ops::Range<usize> {
+                self.start..self.end
+            }
+
+            /// Reads the bitfield's value from its storage.
+            #[inline]
+            pub fn get(&self) -> #ty {
+                if self.start == self.end {
+                    return <#ty>::__from_storage(0);
+                }
+                match <_Storage as ::proc_bitfield::DynBits<#storage_ty>>::dyn_bits(
+                    &*self.storage,
+                    self.start..self.end,
+                ) {
+                    ::core::result::Result::Ok(storage) => <#ty>::__from_storage(storage),
+                    ::core::result::Result::Err(err) => ::core::panic!("{}", err),
+                }
+            }
+        }
+    };
+    let ref_fns = view_fns(&ref_ident, quote! { &'_storage });
+    let mut_fns = view_fns(&mut_ident, quote! { &'_storage mut });
+    let ref_new_fn = new_fn(quote! { &'_storage });
+    let mut_new_fn = new_fn(quote! { &'_storage mut });
+
+    quote! {
+        /// A shared view of a
+        #[doc = ::core::concat!(
+            "[`", ::core::stringify!(#ident), "`] stored in place in a range of bits of a ",
+            "`_Storage` value, such as a word inside a larger buffer or another bitfield's storage."
+        )]
+        #vis struct #ref_ident #view_generics #where_clause {
+            storage: &'_storage _Storage,
+            start: usize,
+            end: usize,
+            _bitfield: ::core::marker::PhantomData<#ty>,
+        }
+
+        impl #view_impl_generics ::core::clone::Clone for #ref_ident #view_ty_generics
+            #where_clause
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl #view_impl_generics ::core::marker::Copy for #ref_ident #view_ty_generics
+            #where_clause
+        {
+        }
+
+        impl #view_impl_generics #ref_ident #view_ty_generics #ref_where_clause {
+            #ref_new_fn
+
+            #(#getters)*
+
+            #(#ref_nested_accessors)*
+        }
+
+        #ref_fns
+
+        /// A mutable view of a
+        #[doc = ::core::concat!(
+            "[`", ::core::stringify!(#ident), "`] stored in place in a range of bits of a ",
+            "`_Storage` value, such as a word inside a larger buffer or another bitfield's storage."
+        )]
+        #vis struct #mut_ident #view_generics #where_clause {
+            storage: &'_storage mut _Storage,
+            start: usize,
+            end: usize,
+            _bitfield: ::core::marker::PhantomData<#ty>,
+        }
+
+        impl #view_impl_generics #mut_ident #view_ty_generics #mut_where_clause {
+            #mut_new_fn
+
+            /// Writes the given value to the bitfield's storage, leaving the bits around it
+            /// unchanged.
+            #[inline]
+            pub fn set(&mut self, value: #ty) {
+                if self.start == self.end {
+                    return;
+                }
+                if let ::core::result::Result::Err(err) =
+                    <_Storage as ::proc_bitfield::DynSetBits<#storage_ty>>::dyn_set_bits(
+                        &mut *self.storage,
+                        self.start..self.end,
+                        value.0,
+                    )
+                {
+                    ::core::panic!("{}", err);
+                }
+            }
+
+            /// Reads the bitfield's value, modifies it using the given function and writes the
+            /// result back.
+            #[inline]
+            pub fn modify(&mut self, f: impl ::core::ops::FnOnce(#ty) -> #ty) {
+                let value = f(self.get());
+                self.set(value);
+            }
+
+            /// Returns a shared view of the same bits.
+            #[inline]
+            pub fn as_ref_view(&self) -> #ref_ident<'_, #(#generic_args,)* _Storage> {
+                #ref_ident {
+                    storage: &*self.storage,
+                    start: self.start,
+                    end: self.end,
+                    _bitfield: ::core::marker::PhantomData,
+                }
+            }
+
+            /// Returns a mutable view of the same bits, borrowing this one.
+            #[inline]
+            pub fn reborrow(&mut self) -> #mut_ident<'_, #(#generic_args,)* _Storage> {
+                #mut_ident {
+                    storage: &mut *self.storage,
+                    start: self.start,
+                    end: self.end,
+                    _bitfield: ::core::marker::PhantomData,
+                }
+            }
+
+            #(#getters)*
+
+            #(#setters)*
+
+            #(#mut_nested_accessors)*
+        }
+
+        #mut_fns
+
+        impl #impl_generics ::proc_bitfield::Viewable for #ty #where_clause {
+            type Ref<'_storage, _Storage: ?::core::marker::Sized + '_storage> =
+                #ref_ident #view_ty_generics;
+            type Mut<'_storage, _Storage: ?::core::marker::Sized + '_storage> =
+                #mut_ident #view_ty_generics;
+        }
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #(#bitfield_nested_accessors)*
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn impl_reflect(
+    ident: &Ident,
+    generics: &Generics,
+    storage_ty: &Type,
+    fields: &Punctuated<Field, Token![,]>,
+    full_bits: &proc_macro2::TokenStream,
+    msb0: bool,
+    is_readable: bool,
+    is_writable: bool,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut where_clause = where_clause.cloned();
+    if !generics.params.is_empty() {
+        where_clause
+            .get_or_insert_with(|| parse_quote! { where })
+            .predicates
+            .push(parse_quote! {
+                #storage_ty: ::proc_bitfield::Bits<u128> + ::proc_bitfield::SetBits<u128>
+            });
+    }
+
+    let mut infos = Vec::new();
+    let mut get_arms = Vec::new();
+    let mut set_arms = Vec::new();
+    let mut last_bits_span = None;
+    for field in fields {
+        let Ok((bits_span, _)) = field.bits_spans(last_bits_span.as_ref()) else {
+            // Invalid spans are already reported when generating accessors
+            return quote! {};
+        };
+        last_bits_span = Some(bits_span.clone());
+        if field.is_reserved() {
+            continue;
+        }
+        let bits_span = if msb0 {
+            bits_span.to_msb0(full_bits)
+        } else {
+            bits_span
+        };
+        let (start, end) = bits_span.to_start_end_or_full(full_bits);
+
+        let index = infos.len();
+        let name = field.ident.to_string();
+        let name = name.trim_start_matches("r#");
+        let len = field.array_len.map_or(1, |(len, _)| len);
+        let access = match (
+            is_readable && field.is_readable(),
+            is_writable && field.is_writable(),
+        ) {
+            (true, false) => quote! { ReadOnly },
+            (false, true) => quote! { WriteOnly },
+            _ => quote! { ReadWrite },
+        };
+        let field_ty = &field.ty;
+        let ty_name = type_name(field_ty);
+        let doc = field
+            .attrs
+            .iter()
+            .filter_map(|attr| match &attr.meta {
+                syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    value:
+                        Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(doc),
+                            ..
+                        }),
+                    ..
+                }) if path.is_ident("doc") => Some(doc.value()),
+                _ => None,
+            })
+            .map(|line| line.strip_prefix(' ').map(str::to_string).unwrap_or(line))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let is_nested = matches!(field.content, FieldContent::Nested(_));
+        let nested = if is_nested {
+            if !generics.params.is_empty() {
+                let where_clause = where_clause.as_mut().unwrap();
+                where_clause
+                    .predicates
+                    .push(parse_quote! { #field_ty: ::proc_bitfield::Reflect });
+                where_clause.predicates.push(parse_quote! {
+                    #storage_ty: ::proc_bitfield::Bits<<#field_ty as ::proc_bitfield::Bitfield>::Storage>
+                        + ::proc_bitfield::SetBits<<#field_ty as ::proc_bitfield::Bitfield>::Storage>
+                });
+            }
+            quote! {
+                ::core::option::Option::Some(
+                    <#field_ty as ::proc_bitfield::Reflect>::FIELDS
+                )
+            }
+        } else {
+            quote! { ::core::option::Option::None }
+        };
+        infos.push(quote! {
+            ::proc_bitfield::FieldInfo {
+                name: #name,
+                bits: (#start)..(#end),
+                len: #len,
+                access: ::proc_bitfield::FieldAccess::#access,
+                ty: #ty_name,
+                doc: #doc,
+                nested: #nested,
+            }
+        });
+
+        let nested_get = is_nested.then(|| {
+            quote! {
+                ::core::option::Option::Some(path) => {
+                    let nested = <#storage_ty as ::proc_bitfield::Bits<
+                        <#field_ty as ::proc_bitfield::Bitfield>::Storage
+                    >>::bits::<{#start}, {#end}>(storage);
+                    <#field_ty as ::proc_bitfield::Reflect>::__get_raw_by_name(&nested, path)
+                }
+            }
+        });
+        get_arms.push(quote! {
+            #index => match path {
+                ::core::option::Option::None => ::core::option::Option::Some(
+                    <#storage_ty as ::proc_bitfield::Bits<u128>>::bits::<{#start}, {#end}>(
+                        storage
+                    )
+                ),
+                #nested_get
+                _ => ::core::option::Option::None,
+            },
+        });
+        let nested_set = is_nested.then(|| {
+            quote! {
+                ::core::option::Option::Some(path) => {
+                    let mut nested = <#storage_ty as ::proc_bitfield::Bits<
+                        <#field_ty as ::proc_bitfield::Bitfield>::Storage
+                    >>::bits::<{#start}, {#end}>(storage);
+                    if !<#field_ty as ::proc_bitfield::Reflect>::__set_raw_by_name(
+                        &mut nested,
+                        path,
+                        value,
+                    ) {
+                        return false;
+                    }
+                    <#storage_ty as ::proc_bitfield::SetBits<
+                        <#field_ty as ::proc_bitfield::Bitfield>::Storage
+                    >>::set_bits::<{#start}, {#end}>(storage, nested);
+                    true
+                }
+            }
+        });
+        set_arms.push(quote! {
+            #index => match path {
+                ::core::option::Option::None => {
+                    <#storage_ty as ::proc_bitfield::SetBits<u128>>::set_bits::<{#start}, {#end}>(
+                        storage,
+                        value,
+                    );
+                    true
+                }
+                #nested_set
+                _ => false,
+            },
+        });
+    }
+
+    quote! {
+        impl #impl_generics ::proc_bitfield::Reflect for #ident #ty_generics #where_clause {
+            const FIELDS: &'static [::proc_bitfield::FieldInfo] = &[#(#infos),*];
+
+            #[inline]
+            fn __storage(&self) -> &#storage_ty {
+                &self.0
+            }
+
+            #[inline]
+            fn __storage_mut(&mut self) -> &mut #storage_ty {
+                &mut self.0
+            }
+
+            #[allow(clippy::identity_op, unused_braces)]
+            fn __get_raw(
+                storage: &#storage_ty,
+                index: usize,
+                path: ::core::option::Option<&str>,
+            ) -> ::core::option::Option<u128> {
+                match index {
+                    #(#get_arms)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[allow(clippy::identity_op, unused_braces)]
+            fn __set_raw(
+                storage: &mut #storage_ty,
+                index: usize,
+                path: ::core::option::Option<&str>,
+                value: u128,
+            ) -> bool {
+                match index {
+                    #(#set_arms)*
+                    _ => false,
+                }
+            }
+        }
+    }
+}
+
+fn impl_descriptors(
+    ident: &Ident,
+    generics: &Generics,
+    fields: &Punctuated<Field, Token![,]>,
+    full_bits: &proc_macro2::TokenStream,
+    msb0: bool,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ty = quote! { #ident #ty_generics };
+
+    let mut descriptors = Vec::new();
+    let mut consts = Vec::new();
+    let mut last_bits_span = None;
+    for field in fields {
+        let Ok((bits_span, _)) = field.bits_spans(last_bits_span.as_ref()) else {
+            // Invalid spans are already reported when generating accessors
+            return quote! {};
+        };
+        last_bits_span = Some(bits_span.clone());
+        let Some(value_ty) = field.descriptor_value_ty() else {
+            continue;
+        };
+        let bits_span = if msb0 {
+            bits_span.to_msb0(full_bits)
+        } else {
+            bits_span
+        };
+        let (start, end) = bits_span.to_start_end_or_full(full_bits);
+
+        let Field {
+            vis,
+            ident: field_ident,
+            ..
+        } = field;
+        let name = field_ident.to_string();
+        let name = name.trim_start_matches("r#");
+        let descriptor_ident = format_ident!("__{}_{}", ident, name);
+        let const_ident = Ident::new(&name.to_uppercase(), field_ident.span());
+        let (set_fn_ident, with_fn_ident) = field.setter_idents();
+
+        descriptors.push(quote! {
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+            #vis struct #descriptor_ident;
+
+            impl #impl_generics ::proc_bitfield::FieldDescriptor<#ty> for #descriptor_ident
+                #where_clause
+            {
+                type Value = #value_ty;
+
+                const NAME: &'static str = #name;
+
+                #[allow(clippy::identity_op)]
+                const RANGE: ::core::ops::Range<usize> = (#start)..(#end);
+
+                #[inline]
+                fn get(self, bitfield: &#ty) -> #value_ty {
+                    bitfield.#field_ident()
+                }
+
+                #[inline]
+                fn set(self, bitfield: &mut #ty, value: #value_ty) {
+                    bitfield.#set_fn_ident(value)
+                }
+
+                #[inline]
+                fn with(self, bitfield: #ty, value: #value_ty) -> #ty {
+                    bitfield.#with_fn_ident(value)
+                }
+            }
+        });
+
+        let doc = format!("The descriptor of the `{name}` field.");
+        consts.push(quote! {
+            #[doc = #doc]
+            #vis const #const_ident: #descriptor_ident = #descriptor_ident;
+        });
+    }
+
+    quote! {
+        #(#descriptors)*
+
+        impl #impl_generics #ty #where_clause {
+            #(#consts)*
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn impl_unpacked(
+    outer_attrs: &[Attribute],
+    vis: &Visibility,
+    ident: &Ident,
+    fields: &Punctuated<Field, Token![,]>,
+    is_readable: bool,
+    is_writable: bool,
+    default_value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let unpacked_ident = format_ident!("{}Unpacked", ident);
+    let derives = outer_attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"));
+
+    let mut unpacked_fields = Vec::new();
+    let mut unpack_values = Vec::new();
+    let mut pack_stmts = Vec::new();
+    let mut unpack_is_fallible = false;
+    let mut pack_is_fallible = false;
+    let mut errors = Vec::new();
+
+    // Fields whose writes have side effects are treated as read-only, and unsafe accessors are
+    // never used
+    for field in fields {
+        let Field {
+            attrs,
+            vis: field_vis,
+            ident: field_ident,
+            ty: field_ty,
+            content,
+            array_len,
+            ..
+        } = field;
+        let name = field_ident.to_string();
+        let name = name.trim_start_matches("r#").to_string();
+        let error = quote! { ::proc_bitfield::FieldConversionError::new(#name) };
+        let (set_fn_ident, _) = field.setter_idents();
+
+        // The unpacked value's type, whether reading and writing it are fallible, and the type
+        // of the getter's values, if it needs to be checked against the setter's
+        let (value_ty, get_is_fallible, set_is_fallible, is_readable, is_writable) = match content {
+            FieldContent::Single(SingleField {
+                get_kind, set_kind, ..
+            }) => {
+                let is_readable = is_readable && field.is_readable() && !get_kind.is_unsafe();
+                let is_writable = is_writable && field.is_plain_writable() && !set_kind.is_unsafe();
+                let (get_value_ty, get_is_fallible) = if is_readable {
+                    match get_kind {
+                        AccessorKind::TryConvTy(ty) => (Some(ty.to_token_stream()), true),
+                        AccessorKind::TryGetFn { result_ty, .. } => {
+                            if is_writable {
+                                (None, true)
+                            } else {
+                                (Some(result_ty.to_token_stream()), false)
+                            }
+                        }
+                        _ => (Some(field.get_conversion(get_kind).1), false),
+                    }
+                } else {
+                    (None, false)
+                };
+                let set_conversion = is_writable.then(|| field.set_conversion(set_kind));
+                let value_ty = match (&set_conversion, get_value_ty) {
+                    (Some(set_conversion), get_value_ty) => {
+                        let input_ty = set_conversion.input_ty.to_token_stream();
+                        if let Some(get_value_ty) = get_value_ty {
+                            if get_value_ty.to_string() != input_ty.to_string() {
+                                errors.push(Error::new(
+                                    field_ident.span(),
+                                    format!(
+                                        "field `{name}` can't be unpacked, as its getter and \
+                                         setter use different types"
+                                    ),
+                                ));
+                                continue;
+                            }
+                        }
+                        input_ty
+                    }
+                    (None, Some(get_value_ty)) => get_value_ty,
+                    (None, None) => continue,
+                };
+                (
+                    value_ty,
+                    get_is_fallible,
+                    set_conversion.is_some_and(|conversion| conversion.is_fallible),
+                    is_readable,
+                    is_writable,
+                )
+            }
+            FieldContent::Nested(_) => {
+                let is_readable = is_readable && field.is_readable();
+                let is_writable = is_writable && field.is_writable();
+                if !is_readable && !is_writable {
+                    continue;
+                }
+                (
+                    quote! { <#field_ty as ::proc_bitfield::Unpack>::Unpacked },
+                    false,
+                    false,
+                    is_readable,
+                    is_writable,
+                )
+            }
+            FieldContent::Reserved(_) => continue,
+        };
+        unpack_is_fallible |= get_is_fallible;
+        pack_is_fallible |= set_is_fallible;
+
+        let value_ty = match array_len {
+            Some((len, _)) => quote! { [#value_ty; #len] },
+            None => value_ty,
+        };
+        let doc_attrs = attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+        if is_readable {
+            unpacked_fields.push(quote! {
+                #(#doc_attrs)*
+                #field_vis #field_ident: #value_ty,
+            });
+        } else {
+            unpacked_fields.push(quote! {
+                #(#doc_attrs)*
+                #field_vis #field_ident: ::core::option::Option<#value_ty>,
+            });
+        }
+
+        let is_nested = matches!(content, FieldContent::Nested(_));
+        let unpack_value = |value: proc_macro2::TokenStream| {
+            if is_nested {
+                // Nested bitfields' conversions are only allowed to fail if the outer ones can
+                quote! { ::core::convert::From::from(#value) }
+            } else if get_is_fallible {
+                quote! {
+                    match ::proc_bitfield::__private::IntoOption::into_option(#value) {
+                        ::core::option::Option::Some(value) => value,
+                        ::core::option::Option::None => {
+                            return ::core::result::Result::Err(#error);
+                        }
+                    }
+                }
+            } else {
+                value
+            }
+        };
+        unpack_values.push(if is_readable {
+            match array_len {
+                Some((len, _)) => {
+                    let values =
+                        (0..*len).map(|i| unpack_value(quote! { bitfield.#field_ident(#i) }));
+                    quote! { #field_ident: [#(#values),*], }
+                }
+                None => {
+                    let value = unpack_value(quote! { bitfield.#field_ident() });
+                    quote! { #field_ident: #value, }
+                }
+            }
+        } else {
+            quote! { #field_ident: ::core::option::Option::None, }
+        });
+
+        if is_writable {
+            let set = |index: Option<proc_macro2::TokenStream>| {
+                if is_nested {
+                    quote! {
+                        bitfield.#set_fn_ident(#index ::core::convert::From::from(value));
+                    }
+                } else if set_is_fallible {
+                    quote! {
+                        if ::proc_bitfield::__private::IntoOption::into_option(
+                            bitfield.#set_fn_ident(#index value),
+                        )
+                        .is_none()
+                        {
+                            return ::core::result::Result::Err(#error);
+                        }
+                    }
+                } else {
+                    quote! { bitfield.#set_fn_ident(#index value); }
+                }
+            };
+            let set = match array_len {
+                Some(_) => {
+                    let set = set(Some(quote! { index, }));
+                    quote! {
+                        for (index, value) in ::core::iter::IntoIterator::into_iter(values)
+                            .enumerate()
+                        {
+                            #set
+                        }
+                    }
+                }
+                None => set(None),
+            };
+            let value_ident = if array_len.is_some() {
+                quote! { values }
+            } else {
+                quote! { value }
+            };
+            pack_stmts.push(if is_readable {
+                quote! {{
+                    let #value_ident = unpacked.#field_ident;
+                    #set
+                }}
+            } else {
+                quote! {
+                    if let ::core::option::Option::Some(#value_ident) = unpacked.#field_ident {
+                        #set
+                    }
+                }
+            });
+        }
+    }
+
+    if let Some(error) = errors.into_iter().reduce(|mut errors, error| {
+        errors.combine(error);
+        errors
+    }) {
+        return error.to_compile_error();
+    }
+
+    let unpacked_doc =
+        format!("The unpacked form of [`{ident}`], with one field per bitfield field.");
+    let unpack_impl = if unpack_is_fallible {
+        quote! {
+            impl ::core::convert::TryFrom<#ident> for #unpacked_ident {
+                type Error = ::proc_bitfield::FieldConversionError;
+
+                fn try_from(bitfield: #ident) -> ::core::result::Result<Self, Self::Error> {
+                    ::core::result::Result::Ok(Self {
+                        #(#unpack_values)*
+                    })
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl ::core::convert::From<#ident> for #unpacked_ident {
+                fn from(bitfield: #ident) -> Self {
+                    Self {
+                        #(#unpack_values)*
+                    }
+                }
+            }
+        }
+    };
+    let pack_impl = is_writable.then(|| {
+        if pack_is_fallible {
+            quote! {
+                impl ::core::convert::TryFrom<#unpacked_ident> for #ident {
+                    type Error = ::proc_bitfield::FieldConversionError;
+
+                    #[allow(unused_mut)]
+                    fn try_from(
+                        unpacked: #unpacked_ident,
+                    ) -> ::core::result::Result<Self, Self::Error> {
+                        let mut bitfield = #default_value;
+                        #(#pack_stmts)*
+                        ::core::result::Result::Ok(bitfield)
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl ::core::convert::From<#unpacked_ident> for #ident {
+                    #[allow(unused_mut)]
+                    fn from(unpacked: #unpacked_ident) -> Self {
+                        let mut bitfield = #default_value;
+                        #(#pack_stmts)*
+                        bitfield
+                    }
+                }
+            }
+        }
+    });
+    let unpack_impl = is_readable.then_some(unpack_impl);
+
+    quote! {
+        #[doc = #unpacked_doc]
+        #(#derives)*
+        #vis struct #unpacked_ident {
+            #(#unpacked_fields)*
+        }
+
+        impl ::proc_bitfield::Unpack for #ident {
+            type Unpacked = #unpacked_ident;
+        }
+
+        #unpack_impl
+
+        #pack_impl
+    }
+}
+
+fn impl_new(
+    ident: &Ident,
+    generics: &Generics,
+    storage_ty: &Type,
+    fields: &Punctuated<Field, Token![,]>,
+    default_value: &proc_macro2::TokenStream,
+    default_value_is_const: bool,
+    has_reset: bool,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut params = Vec::new();
+    let mut stmts = Vec::new();
+    let mut is_fallible = false;
+    let mut is_unsafe = false;
+    let mut is_const = default_value_is_const;
+    for field in fields {
+        let Some((value_ty, field_is_fallible, field_is_unsafe)) = field.init_value() else {
+            continue;
+        };
+        let field_ident = &field.ident;
+        params.push(quote! { #field_ident: #value_ty });
+        stmts.push(field.init_stmts(
+            &field_ident.to_token_stream(),
+            field_is_fallible,
+            field_is_unsafe,
+            &quote! { return ::core::result::Result::Err(error); },
+        ));
+        is_fallible |= field_is_fallible;
+        is_unsafe |= field_is_unsafe;
+        is_const &= field.has_const_setter() && !field_is_fallible;
+    }
+
+    let const_ = is_const.then(|| quote! { const });
+    let unsafe_ = is_unsafe.then(|| quote! { unsafe });
+    let default_bound =
+        (!default_value_is_const).then(|| quote! { where #storage_ty: ::core::default::Default });
+    let (output_ty, result) = if is_fallible {
+        (
+            quote! { ::core::result::Result<Self, ::proc_bitfield::FieldConversionError> },
+            quote! { ::core::result::Result::Ok(__bitfield) },
+        )
+    } else {
+        (quote! { Self }, quote! { __bitfield })
+    };
+    let safety_doc = is_unsafe.then(|| {
+        quote! {
+            ///
+            /// # Safety
+            ///
+            /// The safety requirements of the setters of all fields with unsafe setters must be
+            /// upheld.
+        }
+    });
+    let errors_doc = is_fallible.then(|| {
+        quote! {
+            ///
+            /// # Errors
+            ///
+            /// Returns an error naming the first field whose value couldn't be converted.
+        }
+    });
+
+    let doc = if has_reset {
+        quote! {
+            /// Creates a bitfield with the given field values, in declaration order, and all other
+            /// bits set to their values in [`RESET`](Self::RESET).
+        }
+    } else {
+        quote! {
+            /// Creates a bitfield with the given field values, in declaration order, and all other
+            /// bits cleared (except fixed bits, which are set to their values).
+        }
+    };
+
+    quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #doc
+            #safety_doc
+            #errors_doc
+            #[inline]
+            #[allow(clippy::too_many_arguments)]
+            pub #const_ #unsafe_ fn new(#(#params),*) -> #output_ty #default_bound {
+                let __bitfield = #default_value;
+                #(#stmts)*
+                #result
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn impl_builder(
+    vis: &Visibility,
+    ident: &Ident,
+    generics: &Generics,
+    storage_ty: &Type,
+    fields: &Punctuated<Field, Token![,]>,
+    default_value: &proc_macro2::TokenStream,
+    default_value_is_const: bool,
+    has_reset: bool,
+) -> proc_macro2::TokenStream {
+    let builder_ident = format_ident!("{}Builder", ident);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ty = quote! { #ident #ty_generics };
+    let generic_args = generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+            GenericParam::Type(param) => param.ident.to_token_stream(),
+            GenericParam::Const(param) => param.ident.to_token_stream(),
+        })
+        .collect::<Vec<_>>();
+
+    let builder_fields = fields
+        .iter()
+        .filter_map(|field| {
+            let (value_ty, is_fallible, is_unsafe) = field.init_value()?;
+            // Fields with default values don't need to be set, so their state isn't tracked
+            let name = field.ident.to_string();
+            let state_ident = field.default.is_none().then(|| {
+                Ident::new(
+                    &name.trim_start_matches("r#").to_uppercase(),
+                    field.ident.span(),
+                )
+            });
+            Some((field, state_ident, value_ty, is_fallible, is_unsafe))
+        })
+        .collect::<Vec<_>>();
+    let is_fallible = builder_fields
+        .iter()
+        .any(|(_, _, _, is_fallible, _)| *is_fallible);
+    let inner_ty = if is_fallible {
+        quote! { ::core::result::Result<#ty, ::proc_bitfield::FieldConversionError> }
+    } else {
+        ty.clone()
+    };
+
+    let state_idents = builder_fields
+        .iter()
+        .filter_map(|(_, state_ident, ..)| state_ident.as_ref())
+        .collect::<Vec<_>>();
+    let state_params = state_idents
+        .iter()
+        .map(|state_ident| quote! { const #state_ident: bool })
+        .collect::<Vec<_>>();
+    let mut builder_generics = generics.clone();
+    builder_generics
+        .params
+        .extend(state_idents.iter().map(|state_ident| -> GenericParam {
+            parse_quote! { const #state_ident: bool = false }
+        }));
+    let builder_where_clause = &builder_generics.where_clause;
+
+    let builder_ty = |states: &mut dyn Iterator<Item = proc_macro2::TokenStream>| {
+        quote! { #builder_ident<#(#generic_args,)* #(#states),*> }
+    };
+    let params_impl_generics = |params: &[&proc_macro2::TokenStream]| {
+        let mut generics = generics.clone();
+        generics
+            .params
+            .extend(params.iter().map(|param| -> GenericParam {
+                parse_quote! { #param }
+            }));
+        generics.split_for_impl().0.to_token_stream()
+    };
+
+    let setters = builder_fields
+        .iter()
+        .map(
+            |(field, field_state_ident, value_ty, field_is_fallible, is_unsafe)| {
+                let Field {
+                    attrs,
+                    vis,
+                    ident: field_ident,
+                    ..
+                } = field;
+                let other_params = state_idents
+                    .iter()
+                    .zip(&state_params)
+                    .filter(|(state_ident, _)| Some(**state_ident) != field_state_ident.as_ref())
+                    .map(|(_, param)| param)
+                    .collect::<Vec<_>>();
+                let impl_generics = params_impl_generics(&other_params);
+                let states = |value: bool| {
+                    state_idents.iter().map(move |state_ident| {
+                        if Some(*state_ident) == field_state_ident.as_ref() {
+                            quote! { #value }
+                        } else {
+                            state_ident.to_token_stream()
+                        }
+                    })
+                };
+                let unset_ty = builder_ty(&mut states(false));
+                let set_ty = builder_ty(&mut states(true));
+                let unsafe_ = is_unsafe.then(|| quote! { unsafe });
+                let doc_attrs = attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+                let body = if is_fallible {
+                    let stmts = field.init_stmts(
+                        &field_ident.to_token_stream(),
+                        *field_is_fallible,
+                        *is_unsafe,
+                        &quote! { return #builder_ident(::core::result::Result::Err(error)); },
+                    );
+                    quote! {
+                        let __bitfield = match self.0 {
+                            ::core::result::Result::Ok(bitfield) => bitfield,
+                            ::core::result::Result::Err(error) => {
+                                return #builder_ident(::core::result::Result::Err(error));
+                            }
+                        };
+                        #stmts
+                        #builder_ident(::core::result::Result::Ok(__bitfield))
+                    }
+                } else {
+                    let stmts = field.init_stmts(
+                        &field_ident.to_token_stream(),
+                        false,
+                        *is_unsafe,
+                        &quote! {},
+                    );
+                    quote! {
+                        let __bitfield = self.0;
+                        #stmts
+                        #builder_ident(__bitfield)
+                    }
+                };
+                quote! {
+                    impl #impl_generics #unset_ty #builder_where_clause {
+                        #(#doc_attrs)*
+                        #[inline]
+                        #[must_use]
+                        #vis #unsafe_ fn #field_ident(self, #field_ident: #value_ty) -> #set_ty {
+                            #body
+                        }
+                    }
+                }
+            },
+        )
+        .collect::<Vec<_>>();
+
+    let unset_ty = builder_ty(&mut state_idents.iter().map(|_| quote! { false }));
+    let complete_ty = builder_ty(&mut state_idents.iter().map(|_| quote! { true }));
+    let default_bound =
+        (!default_value_is_const).then(|| quote! { where #storage_ty: ::core::default::Default });
+    let initial_value = if is_fallible {
+        quote! { ::core::result::Result::Ok(#default_value) }
+    } else {
+        default_value.clone()
+    };
+    let builder_doc = format!(
+        "A builder for [`{ident}`] values, only allowing them to be built once every field \
+         without a default value has been set exactly once."
+    );
+    let errors_doc = is_fallible.then(|| {
+        quote! {
+            ///
+            /// # Errors
+            ///
+            /// Returns an error naming the first field whose value couldn't be converted.
+        }
+    });
+
+    let builder_fn_doc = if has_reset {
+        quote! {
+            /// Returns a builder for values of this bitfield, starting from
+            /// [`RESET`](Self::RESET) until their fields are set.
+        }
+    } else {
+        quote! {
+            /// Returns a builder for values of this bitfield, with all bits cleared (except fixed
+            /// bits, which are set to their values) until their fields are set.
+        }
     };
 
-    let mut last_bits_span = None;
-    let field_fns = fields
-        .iter()
-        .map(|field| {
-            let bits_span = match field.bits.clone().into_span(last_bits_span.as_ref()) {
-                Ok(bits_span) => bits_span,
-                Err(err) => return err.to_compile_error(),
-            };
-            last_bits_span = Some(bits_span.clone());
-
-            let asserts = field.asserts(&bits_span, full_bits, full_bits_are_const);
+    quote! {
+        #[doc = #builder_doc]
+        #[must_use]
+        #vis struct #builder_ident #builder_generics(#inner_ty) #builder_where_clause;
 
-            let getters = is_readable.then(|| {
-                field.getters(
-                    &bits_span,
-                    &asserts,
-                    storage_deref,
-                    storage_deref_ty,
-                    full_bits,
-                    #[cfg(feature = "gce")]
-                    start_end_bits,
-                    #[cfg(feature = "gce")]
-                    storage_needs_const_bounds,
-                )
-            });
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #builder_fn_doc
+            #[inline]
+            pub fn builder() -> #unset_ty #default_bound {
+                #builder_ident(#initial_value)
+            }
+        }
 
-            let setters = is_writable.then(|| {
-                field.setters(
-                    &bits_span,
-                    &asserts,
-                    storage_deref,
-                    storage_deref_ty,
-                    full_bits,
-                    is_readable,
-                    allows_with,
-                    #[cfg(feature = "gce")]
-                    start_end_bits,
-                    #[cfg(feature = "gce")]
-                    storage_needs_const_bounds,
-                )
-            });
+        #(#setters)*
 
-            quote! {
-                #getters
-                #setters
+        impl #impl_generics #complete_ty #where_clause {
+            /// Returns the built bitfield value.
+            #errors_doc
+            #[inline]
+            pub fn build(self) -> #inner_ty {
+                self.0
             }
-        })
-        .collect::<Vec<_>>();
+        }
+    }
+}
 
-    let type_params_phantom_data_field_ty = if has_unused_type_params {
-        quote! { , ::core::marker::PhantomData<(#(#unused_type_params),*)> }
-    } else {
-        quote! {}
+/// Checks that the given fields don't overlap unless they're aliases or members of the same union
+/// group, and, if `exhaustive` is set, that they cover every bit of the storage type. Only spans
+/// that can be evaluated at expansion time are checked for overlaps.
+fn check_layout(
+    fields: &Punctuated<Field, Token![,]>,
+    storage_ty: &Type,
+    exhaustive: Option<Span>,
+) -> Result<()> {
+    fn describe(field: &Field) -> String {
+        if field.is_reserved() {
+            "reserved bits".to_string()
+        } else {
+            format!("field `{}`", field.ident)
+        }
+    }
+
+    let full_bits = const_size_of(storage_ty).and_then(|size| size.checked_mul(8));
+    let mut errors: Option<Error> = None;
+    let mut push_error = |error: Error| match &mut errors {
+        Some(errors) => errors.combine(error),
+        None => errors = Some(error),
     };
 
-    quote! {
-        #(#outer_attrs)*
-        #[repr(transparent)]
-        #vis struct #ident #generics(
-            #storage_vis #storage_ty #type_params_phantom_data_field_ty
-        ) #where_clause;
+    // Bit ranges are checked in declaration order, as MSB-0 numbering maps them one-to-one
+    let mut last_bits_span = None;
+    let mut ranges: Vec<(&Field, Option<(u128, u128)>)> = Vec::new();
+    for field in fields {
+        // Invalid spans are already reported when generating accessors
+        let Ok((bits_span, _)) = field.bits_spans(last_bits_span.as_ref()) else {
+            return Ok(());
+        };
+        let range = match &bits_span {
+            BitsSpan::Single(bit) => const_eval_tokens(bit).map(|bit| (bit, bit + 1)),
+            BitsSpan::Range { start, end } => const_eval_tokens(start).zip(const_eval_tokens(end)),
+            BitsSpan::Full => full_bits.map(|full_bits| (0, full_bits)),
+        };
+        last_bits_span = Some(bits_span);
 
-        impl #impl_generics #ident #ty_generics #where_clause {
-            #[inline(always)]
-            const fn __from_storage(storage: #storage_ty) -> Self {
-                Self(storage #type_params_phantom_data)
+        if let Some((start, end)) = range {
+            for (other, other_range) in &ranges {
+                let Some((other_start, other_end)) = *other_range else {
+                    continue;
+                };
+                let is_allowed = field.is_alias
+                    || other.is_alias
+                    || (field.union_group.is_some() && field.union_group == other.union_group);
+                if !is_allowed && start < other_end && other_start < end {
+                    push_error(Error::new(
+                        field.ident.span(),
+                        format!(
+                            "{} overlaps with {} (bits {}..{})",
+                            describe(field),
+                            describe(other),
+                            start.max(other_start),
+                            end.min(other_end),
+                        ),
+                    ));
+                }
             }
+        } else if exhaustive.is_some() {
+            push_error(Error::new(
+                field.ident.span(),
+                format!(
+                    "the bits of {} can't be determined at expansion time, which is required \
+                     by `Exhaustive`",
+                    describe(field)
+                ),
+            ));
+        }
+        ranges.push((field, range));
+    }
 
-            #(#field_fns)*
+    if let Some(exhaustive_span) = exhaustive {
+        match full_bits {
+            Some(full_bits) => {
+                let mut covered = ranges
+                    .iter()
+                    .filter_map(|(_, range)| *range)
+                    .collect::<Vec<_>>();
+                covered.sort_unstable();
+                let mut next_uncovered = 0;
+                for (start, end) in covered.into_iter().chain([(full_bits, full_bits)]) {
+                    if start > next_uncovered {
+                        push_error(Error::new(
+                            exhaustive_span,
+                            format!(
+                                "bits {next_uncovered}..{start} aren't covered by any field or \
+                                 declared as reserved"
+                            ),
+                        ));
+                    }
+                    next_uncovered = next_uncovered.max(end);
+                }
+            }
+            None => push_error(Error::new(
+                exhaustive_span,
+                "`Exhaustive` can only be used for bitfields with storage types of known size",
+            )),
+        }
+    }
+
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(()),
+    }
+}
+
+/// Checks that the associated constants declared for fields through the `FieldConsts` and
+/// `Descriptors` options don't collide with each other or with the bitfield struct's own constants.
+fn check_const_names(
+    fields: &Punctuated<Field, Token![,]>,
+    field_consts: bool,
+    descriptors: bool,
+) -> Result<()> {
+    const STRUCT_CONSTS: [&str; 10] = [
+        "W1C_MASK",
+        "W1S_MASK",
+        "RC_MASK",
+        "WRITABLE_MASK",
+        "ALL_FIELDS_MASK",
+        "RESERVED_MASK",
+        "FIXED_MASK",
+        "FIXED_VALUE",
+        "RESET",
+        "MIN_LEN",
+    ];
+
+    let mut errors: Option<Error> = None;
+    let mut names: Vec<(Ident, &Ident)> = Vec::new();
+    for field in fields {
+        if field.is_reserved() {
+            continue;
+        }
+        let mut field_names = Vec::new();
+        if field_consts {
+            field_names.extend(
+                ["MASK", "SHIFT", "RANGE", "BITS"].map(|suffix| field.layout_const_ident(suffix)),
+            );
+            if field.array_len.is_some() {
+                field_names.push(field.layout_const_ident("LEN"));
+            }
+        }
+        if descriptors && field.descriptor_value_ty().is_some() {
+            let name = field.ident.to_string();
+            field_names.push(Ident::new(
+                &name.trim_start_matches("r#").to_uppercase(),
+                field.ident.span(),
+            ));
+        }
+        for name in field_names {
+            let other = if STRUCT_CONSTS.iter().any(|other| name == other) {
+                Some("the bitfield struct's own constant".to_string())
+            } else {
+                names
+                    .iter()
+                    .find(|(other, _)| *other == name)
+                    .map(|(_, other_field)| format!("the one declared for field `{other_field}`"))
+            };
+            if let Some(other) = other {
+                let error = Error::new(
+                    field.ident.span(),
+                    format!(
+                        "the `{name}` associated constant declared for field `{}` collides with \
+                         {other}",
+                        field.ident
+                    ),
+                );
+                match &mut errors {
+                    Some(errors) => errors.combine(error),
+                    None => errors = Some(error),
+                }
+            }
+            names.push((name, &field.ident));
         }
     }
+
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(()),
+    }
 }
 
 pub fn bitfield(input: TokenStream) -> TokenStream {
@@ -1756,14 +5511,57 @@ pub fn bitfield(input: TokenStream) -> TokenStream {
         storage_vis,
         storage_ty,
         auto_impls,
+        msb0,
+        write_zero,
+        field_consts,
+        exhaustive,
+        reset_value,
         fields,
+        write_fields,
     } = syn::parse_macro_input!(input);
 
+    let layout_errors = core::iter::once(&fields)
+        .chain(&write_fields)
+        .filter_map(|fields| check_layout(fields, &storage_ty, exhaustive).err())
+        .chain(
+            check_const_names(
+                &fields,
+                field_consts,
+                auto_impls.descriptors && write_fields.is_none(),
+            )
+            .err(),
+        )
+        .chain(
+            write_fields
+                .iter()
+                .filter_map(|fields| check_const_names(fields, field_consts, false).err()),
+        )
+        .map(|err| err.to_compile_error())
+        .collect::<proc_macro2::TokenStream>();
+
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let ty = quote! { #ident #ty_generics };
 
-    let storage_ty_bits = quote! { ::core::mem::size_of::<#storage_ty>() << 3 };
-    let storage_ty_bits_are_const = generics.params.is_empty();
+    let storage_ty_bits = match &storage_ty {
+        Type::Slice(ty) => {
+            let elem_ty = &ty.elem;
+            quote! { (Self::MIN_LEN * (::core::mem::size_of::<#elem_ty>() << 3)) }
+        }
+        _ => quote! { ::core::mem::size_of::<#storage_ty>() << 3 },
+    };
+    let is_slice = matches!(storage_ty, Type::Slice(_));
+
+    // Layouts have reset values if the struct uses the `Default` option or any of their fields
+    // have default values, starting from the struct's reset value (or zero)
+    let reset_value_for = |fields: &Punctuated<Field, Token![,]>| {
+        (auto_impls.default || fields.iter().any(|field| field.default.is_some())).then(|| {
+            reset_value
+                .as_ref()
+                .map_or_else(|| quote! { 0 }, ToTokens::to_token_stream)
+        })
+    };
+    let reset_value = reset_value_for(&fields);
+    let write_reset_value = write_fields.as_ref().and_then(reset_value_for);
 
     let ty_impl = impl_bitfield_ty(
         &outer_attrs,
@@ -1776,19 +5574,81 @@ pub fn bitfield(input: TokenStream) -> TokenStream {
         &quote! { self.0 },
         &storage_ty,
         &storage_ty_bits,
-        storage_ty_bits_are_const,
+        msb0,
+        write_zero,
+        field_consts,
+        auto_impls.try_from_storage,
         &fields,
         true,
-        true,
-        true,
+        write_fields.is_none(),
+        !is_slice,
+        reset_value.as_ref(),
         #[cfg(feature = "gce")]
         None,
         #[cfg(feature = "gce")]
         false,
     );
 
+    let write_ident = write_fields
+        .as_ref()
+        .map(|_| format_ident!("{}Write", ident));
+    let write_ty_impl = write_fields.as_ref().map(|write_fields| {
+        let write_ident = write_ident.as_ref().unwrap();
+        let write_doc = format!("The write layout of [`{ident}`].");
+        let write_outer_attrs = core::iter::once(parse_quote! { #[doc = #write_doc] })
+            .chain(
+                outer_attrs
+                    .iter()
+                    .filter(|attr| !attr.path().is_ident("doc"))
+                    .cloned(),
+            )
+            .collect::<Vec<_>>();
+        let write_ty_impl = impl_bitfield_ty(
+            &write_outer_attrs,
+            &vis,
+            write_ident,
+            &generics,
+            generics.type_params(),
+            &storage_vis,
+            &storage_ty,
+            &quote! { self.0 },
+            &storage_ty,
+            &storage_ty_bits,
+            msb0,
+            write_zero,
+            field_consts,
+            auto_impls.try_from_storage,
+            write_fields,
+            false,
+            true,
+            true,
+            write_reset_value.as_ref(),
+            #[cfg(feature = "gce")]
+            None,
+            #[cfg(feature = "gce")]
+            false,
+        );
+        quote! {
+            impl #impl_generics ::proc_bitfield::Bitfield for #write_ident #ty_generics
+                #where_clause
+            {
+                type Storage = #storage_ty;
+            }
+
+            impl #impl_generics ::proc_bitfield::ReadWriteBitfield for #ty #where_clause {
+                type Write = #write_ident #ty_generics;
+            }
+
+            #write_ty_impl
+        }
+    });
+
+    // Split layouts can't be used as nested bitfields, as they have no single type that's both
+    // readable and writable
     #[cfg(feature = "gce")]
-    let nested = {
+    let nested = if write_fields.is_some() || is_slice {
+        quote! {}
+    } else {
         let mut nested_outer_attrs = outer_attrs.clone();
         // TODO: Hack
         nested_outer_attrs.retain_mut(|attr| !attr.path().is_ident("derive"));
@@ -1828,11 +5688,15 @@ pub fn bitfield(input: TokenStream) -> TokenStream {
             &nested_storage_deref,
             &nested_storage_deref_ty,
             &storage_ty_bits,
-            storage_ty_bits_are_const,
+            msb0,
+            false,
+            false,
+            false,
             &fields,
             true,
             false,
             false,
+            None,
             Some((&nested_start_bit, &nested_end_bit)),
             true,
         );
@@ -1863,11 +5727,15 @@ pub fn bitfield(input: TokenStream) -> TokenStream {
             &nested_storage_deref,
             &nested_storage_deref_ty,
             &storage_ty_bits,
-            storage_ty_bits_are_const,
+            msb0,
+            false,
+            false,
+            false,
             &fields,
             true,
             true,
             false,
+            None,
             Some((&nested_start_bit, &nested_end_bit)),
             true,
         );
@@ -1898,11 +5766,15 @@ pub fn bitfield(input: TokenStream) -> TokenStream {
             &nested_storage_deref,
             &nested_storage_deref_ty,
             &storage_ty_bits,
-            storage_ty_bits_are_const,
+            msb0,
+            false,
+            false,
+            false,
             &fields,
             false,
             true,
             false,
+            None,
             Some((&nested_start_bit, &nested_end_bit)),
             true,
         );
@@ -1978,77 +5850,339 @@ pub fn bitfield(input: TokenStream) -> TokenStream {
 
     let mut impls = Vec::new();
 
-    if auto_impls.debug {
-        let readable_fields = fields.iter().filter(|field| field.is_readable());
-        let field_idents = readable_fields.clone().map(|field| &field.ident);
-        let field_unsafes = readable_fields.map(|field| {
-            if field.has_unsafe_getter() {
-                quote! { unsafe }
+    // Split layouts have a read view type (only declaring getters) and a write builder type (only
+    // declaring setters); automatic implementations apply to both, but only the read view's
+    // fields are printed by `Debug`
+    let layouts = core::iter::once((&ident, &fields, true, write_fields.is_none())).chain(
+        write_ident
+            .as_ref()
+            .zip(write_fields.as_ref())
+            .map(|(write_ident, write_fields)| (write_ident, write_fields, false, true)),
+    );
+    for (ident, layout_fields, is_read_view, is_writable) in layouts {
+        // Values created from storage values have their reserved and fixed bits forced to their
+        // values, unless they're read views
+        let fixes = is_writable && layout_fields.iter().any(Field::is_reserved);
+        let from_storage = |storage: proc_macro2::TokenStream| {
+            if fixes {
+                quote! {{
+                    let mut storage = #storage;
+                    Self::__fix_storage(&mut storage);
+                    Self::__from_storage(storage)
+                }}
             } else {
-                quote! {}
+                quote! { Self::__from_storage(#storage) }
             }
-        });
-        impls.push(quote! {
-            impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #where_clause {
-                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                    f.debug_struct(::core::stringify!(#ident))
-                        .field("0", &self.0)
-                        #(.field(
-                            ::core::stringify!(#field_idents),
-                            #field_unsafes { &self.#field_idents() },
-                        ))*
-                        .finish()
+        };
+
+        if auto_impls.debug {
+            let readable_fields = layout_fields
+                .iter()
+                .filter(|field| is_read_view && field.is_readable());
+            let field_idents = readable_fields.clone().map(|field| &field.ident);
+            let field_unsafes = readable_fields.clone().map(|field| {
+                if field.has_unsafe_getter() {
+                    quote! { unsafe }
+                } else {
+                    quote! {}
                 }
-            }
-        });
-    }
+            });
+            let field_values = readable_fields.map(|field| {
+                let ident = &field.ident;
+                match field.array_len {
+                    Some((len, _)) => {
+                        let indices = 0..len;
+                        quote! { &[#(self.#ident(#indices)),*] }
+                    }
+                    None => quote! { &self.#ident() },
+                }
+            });
+            // Unsized storage has to be printed through a reference
+            let storage_ref = if is_slice {
+                quote! { &&self.0 }
+            } else {
+                quote! { &self.0 }
+            };
+            impls.push(quote! {
+                impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #where_clause {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        f.debug_struct(::core::stringify!(#ident))
+                            .field("0", #storage_ref)
+                            #(.field(
+                                ::core::stringify!(#field_idents),
+                                #field_unsafes { #field_values },
+                            ))*
+                            .finish()
+                    }
+                }
+            });
+        }
 
-    if auto_impls.from_storage {
-        impls.push(quote! {
-            impl #impl_generics ::core::convert::From<#storage_ty> for #ident #ty_generics
-                #where_clause
-            {
-                fn from(other: #storage_ty) -> Self {
-                    Self::__from_storage(other)
+        if auto_impls.reflect {
+            impls.push(impl_reflect(
+                ident,
+                &generics,
+                &storage_ty,
+                layout_fields,
+                &storage_ty_bits,
+                msb0,
+                is_read_view,
+                is_writable,
+            ));
+        }
+
+        // Split layouts' fields are either read-only or write-only, so they don't have descriptors
+        if auto_impls.descriptors && is_read_view && is_writable {
+            impls.push(impl_descriptors(
+                ident,
+                &generics,
+                layout_fields,
+                &storage_ty_bits,
+                msb0,
+            ));
+        }
+
+        let has_reset =
+            auto_impls.default || layout_fields.iter().any(|field| field.default.is_some());
+
+        if auto_impls.unpacked {
+            impls.push(impl_unpacked(
+                &outer_attrs,
+                &vis,
+                ident,
+                layout_fields,
+                is_read_view,
+                is_writable,
+                &if has_reset {
+                    quote! { Self::RESET }
+                } else {
+                    from_storage(quote! { ::core::default::Default::default() })
+                },
+            ));
+        }
+
+        if auto_impls.default {
+            impls.push(quote! {
+                impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
+                    #[inline]
+                    fn default() -> Self {
+                        Self::RESET
+                    }
                 }
-            }
-        });
-    }
+            });
+        }
 
-    if auto_impls.into_storage {
-        impls.push(quote! {
-            impl #impl_generics ::core::convert::From<#ident #ty_generics> for #storage_ty
-                #where_clause
-            {
-                fn from(other: #ident #ty_generics) -> Self {
-                    other.0
+        if is_writable && (auto_impls.new || auto_impls.builder) {
+            let (default_value, zero_is_const) = if has_reset {
+                (quote! { Self::RESET }, true)
+            } else {
+                match zero_value(&storage_ty) {
+                    Some(zero) => (from_storage(zero), true),
+                    None => (
+                        from_storage(quote! { ::core::default::Default::default() }),
+                        false,
+                    ),
                 }
+            };
+            if auto_impls.new {
+                impls.push(impl_new(
+                    ident,
+                    &generics,
+                    &storage_ty,
+                    layout_fields,
+                    &default_value,
+                    zero_is_const,
+                    has_reset,
+                ));
             }
-        });
+            if auto_impls.builder {
+                impls.push(impl_builder(
+                    &vis,
+                    ident,
+                    &generics,
+                    &storage_ty,
+                    layout_fields,
+                    &default_value,
+                    zero_is_const,
+                    has_reset,
+                ));
+            }
+        }
+
+        if auto_impls.from_storage {
+            let from_other = from_storage(quote! { other });
+            impls.push(quote! {
+                impl #impl_generics ::core::convert::From<#storage_ty> for #ident #ty_generics
+                    #where_clause
+                {
+                    fn from(other: #storage_ty) -> Self {
+                        #from_other
+                    }
+                }
+            });
+        }
+
+        if auto_impls.try_from_storage {
+            let from_other = from_storage(quote! { other });
+            impls.push(quote! {
+                impl #impl_generics ::core::convert::TryFrom<#storage_ty> for #ident #ty_generics
+                    #where_clause
+                {
+                    type Error = ::proc_bitfield::FixedBitsError<#storage_ty>;
+
+                    fn try_from(other: #storage_ty) -> ::core::result::Result<Self, Self::Error> {
+                        if Self::__has_valid_fixed_bits(&other) {
+                            ::core::result::Result::Ok(#from_other)
+                        } else {
+                            ::core::result::Result::Err(::proc_bitfield::FixedBitsError::new(other))
+                        }
+                    }
+                }
+            });
+        }
+
+        if auto_impls.into_storage {
+            impls.push(quote! {
+                impl #impl_generics ::core::convert::From<#ident #ty_generics> for #storage_ty
+                    #where_clause
+                {
+                    fn from(other: #ident #ty_generics) -> Self {
+                        other.0
+                    }
+                }
+            });
+        }
+
+        if auto_impls.deref_storage {
+            impls.push(quote! {
+                impl #impl_generics ::core::ops::Deref for #ident #ty_generics #where_clause {
+                    type Target = #storage_ty;
+
+                    fn deref(&self) -> &#storage_ty {
+                        &self.0
+                    }
+                }
+            });
+        }
+
+        if auto_impls.bytes {
+            let bytes_ty = quote! { [u8; ::core::mem::size_of::<#storage_ty>()] };
+            let from_be_bytes = from_storage(quote! { <#storage_ty>::from_be_bytes(bytes) });
+            let from_le_bytes = from_storage(quote! { <#storage_ty>::from_le_bytes(bytes) });
+            let from_ne_bytes = from_storage(quote! { <#storage_ty>::from_ne_bytes(bytes) });
+            impls.push(quote! {
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// Returns the memory representation of this bitfield's storage as a byte array
+                    /// in big-endian byte order.
+                    #[inline]
+                    pub const fn to_be_bytes(self) -> #bytes_ty {
+                        self.0.to_be_bytes()
+                    }
+
+                    /// Returns the memory representation of this bitfield's storage as a byte array
+                    /// in little-endian byte order.
+                    #[inline]
+                    pub const fn to_le_bytes(self) -> #bytes_ty {
+                        self.0.to_le_bytes()
+                    }
+
+                    /// Returns the memory representation of this bitfield's storage as a byte array
+                    /// in native byte order.
+                    #[inline]
+                    pub const fn to_ne_bytes(self) -> #bytes_ty {
+                        self.0.to_ne_bytes()
+                    }
+
+                    /// Creates a bitfield from its storage's representation as a byte array in
+                    /// big-endian byte order.
+                    #[inline]
+                    pub const fn from_be_bytes(bytes: #bytes_ty) -> Self {
+                        #from_be_bytes
+                    }
+
+                    /// Creates a bitfield from its storage's representation as a byte array in
+                    /// little-endian byte order.
+                    #[inline]
+                    pub const fn from_le_bytes(bytes: #bytes_ty) -> Self {
+                        #from_le_bytes
+                    }
+
+                    /// Creates a bitfield from its storage's representation as a byte array in
+                    /// native byte order.
+                    #[inline]
+                    pub const fn from_ne_bytes(bytes: #bytes_ty) -> Self {
+                        #from_ne_bytes
+                    }
+                }
+            });
+        }
+    }
+
+    if auto_impls.volatile {
+        impls.push(impl_volatile(
+            &vis,
+            &ident,
+            &generics,
+            &storage_ty,
+            &fields,
+            write_ident.as_ref().zip(write_fields.as_ref()),
+            write_zero,
+        ));
+    }
+
+    if auto_impls.atomic {
+        impls.push(impl_atomic(&vis, &ident, &generics, &storage_ty, &fields));
+    }
+
+    if auto_impls.cell {
+        impls.push(impl_cell(&vis, &ident, &generics, &storage_ty, &fields));
     }
 
-    if auto_impls.deref_storage {
-        impls.push(quote! {
-            impl #impl_generics ::core::ops::Deref for #ident #ty_generics #where_clause {
-                type Target = #storage_ty;
+    if auto_impls.views {
+        impls.push(impl_views(
+            &vis,
+            &ident,
+            &generics,
+            &storage_ty,
+            &storage_ty_bits,
+            &fields,
+        ));
+    }
 
-                fn deref(&self) -> &#storage_ty {
-                    &self.0
+    // The `Bitfield` trait requires a sized storage type
+    let bitfield_impl = (!is_slice).then(|| {
+        let has_side_effects = fields
+            .iter()
+            .any(|field| field.side_effect() != SideEffect::None);
+        let to_write_fn = (write_fields.is_none() && (has_side_effects || write_zero)).then(|| {
+            quote! {
+                #[inline]
+                fn to_write(self) -> Self {
+                    Self::to_write(self)
                 }
             }
         });
-    }
+        quote! {
+            impl #impl_generics ::proc_bitfield::Bitfield for #ty #where_clause {
+                type Storage = #storage_ty;
 
-    quote! {
-        impl #impl_generics ::proc_bitfield::Bitfield for #ty #where_clause {
-            type Storage = #storage_ty;
+                #to_write_fn
+            }
         }
+    });
+
+    quote! {
+        #bitfield_impl
 
         #ty_impl
 
+        #write_ty_impl
+
         #nested
 
         #(#impls)*
+
+        #layout_errors
     }
     .into()
 }
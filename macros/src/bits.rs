@@ -1,17 +1,19 @@
 use crate::utils::maybe_const_assert;
 use proc_macro::TokenStream;
+use proc_macro2::Span;
 use quote::{quote, ToTokens};
 use std::borrow::Cow;
 use syn::{
     parenthesized,
     parse::{Parse, ParseStream, Result},
     token::Paren,
-    Error, Expr, Lit, Token, Type,
+    Error, Expr, ExprAssign, ExprRange, Lit, RangeLimits, Token, Type,
 };
 
 mod kw {
     syn::custom_keyword!(above);
     syn::custom_keyword!(below);
+    syn::custom_keyword!(msb0);
 }
 
 #[derive(Clone)]
@@ -117,6 +119,13 @@ impl Parse for Bits {
 }
 
 impl Bits {
+    pub fn is_pack_below(&self) -> bool {
+        matches!(
+            self,
+            Bits::SinglePack { above: false, .. } | Bits::Pack { above: false, .. }
+        )
+    }
+
     pub fn into_span(self, last: Option<&BitsSpan>) -> Result<BitsSpan> {
         Ok(match self {
             Bits::Single(bit) => BitsSpan::Single(bit.to_token_stream()),
@@ -181,6 +190,61 @@ impl Bits {
             Bits::RangeFull => BitsSpan::Full,
         })
     }
+
+    /// Resolves the bit span of a field, along with the spans of its elements if it's a field
+    /// array of the given length.
+    pub fn into_spans(
+        self,
+        last: Option<&BitsSpan>,
+        array_len: Option<(usize, Span)>,
+    ) -> Result<(BitsSpan, Option<Vec<BitsSpan>>)> {
+        let is_pack_below = self.is_pack_below();
+        let bits_span = self.into_span(last)?;
+        let Some((len, len_span)) = array_len else {
+            return Ok((bits_span, None));
+        };
+
+        let (start, width) = match &bits_span {
+            BitsSpan::Single(bit) => (bit.clone(), quote! { 1 }),
+            BitsSpan::Range { start, end } => (start.clone(), quote! { (#end) - (#start) }),
+            BitsSpan::Full => {
+                return Err(Error::new(
+                    len_span,
+                    "field arrays can't span the full range of the storage type",
+                ))
+            }
+        };
+        // When packing below the previous field, the whole array has to end where the previous
+        // field starts, so the first element is moved down accordingly
+        let start = if is_pack_below {
+            let shift = proc_macro2::Literal::usize_unsuffixed(len - 1);
+            quote! { (#start) - #shift * (#width) }
+        } else {
+            start
+        };
+
+        let element_spans = (0..len)
+            .map(|i| {
+                let i = proc_macro2::Literal::usize_unsuffixed(i);
+                let element_start = quote! { (#start) + #i * (#width) };
+                match &bits_span {
+                    BitsSpan::Single(_) => BitsSpan::Single(element_start),
+                    _ => BitsSpan::Range {
+                        end: quote! { (#element_start) + (#width) },
+                        start: element_start,
+                    },
+                }
+            })
+            .collect();
+        let len = proc_macro2::Literal::usize_unsuffixed(len);
+        Ok((
+            BitsSpan::Range {
+                end: quote! { (#start) + #len * (#width) },
+                start,
+            },
+            Some(element_spans),
+        ))
+    }
 }
 
 #[derive(Clone)]
@@ -207,6 +271,19 @@ impl BitsSpan {
         }
     }
 
+    /// Translates a span expressed in MSB-0 bit numbering (where bit 0 is the most significant
+    /// one) into the equivalent LSB-0 span, given the total number of bits in the storage.
+    pub fn to_msb0(&self, full_bits: &proc_macro2::TokenStream) -> BitsSpan {
+        match self {
+            BitsSpan::Single(bit) => BitsSpan::Single(quote! { (#full_bits) - 1 - (#bit) }),
+            BitsSpan::Range { start, end } => BitsSpan::Range {
+                start: quote! { (#full_bits) - (#end) },
+                end: quote! { (#full_bits) - (#start) },
+            },
+            BitsSpan::Full => BitsSpan::Full,
+        }
+    }
+
     pub fn to_start_end_or_full<'a>(
         &'a self,
         full_bits: &'a proc_macro2::TokenStream,
@@ -235,37 +312,67 @@ fn ty_bits(ty: &Option<Type>, runtime_value: proc_macro2::TokenStream) -> proc_m
     }
 }
 
+/// Returns the bit range checks for an access; `field_ty_bits` is `None` for writes, as written
+/// values are sign- or zero-extended to the range's width.
 fn asserts(
     bits_span: &BitsSpan,
     has_storage_ty: bool,
     storage_ty_bits: &proc_macro2::TokenStream,
     has_field_ty: bool,
-    field_ty_bits: &proc_macro2::TokenStream,
+    field_ty_bits: Option<&proc_macro2::TokenStream>,
 ) -> proc_macro2::TokenStream {
-    let storage_ty_assert = maybe_const_assert(has_storage_ty);
-    let field_ty_assert = maybe_const_assert(has_field_ty);
     match bits_span {
         BitsSpan::Single(bit) => {
-            quote! {
-                #storage_ty_assert((#bit) < (#storage_ty_bits));
-            }
+            maybe_const_assert(has_storage_ty, quote! { (#bit) < (#storage_ty_bits) })
         }
         BitsSpan::Range { start, end } => {
+            let range_assert = maybe_const_assert(true, quote! { (#end) > (#start) });
+            let storage_ty_assert = maybe_const_assert(
+                has_storage_ty,
+                quote! { (#start) < (#storage_ty_bits) && (#end) <= (#storage_ty_bits) },
+            );
+            let field_ty_assert = field_ty_bits.map(|field_ty_bits| {
+                maybe_const_assert(
+                    has_field_ty,
+                    quote! { (#end) - (#start) <= (#field_ty_bits) },
+                )
+            });
             quote! {
-                ::proc_bitfield::__private::static_assertions::const_assert!((#end) > (#start));
-                #storage_ty_assert((#start) < (#storage_ty_bits) && (#end) <= (#storage_ty_bits));
-                #field_ty_assert((#end) - (#start) <= (#field_ty_bits));
-            }
-        }
-        BitsSpan::Full => {
-            let assert = maybe_const_assert(has_storage_ty && has_field_ty);
-            quote! {
-                #assert((#storage_ty_bits) <= (#field_ty_bits));
+                #range_assert
+                #storage_ty_assert
+                #field_ty_assert
             }
         }
+        BitsSpan::Full => field_ty_bits
+            .map(|field_ty_bits| {
+                maybe_const_assert(
+                    has_storage_ty && has_field_ty,
+                    quote! { (#storage_ty_bits) <= (#field_ty_bits) },
+                )
+            })
+            .unwrap_or_default(),
     }
 }
 
+fn parse_msb0(input: ParseStream) -> bool {
+    let fork = input.fork();
+    let is_msb0 =
+        fork.parse::<kw::msb0>().is_ok() && fork.parse::<Expr>().is_ok() && fork.peek(Token![,]);
+    if is_msb0 {
+        input.parse::<kw::msb0>().unwrap();
+    }
+    is_msb0
+}
+
+fn msb0_storage_ty_error(storage_value: &Expr) -> TokenStream {
+    Error::new_spanned(
+        storage_value,
+        "input type needs to be specified with `as T` to use MSB-0 bit numbering",
+    )
+    .into_compile_error()
+    .into()
+}
+
 struct TyAndAtSign(Type);
 
 impl Parse for TyAndAtSign {
@@ -276,25 +383,112 @@ impl Parse for TyAndAtSign {
     }
 }
 
+fn parse_field_ty(input: ParseStream) -> Option<Type> {
+    if input.fork().parse::<TyAndAtSign>().is_ok() {
+        input.parse::<TyAndAtSign>().ok().map(|t| t.0)
+    } else {
+        None
+    }
+}
+
+/// The bit range accessed by the `bits!` family of macros: either a constant [`Bits`]
+/// specification, or a range only known at run time, specified as `dyn` followed by an expression
+/// of type `Range<usize>`, an inclusive range expression, or a start and length separated by `;`.
+#[allow(clippy::large_enum_variant)]
+enum BitsArg {
+    Const(Bits),
+    Dyn(proc_macro2::TokenStream),
+}
+
+impl BitsArg {
+    /// Parses the bit range, along with the field's new value if `with_value` is specified; for
+    /// `dyn` ranges, the `=` separating them may also be parsed as part of an assignment
+    /// expression, which is then split.
+    fn parse(input: ParseStream, with_value: bool) -> Result<(Self, Option<Expr>)> {
+        if input.parse::<Option<Token![dyn]>>()?.is_none() {
+            let bits = input.parse()?;
+            let value = if with_value {
+                input.parse::<Token![=]>()?;
+                Some(input.parse()?)
+            } else {
+                None
+            };
+            return Ok((BitsArg::Const(bits), value));
+        }
+
+        let split_value = |expr: Expr| -> Result<(Expr, Option<Expr>)> {
+            if !with_value {
+                return Ok((expr, None));
+            }
+            match expr {
+                Expr::Assign(ExprAssign { left, right, .. }) => Ok((*left, Some(*right))),
+                expr => {
+                    input.parse::<Token![=]>()?;
+                    Ok((expr, Some(input.parse()?)))
+                }
+            }
+        };
+
+        let start_or_range = input.parse::<Expr>()?;
+        let (range, value) = if input.parse::<Option<Token![;]>>()?.is_some() {
+            let (length, value) = split_value(input.parse()?)?;
+            (
+                quote! {
+                    ::proc_bitfield::__private::dyn_range_offset_and_length(
+                        #start_or_range,
+                        #length,
+                    )
+                },
+                value,
+            )
+        } else {
+            let (range, value) = split_value(start_or_range)?;
+            let range = match range {
+                Expr::Range(ExprRange {
+                    start: Some(start),
+                    limits: RangeLimits::Closed(_),
+                    end: Some(end),
+                    ..
+                }) => quote! { ::proc_bitfield::__private::dyn_range_inclusive(#start, #end) },
+                range => range.into_token_stream(),
+            };
+            (range, value)
+        };
+        Ok((BitsArg::Dyn(range), value))
+    }
+}
+
+fn dyn_msb0_error(storage_value: &Expr) -> TokenStream {
+    Error::new_spanned(
+        storage_value,
+        "MSB-0 bit numbering can't be used with `dyn` bit ranges",
+    )
+    .into_compile_error()
+    .into()
+}
+
 pub fn bits(input: TokenStream) -> TokenStream {
     struct Arguments {
+        msb0: bool,
         storage_value: Expr,
         storage_ty: Option<Type>,
-        bits: Bits,
+        bits: BitsArg,
         field_ty: Option<Type>,
     }
 
     impl Parse for Arguments {
         fn parse(input: ParseStream) -> Result<Self> {
+            let msb0 = parse_msb0(input);
             let storage_value = input.parse()?;
             let storage_ty = maybe_ty_from_cast_expr(&storage_value);
             input.parse::<Token![,]>()?;
-            let field_ty = input.parse::<TyAndAtSign>().ok().map(|t| t.0);
-            let bits = input.parse()?;
+            let field_ty = parse_field_ty(input);
+            let (bits, _) = BitsArg::parse(input, false)?;
             if !input.is_empty() {
                 return Err(input.error("unexpected extra tokens"));
             }
             Ok(Arguments {
+                msb0,
                 storage_value,
                 storage_ty,
                 bits,
@@ -304,12 +498,27 @@ pub fn bits(input: TokenStream) -> TokenStream {
     }
 
     let Arguments {
+        msb0,
         storage_value,
         storage_ty,
         bits,
         field_ty,
     } = syn::parse_macro_input!(input);
 
+    let bits = match bits {
+        BitsArg::Const(bits) => bits,
+        BitsArg::Dyn(range) => {
+            if msb0 {
+                return dyn_msb0_error(&storage_value);
+            }
+            let bits_trait = match &field_ty {
+                Some(field_ty) => quote! { ::proc_bitfield::DynBits::<#field_ty> },
+                None => quote! { ::proc_bitfield::DynBits },
+            };
+            return quote! { #bits_trait::dyn_bits(&(#storage_value), #range) }.into();
+        }
+    };
+
     let storage_ty_bits = ty_bits(&storage_ty, quote! { storage_value });
     let field_ty_bits = ty_bits(&field_ty, quote! { &result });
     let bits_span = match bits.into_span(None) {
@@ -321,8 +530,16 @@ pub fn bits(input: TokenStream) -> TokenStream {
         storage_ty.is_some(),
         &storage_ty_bits,
         field_ty.is_some(),
-        &field_ty_bits,
+        Some(&field_ty_bits),
     );
+    let bits_span = if msb0 {
+        if storage_ty.is_none() {
+            return msb0_storage_ty_error(&storage_value);
+        }
+        bits_span.to_msb0(&storage_ty_bits)
+    } else {
+        bits_span
+    };
 
     let (maybe_uninit_ty, bits_trait) = if let Some(field_ty) = &field_ty {
         (
@@ -387,27 +604,29 @@ pub fn bits(input: TokenStream) -> TokenStream {
 }
 
 struct ModifyBitsArguments {
+    msb0: bool,
     storage_value: Expr,
     storage_ty: Option<Type>,
-    bits: Bits,
+    bits: BitsArg,
     field_value: Expr,
     field_ty: Option<Type>,
 }
 
 impl Parse for ModifyBitsArguments {
     fn parse(input: ParseStream) -> Result<Self> {
+        let msb0 = parse_msb0(input);
         let storage_value = input.parse()?;
         let storage_ty = maybe_ty_from_cast_expr(&storage_value);
         input.parse::<Token![,]>()?;
-        let pre_field_ty = input.parse::<TyAndAtSign>().ok().map(|t| t.0);
-        let bits = input.parse()?;
-        input.parse::<Token![=]>()?;
-        let field_value = input.parse()?;
+        let pre_field_ty = parse_field_ty(input);
+        let (bits, field_value) = BitsArg::parse(input, true)?;
+        let field_value = field_value.unwrap();
         let post_field_ty = maybe_ty_from_cast_expr(&field_value);
         if !input.is_empty() {
             return Err(input.error("unexpected extra tokens"));
         }
         Ok(ModifyBitsArguments {
+            msb0,
             storage_value,
             storage_ty,
             bits,
@@ -419,6 +638,7 @@ impl Parse for ModifyBitsArguments {
 
 pub fn with_bits(input: TokenStream) -> TokenStream {
     let ModifyBitsArguments {
+        msb0,
         storage_value,
         storage_ty,
         bits,
@@ -426,8 +646,24 @@ pub fn with_bits(input: TokenStream) -> TokenStream {
         field_ty,
     } = syn::parse_macro_input!(input);
 
+    let bits = match bits {
+        BitsArg::Const(bits) => bits,
+        BitsArg::Dyn(range) => {
+            if msb0 {
+                return dyn_msb0_error(&storage_value);
+            }
+            let bits_trait = match &field_ty {
+                Some(field_ty) => quote! { ::proc_bitfield::DynWithBits::<#field_ty> },
+                None => quote! { ::proc_bitfield::DynWithBits },
+            };
+            return quote! {
+                #bits_trait::dyn_with_bits(#storage_value, #range, #field_value)
+            }
+            .into();
+        }
+    };
+
     let storage_ty_bits = ty_bits(&storage_ty, quote! { &storage_value });
-    let field_ty_bits = ty_bits(&field_ty, quote! { &field_value });
     let bits_span = match bits.into_span(None) {
         Ok(bits_span) => bits_span,
         Err(err) => return err.to_compile_error().into(),
@@ -437,8 +673,16 @@ pub fn with_bits(input: TokenStream) -> TokenStream {
         storage_ty.is_some(),
         &storage_ty_bits,
         field_ty.is_some(),
-        &field_ty_bits,
+        None,
     );
+    let bits_span = if msb0 {
+        if storage_ty.is_none() {
+            return msb0_storage_ty_error(&storage_value);
+        }
+        bits_span.to_msb0(&storage_ty_bits)
+    } else {
+        bits_span
+    };
 
     let bits_trait = if let Some(field_ty) = &field_ty {
         quote! { ::proc_bitfield::WithBits::<#field_ty> }
@@ -492,6 +736,7 @@ pub fn with_bits(input: TokenStream) -> TokenStream {
 
 pub fn set_bits(input: TokenStream) -> TokenStream {
     let ModifyBitsArguments {
+        msb0,
         storage_value,
         storage_ty,
         bits,
@@ -504,8 +749,24 @@ pub fn set_bits(input: TokenStream) -> TokenStream {
         _ => storage_value,
     };
 
+    let bits = match bits {
+        BitsArg::Const(bits) => bits,
+        BitsArg::Dyn(range) => {
+            if msb0 {
+                return dyn_msb0_error(&storage_value);
+            }
+            let bits_trait = match &field_ty {
+                Some(field_ty) => quote! { ::proc_bitfield::DynSetBits::<#field_ty> },
+                None => quote! { ::proc_bitfield::DynSetBits },
+            };
+            return quote! {
+                #bits_trait::dyn_set_bits(&mut #storage_value, #range, #field_value)
+            }
+            .into();
+        }
+    };
+
     let storage_ty_bits = ty_bits(&storage_ty, quote! { storage_value });
-    let field_ty_bits = ty_bits(&field_ty, quote! { &field_value });
     let bits_span = match bits.into_span(None) {
         Ok(bits_span) => bits_span,
         Err(err) => return err.to_compile_error().into(),
@@ -515,8 +776,16 @@ pub fn set_bits(input: TokenStream) -> TokenStream {
         storage_ty.is_some(),
         &storage_ty_bits,
         field_ty.is_some(),
-        &field_ty_bits,
+        None,
     );
+    let bits_span = if msb0 {
+        if storage_ty.is_none() {
+            return msb0_storage_ty_error(&storage_value);
+        }
+        bits_span.to_msb0(&storage_ty_bits)
+    } else {
+        bits_span
+    };
 
     let bits_trait = if let Some(field_ty) = &field_ty {
         quote! { ::proc_bitfield::SetBits::<#field_ty> }
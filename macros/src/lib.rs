@@ -1,6 +1,8 @@
+mod assert_layout;
 mod bitfield;
 mod bits;
 mod enum_conv;
+mod register_map;
 #[cfg(feature = "nightly")]
 mod unwrap_bits;
 mod utils;
@@ -12,6 +14,11 @@ pub fn bitfield(input: TokenStream) -> TokenStream {
     bitfield::bitfield(input)
 }
 
+#[proc_macro]
+pub fn assert_layout(input: TokenStream) -> TokenStream {
+    assert_layout::assert_layout(input)
+}
+
 #[proc_macro]
 pub fn bits(input: TokenStream) -> TokenStream {
     bits::bits(input)
@@ -27,6 +34,11 @@ pub fn set_bits(input: TokenStream) -> TokenStream {
     bits::set_bits(input)
 }
 
+#[proc_macro]
+pub fn register_map(input: TokenStream) -> TokenStream {
+    register_map::register_map(input)
+}
+
 #[proc_macro_derive(ConvRaw)]
 pub fn derive_conv_raw(item: TokenStream) -> TokenStream {
     enum_conv::derive_conv_raw(item)
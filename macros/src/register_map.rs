@@ -0,0 +1,282 @@
+use crate::{
+    bits::BitExpr,
+    utils::{parse_braces, parse_brackets, parse_terminated},
+};
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream, Result},
+    punctuated::Punctuated,
+    token, Attribute, Error, Ident, Token, Type, Visibility,
+};
+
+mod kw {
+    syn::custom_keyword!(read_only);
+    syn::custom_keyword!(ro);
+    syn::custom_keyword!(write_only);
+    syn::custom_keyword!(wo);
+}
+
+struct Register {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    ty: Type,
+    array: Option<(BitExpr, Option<BitExpr>)>,
+    offset: BitExpr,
+    is_readable: bool,
+    is_writable: bool,
+}
+
+struct RegisterMap {
+    outer_attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    registers: Punctuated<Register, Token![,]>,
+}
+
+impl Parse for Register {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+
+        let (ty, len) = if input.peek(token::Bracket) {
+            let content = parse_brackets(input)?;
+            let ty = content.parse()?;
+            content.parse::<Token![;]>()?;
+            let len = content.parse()?;
+            if !content.is_empty() {
+                return Err(content.error("unexpected extra tokens"));
+            }
+            (ty, Some(len))
+        } else {
+            (input.parse()?, None)
+        };
+
+        let mut is_readable = true;
+        let mut is_writable = true;
+        if input.peek(token::Bracket) {
+            let options_content = parse_brackets(input)?;
+            while !options_content.is_empty() {
+                let lookahead = options_content.lookahead1();
+                if lookahead.peek(kw::read_only) || lookahead.peek(kw::ro) {
+                    let span = options_content
+                        .parse::<kw::read_only>()
+                        .map(|kw| kw.span)
+                        .or_else(|_| options_content.parse::<kw::ro>().map(|kw| kw.span))?;
+                    if !is_readable || !is_writable {
+                        return Err(Error::new(span, "Conflicting access restrictions"));
+                    }
+                    is_writable = false;
+                } else if lookahead.peek(kw::write_only) || lookahead.peek(kw::wo) {
+                    let span = options_content
+                        .parse::<kw::write_only>()
+                        .map(|kw| kw.span)
+                        .or_else(|_| options_content.parse::<kw::wo>().map(|kw| kw.span))?;
+                    if !is_readable || !is_writable {
+                        return Err(Error::new(span, "Conflicting access restrictions"));
+                    }
+                    is_readable = false;
+                } else {
+                    return Err(lookahead.error());
+                }
+
+                let had_comma = options_content.parse::<Token![,]>().is_ok();
+                if !options_content.is_empty() && !had_comma {
+                    return Err(options_content.error("expected comma between register options"));
+                }
+            }
+        }
+
+        input.parse::<Token![@]>()?;
+        let offset = input.parse()?;
+        let array = match len {
+            Some(len) => {
+                let stride = if input.parse::<Token![;]>().is_ok() {
+                    Some(input.parse()?)
+                } else {
+                    None
+                };
+                Some((len, stride))
+            }
+            None => None,
+        };
+
+        Ok(Register {
+            attrs,
+            vis,
+            ident,
+            ty,
+            array,
+            offset,
+            is_readable,
+            is_writable,
+        })
+    }
+}
+
+impl Parse for RegisterMap {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let outer_attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![struct]>()?;
+        let ident = input.parse()?;
+        let content = parse_braces(input)?;
+        let registers = parse_terminated(&content, Register::parse)?;
+        Ok(RegisterMap {
+            outer_attrs,
+            vis,
+            ident,
+            registers,
+        })
+    }
+}
+
+pub fn register_map(input: TokenStream) -> TokenStream {
+    let RegisterMap {
+        outer_attrs,
+        vis,
+        ident,
+        registers,
+    } = syn::parse_macro_input!(input);
+
+    let register_fns = registers.iter().map(|register| {
+        let Register {
+            attrs,
+            vis,
+            ident,
+            ty,
+            array,
+            offset,
+            is_readable,
+            is_writable,
+        } = register;
+
+        let storage_ty = quote! { <#ty as ::proc_bitfield::Bitfield>::Storage };
+        let where_clause = quote! {
+            where B: ::proc_bitfield::register_map::RegisterAccess<#storage_ty>
+        };
+        let (index_param, index_arg, addr) = match array {
+            Some((len, stride)) => {
+                let stride = match stride {
+                    Some(stride) => quote! { #stride },
+                    None => quote! { ::core::mem::size_of::<#storage_ty>() },
+                };
+                let out_of_bounds_msg = format!("index out of bounds for register array `{ident}`");
+                (
+                    quote! { index: usize, },
+                    quote! { index, },
+                    quote! {{
+                        ::core::assert!(index < (#len), #out_of_bounds_msg);
+                        self.base + (#offset) + index * (#stride)
+                    }},
+                )
+            }
+            None => (quote! {}, quote! {}, quote! { self.base + (#offset) }),
+        };
+
+        let read_fn_ident = format_ident!("read_{}", ident);
+        let write_fn_ident = format_ident!("write_{}", ident);
+        let modify_fn_ident = format_ident!("modify_{}", ident);
+
+        let reader = is_readable.then(|| {
+            quote! {
+                #(#attrs)*
+                #[inline]
+                #vis fn #read_fn_ident(&mut self, #index_param) -> #ty #where_clause {
+                    let addr = #addr;
+                    <#ty as ::core::convert::From<#storage_ty>>::from(
+                        ::proc_bitfield::register_map::RegisterAccess::<#storage_ty>::read(
+                            &mut self.backend,
+                            addr,
+                        ),
+                    )
+                }
+            }
+        });
+
+        let writer = is_writable.then(|| {
+            quote! {
+                #(#attrs)*
+                #[inline]
+                #vis fn #write_fn_ident(&mut self, #index_param value: #ty) #where_clause {
+                    let addr = #addr;
+                    ::proc_bitfield::register_map::RegisterAccess::<#storage_ty>::write(
+                        &mut self.backend,
+                        addr,
+                        <#storage_ty as ::core::convert::From<#ty>>::from(value),
+                    );
+                }
+            }
+        });
+
+        let modifier = (*is_readable && *is_writable).then(|| {
+            quote! {
+                #(#attrs)*
+                #[inline]
+                #vis fn #modify_fn_ident(
+                    &mut self,
+                    #index_param
+                    f: impl ::core::ops::FnOnce(#ty) -> #ty,
+                ) #where_clause {
+                    let value = self.#read_fn_ident(#index_arg);
+                    self.#write_fn_ident(
+                        #index_arg f(<#ty as ::proc_bitfield::Bitfield>::to_write(value)),
+                    );
+                }
+            }
+        });
+
+        quote! {
+            #reader
+            #writer
+            #modifier
+        }
+    });
+
+    quote! {
+        #(#outer_attrs)*
+        #vis struct #ident<B> {
+            backend: B,
+            base: usize,
+        }
+
+        impl<B> #ident<B> {
+            /// Creates a register map accessing registers through the given backend, at offsets
+            /// relative to the given base address.
+            #[inline]
+            pub const fn new(backend: B, base: usize) -> Self {
+                Self { backend, base }
+            }
+
+            /// Returns the base address of the register map.
+            #[inline]
+            pub const fn base(&self) -> usize {
+                self.base
+            }
+
+            /// Returns a reference to the register map's backend.
+            #[inline]
+            pub const fn backend(&self) -> &B {
+                &self.backend
+            }
+
+            /// Returns a mutable reference to the register map's backend.
+            #[inline]
+            pub fn backend_mut(&mut self) -> &mut B {
+                &mut self.backend
+            }
+
+            /// Consumes the register map, returning its backend.
+            #[inline]
+            pub fn into_backend(self) -> B {
+                self.backend
+            }
+
+            #(#register_fns)*
+        }
+    }
+    .into()
+}
@@ -1,5 +1,3 @@
-use std::cell::Cell;
-
 #[cfg(feature = "gce")]
 use proc_macro2::Span;
 #[cfg(feature = "gce")]
@@ -27,6 +25,166 @@ pub fn for_all_int_types(mut f: impl FnMut(u8, bool, Ident)) {
     }
 }
 
+pub fn is_int_ty(ty: &syn::Type) -> bool {
+    let syn::Type::Path(ty) = ty else {
+        return false;
+    };
+    let Some(ident) = ty.qself.is_none().then(|| ty.path.get_ident()).flatten() else {
+        return false;
+    };
+    if ident == "usize" || ident == "isize" {
+        return true;
+    }
+    let mut is_int_ty = false;
+    for_all_int_types(|_, _, ty_ident| is_int_ty |= *ident == ty_ident);
+    is_int_ty
+}
+
+/// Returns a constant expression evaluating to a zero value of the given type, if it's a builtin
+/// integer type or a (possibly nested) array of them.
+pub fn zero_value(ty: &syn::Type) -> Option<proc_macro2::TokenStream> {
+    match ty {
+        syn::Type::Array(ty) => {
+            let elem = zero_value(&ty.elem)?;
+            let len = &ty.len;
+            Some(quote! { [#elem; #len] })
+        }
+        syn::Type::Paren(ty) => zero_value(&ty.elem),
+        _ => is_int_ty(ty).then(|| quote! { 0 }),
+    }
+}
+
+/// Returns the name of the `core::sync::atomic` type with the same size and in-memory
+/// representation as the given type, if it's a builtin integer type with one.
+pub fn atomic_ty(ty: &syn::Type) -> Option<Ident> {
+    let syn::Type::Path(ty) = ty else {
+        return None;
+    };
+    let ident = ty.qself.is_none().then(|| ty.path.get_ident()).flatten()?;
+    let name = match ident.to_string().as_str() {
+        "u8" => "AtomicU8",
+        "u16" => "AtomicU16",
+        "u32" => "AtomicU32",
+        "u64" => "AtomicU64",
+        "usize" => "AtomicUsize",
+        "i8" => "AtomicI8",
+        "i16" => "AtomicI16",
+        "i32" => "AtomicI32",
+        "i64" => "AtomicI64",
+        "isize" => "AtomicIsize",
+        _ => return None,
+    };
+    Some(Ident::new(name, ident.span()))
+}
+
+/// Returns the size in bytes of the given type if it can be determined at expansion time, i.e.
+/// for fixed-size integers, and arrays and endianness wrappers of them.
+pub fn const_size_of(ty: &syn::Type) -> Option<u128> {
+    match ty {
+        syn::Type::Paren(ty) => const_size_of(&ty.elem),
+        syn::Type::Group(ty) => const_size_of(&ty.elem),
+        syn::Type::Array(ty) => const_size_of(&ty.elem)?.checked_mul(const_eval(&ty.len)?),
+        syn::Type::Path(ty) if ty.qself.is_none() => {
+            let segment = ty.path.segments.last()?;
+            match &segment.arguments {
+                syn::PathArguments::None => {
+                    let mut size = None;
+                    for_all_int_types(|bits, _, ty_ident| {
+                        if segment.ident == ty_ident {
+                            size = Some(bits as u128 >> 3);
+                        }
+                    });
+                    size
+                }
+                syn::PathArguments::AngleBracketed(args)
+                    if segment.ident == "BigEndian" || segment.ident == "LittleEndian" =>
+                {
+                    match args.args.first() {
+                        Some(syn::GenericArgument::Type(ty)) if args.args.len() == 1 => {
+                            const_size_of(ty)
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Evaluates the given expression if it only consists of integer literals, basic arithmetic and
+/// `size_of` calls on types supported by [`const_size_of`].
+pub fn const_eval(expr: &syn::Expr) -> Option<u128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit.base10_parse().ok(),
+        syn::Expr::Paren(expr) => const_eval(&expr.expr),
+        syn::Expr::Group(expr) => const_eval(&expr.expr),
+        syn::Expr::Cast(expr) => const_eval(&expr.expr),
+        syn::Expr::Binary(expr) => {
+            let left = const_eval(&expr.left)?;
+            let right = const_eval(&expr.right)?;
+            match expr.op {
+                syn::BinOp::Add(_) => left.checked_add(right),
+                syn::BinOp::Sub(_) => left.checked_sub(right),
+                syn::BinOp::Mul(_) => left.checked_mul(right),
+                syn::BinOp::Div(_) => left.checked_div(right),
+                syn::BinOp::Rem(_) => left.checked_rem(right),
+                syn::BinOp::Shl(_) => left.checked_shl(right.try_into().ok()?),
+                syn::BinOp::Shr(_) => left.checked_shr(right.try_into().ok()?),
+                _ => None,
+            }
+        }
+        syn::Expr::Call(expr) if expr.args.is_empty() => {
+            let syn::Expr::Path(func) = &*expr.func else {
+                return None;
+            };
+            let segment = func.path.segments.last()?;
+            match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args)
+                    if segment.ident == "size_of" && args.args.len() == 1 =>
+                {
+                    match &args.args[0] {
+                        syn::GenericArgument::Type(ty) => const_size_of(ty),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+pub fn const_eval_tokens(tokens: &proc_macro2::TokenStream) -> Option<u128> {
+    const_eval(&syn::parse2(tokens.clone()).ok()?)
+}
+
+/// Returns the given type as it'd usually be written in source code.
+pub fn type_name(ty: &syn::Type) -> String {
+    let mut name = quote::quote! { #ty }.to_string();
+    for (from, to) in [
+        (" :: ", "::"),
+        (":: ", "::"),
+        (" < ", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ,", ","),
+        ("& ", "&"),
+        ("[ ", "["),
+        (" ]", "]"),
+        (" ;", ";"),
+        ("( ", "("),
+        (" )", ")"),
+    ] {
+        name = name.replace(from, to);
+    }
+    name
+}
+
 pub fn parse_parens(input: ParseStream<'_>) -> Result<ParseBuffer<'_>> {
     let content;
     parenthesized!(content in input);
@@ -45,11 +203,17 @@ pub fn parse_braces(input: ParseStream<'_>) -> Result<ParseBuffer<'_>> {
     Ok(content)
 }
 
-pub fn maybe_const_assert(is_const: bool) -> proc_macro2::TokenStream {
+/// Returns a statement asserting the given condition, in an inline `const` block if it can be
+/// evaluated at compile time (possibly after monomorphization, if it depends on generic
+/// parameters) or at run time otherwise.
+pub fn maybe_const_assert(
+    is_const: bool,
+    cond: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
     if is_const {
-        quote! { ::proc_bitfield::__private::static_assertions::const_assert! }
+        quote! { const { ::core::assert!(#cond) }; }
     } else {
-        quote! { ::core::assert! }
+        quote! { ::core::assert!(#cond); }
     }
 }
 
@@ -82,31 +246,6 @@ pub fn const_param(ident: Ident, ty: Type, default: Option<Expr>) -> ConstParam
     }
 }
 
-pub struct MaybeRepeat {
-    content: proc_macro2::TokenStream,
-    was_consumed: Cell<bool>,
-    after_first: Option<proc_macro2::TokenStream>,
-}
-
-impl MaybeRepeat {
-    pub fn new(content: proc_macro2::TokenStream, should_repeat: bool) -> Self {
-        MaybeRepeat {
-            content,
-            was_consumed: Cell::new(false),
-            after_first: should_repeat.then(Default::default),
-        }
-    }
-
-    pub fn get(&self) -> &proc_macro2::TokenStream {
-        if let Some(after_first) = &self.after_first {
-            if self.was_consumed.replace(true) {
-                return after_first;
-            }
-        }
-        &self.content
-    }
-}
-
 pub fn parse_terminated<T, P: Parse>(
     input: ParseStream,
     mut parser: impl FnMut(ParseStream) -> syn::Result<T>,
@@ -161,11 +300,11 @@ pub fn add_const_bounds(
     ty: &Type,
     traits: &[proc_macro2::TokenStream],
 ) {
-    where_clause.predicates.push(
-        syn::parse2(quote_spanned! {
+    where_clause.predicates.extend(traits.iter().map(|trait_| {
+        syn::parse2::<WherePredicate>(quote_spanned! {
             span =>
-            #(#ty: [const] #traits),*
+            #ty: [const] #trait_
         })
-        .unwrap(),
-    );
+        .unwrap()
+    }));
 }
@@ -0,0 +1,258 @@
+use crate::{
+    Bit, BitRangeError, Bits, DynBits, DynSetBits, DynWithBits, SetBit, SetBits, WithBit, WithBits,
+};
+use core::ops::{Deref, DerefMut, Range};
+
+macro_rules! endian_wrapper {
+    ($(#[$attr: meta])* $ident: ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        #[repr(transparent)]
+        pub struct $ident<T>(pub T);
+
+        impl<T> $ident<T> {
+            /// Returns the wrapped value.
+            #[inline]
+            pub const fn into_inner(self) -> T
+            where
+                T: Copy,
+            {
+                self.0
+            }
+
+            /// Reinterprets a reference to a value as a reference to the wrapper, allowing it to
+            /// be used in place as bitfield storage.
+            #[inline]
+            pub const fn from_ref(value: &T) -> &Self {
+                // SAFETY: `Self` is `#[repr(transparent)]` over `T`.
+                unsafe { &*(value as *const T as *const Self) }
+            }
+
+            /// Reinterprets a mutable reference to a value as a mutable reference to the wrapper,
+            /// allowing it to be used in place as bitfield storage.
+            #[inline]
+            pub fn from_mut(value: &mut T) -> &mut Self {
+                // SAFETY: `Self` is `#[repr(transparent)]` over `T`.
+                unsafe { &mut *(value as *mut T as *mut Self) }
+            }
+        }
+
+        impl<T> From<T> for $ident<T> {
+            #[inline]
+            fn from(value: T) -> Self {
+                $ident(value)
+            }
+        }
+
+        impl<T> Deref for $ident<T> {
+            type Target = T;
+
+            #[inline]
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T> DerefMut for $ident<T> {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut T {
+                &mut self.0
+            }
+        }
+    };
+}
+
+endian_wrapper! {
+    /// Byte array bitfield storage that's treated as a big-endian integer.
+    ///
+    /// Bit `0` is the least significant bit of the *last* byte, and bit `N * 8 - 1` is the most
+    /// significant bit of the first one; when combined with the `Msb0` option of
+    /// [`bitfield!`](crate::bitfield), bit `0` is instead the most significant bit of the first
+    /// byte, matching the numbering used by most network protocol specifications.
+    BigEndian
+}
+
+endian_wrapper! {
+    /// Byte array bitfield storage that's treated as a little-endian integer.
+    ///
+    /// Bit `0` is the least significant bit of the first byte, and bit `N * 8 - 1` is the most
+    /// significant bit of the last one.
+    LittleEndian
+}
+
+macro_rules! impl_bits_for_endian_arr {
+    ($wrapper: ident, $n: ident, $i: ident => $byte_index: expr, $value: ty $(, $const:tt)?) => {
+        impl<const $n: usize> $($const)* Bits<$value> for $wrapper<[u8; $n]> {
+            #[inline]
+            fn bits<const START: usize, const END: usize>(&self) -> $value {
+                const VALUE_BITS: usize = <$value>::BITS as usize;
+                check_bit_range!(START, END, VALUE_BITS, $n << 3);
+                let mut bits: $value = 0;
+                let mut $i = START >> 3;
+                while $i <= (END - 1) >> 3 {
+                    let start = $crate::__private::max(START, $i << 3);
+                    bits |= ((self.0[$byte_index] >> (start & 7)) as $value) << (start - START);
+                    $i += 1;
+                }
+                let read_bits = END - START;
+                bits << (VALUE_BITS - read_bits) >> (VALUE_BITS - read_bits)
+            }
+        }
+
+        impl<const $n: usize> $($const)* WithBits<$value> for $wrapper<[u8; $n]> {
+            #[inline]
+            fn with_bits<const START: usize, const END: usize>(mut self, value: $value) -> Self {
+                self.set_bits::<START, END>(value);
+                self
+            }
+        }
+
+        impl<const $n: usize> $($const)* SetBits<$value> for $wrapper<[u8; $n]> {
+            #[inline]
+            fn set_bits<const START: usize, const END: usize>(&mut self, value: $value) {
+                const VALUE_BITS: usize = <$value>::BITS as usize;
+                check_bit_range!(write START, END, $n << 3);
+                let mut $i = START >> 3;
+                while $i <= (END - 1) >> 3 {
+                    let start = $crate::__private::max(START, $i << 3);
+                    let end = $crate::__private::min(END, ($i + 1) << 3);
+                    let written_bits = end - start;
+                    let mask = ((1_u8 << (written_bits - 1) << 1).wrapping_sub(1)) << (start & 7);
+                    let byte = &mut self.0[$byte_index];
+                    // Ranges wider than the value type are sign- or zero-extended
+                    let value = if start - START < VALUE_BITS {
+                        value >> (start - START)
+                    } else {
+                        value >> (VALUE_BITS - 1) >> 1
+                    };
+                    *byte = (*byte & !mask) | ((value as u8) << (start & 7) & mask);
+                    $i += 1;
+                }
+            }
+        }
+
+        impl<const $n: usize> $($const)* DynBits<$value> for $wrapper<[u8; $n]> {
+            #[inline]
+            fn dyn_bits(&self, range: Range<usize>) -> Result<$value, BitRangeError> {
+                const VALUE_BITS: usize = <$value>::BITS as usize;
+                let Range { start, end } = range;
+                if start >= end || end > $n << 3 || end - start > VALUE_BITS {
+                    return Err(BitRangeError::new(start, end, $n << 3));
+                }
+                let mut bits: $value = 0;
+                let mut $i = start >> 3;
+                while $i <= (end - 1) >> 3 {
+                    let start_ = $crate::__private::max(start, $i << 3);
+                    bits |= ((self.0[$byte_index] >> (start_ & 7)) as $value) << (start_ - start);
+                    $i += 1;
+                }
+                let read_bits = end - start;
+                Ok(bits << (VALUE_BITS - read_bits) >> (VALUE_BITS - read_bits))
+            }
+        }
+
+        impl<const $n: usize> $($const)* DynWithBits<$value> for $wrapper<[u8; $n]> {
+            #[inline]
+            fn dyn_with_bits(
+                mut self,
+                range: Range<usize>,
+                value: $value,
+            ) -> Result<Self, BitRangeError> {
+                match self.dyn_set_bits(range, value) {
+                    Ok(()) => Ok(self),
+                    Err(err) => Err(err),
+                }
+            }
+        }
+
+        impl<const $n: usize> $($const)* DynSetBits<$value> for $wrapper<[u8; $n]> {
+            #[inline]
+            fn dyn_set_bits(
+                &mut self,
+                range: Range<usize>,
+                value: $value,
+            ) -> Result<(), BitRangeError> {
+                const VALUE_BITS: usize = <$value>::BITS as usize;
+                let Range { start, end } = range;
+                if start >= end || end > $n << 3 {
+                    return Err(BitRangeError::new(start, end, $n << 3));
+                }
+                let mut $i = start >> 3;
+                while $i <= (end - 1) >> 3 {
+                    let start_ = $crate::__private::max(start, $i << 3);
+                    let end_ = $crate::__private::min(end, ($i + 1) << 3);
+                    let written_bits = end_ - start_;
+                    let mask = ((1_u8 << (written_bits - 1) << 1).wrapping_sub(1)) << (start_ & 7);
+                    let byte = &mut self.0[$byte_index];
+                    // Ranges wider than the value type are sign- or zero-extended
+                    let value = if start_ - start < VALUE_BITS {
+                        value >> (start_ - start)
+                    } else {
+                        value >> (VALUE_BITS - 1) >> 1
+                    };
+                    *byte = (*byte & !mask) | ((value as u8) << (start_ & 7) & mask);
+                    $i += 1;
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+macro_rules! impl_bit_for_endian_arr {
+    ($wrapper: ident, $n: ident, $i: ident => $byte_index: expr $(, $const:tt)?) => {
+        impl<const $n: usize> $($const)* Bit for $wrapper<[u8; $n]> {
+            #[inline]
+            fn bit<const BIT: usize>(&self) -> bool {
+                check_bit_index!(BIT, $n << 3);
+                let $i = BIT >> 3;
+                self.0[$byte_index] & 1 << (BIT & 7) != 0
+            }
+        }
+
+        impl<const $n: usize> $($const)* WithBit for $wrapper<[u8; $n]> {
+            #[inline]
+            fn with_bit<const BIT: usize>(mut self, value: bool) -> Self {
+                self.set_bit::<BIT>(value);
+                self
+            }
+        }
+
+        impl<const $n: usize> $($const)* SetBit for $wrapper<[u8; $n]> {
+            #[inline]
+            fn set_bit<const BIT: usize>(&mut self, value: bool) {
+                check_bit_index!(BIT, $n << 3);
+                let $i = BIT >> 3;
+                let byte = &mut self.0[$byte_index];
+                *byte = (*byte & !(1 << (BIT & 7))) | (value as u8) << (BIT & 7);
+            }
+        }
+    };
+}
+
+macro_rules! impl_for_endian_arrs {
+    (const $($value: ty),*) => {
+        impl_bit_for_endian_arr!(BigEndian, N, i => N - 1 - i, const);
+        impl_bit_for_endian_arr!(LittleEndian, N, i => i, const);
+        $(
+            impl_bits_for_endian_arr!(BigEndian, N, i => N - 1 - i, $value, const);
+            impl_bits_for_endian_arr!(LittleEndian, N, i => i, $value, const);
+        )*
+    };
+    ($($value: ty),*) => {
+        impl_bit_for_endian_arr!(BigEndian, N, i => N - 1 - i);
+        impl_bit_for_endian_arr!(LittleEndian, N, i => i);
+        $(
+            impl_bits_for_endian_arr!(BigEndian, N, i => N - 1 - i, $value);
+            impl_bits_for_endian_arr!(LittleEndian, N, i => i, $value);
+        )*
+    };
+}
+
+#[cfg(not(feature = "nightly"))]
+impl_for_endian_arrs!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+#[cfg(feature = "nightly")]
+impl_for_endian_arrs!(
+    const
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
@@ -0,0 +1,121 @@
+use core::fmt;
+
+/// The error type returned by `TryFrom<$storage_ty>` implementations of bitfields using the
+/// `TryFromStorage` option, when the storage value's fixed bits don't have their declared values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FixedBitsError<S>(S);
+
+impl<S> FixedBitsError<S> {
+    /// Creates a new error for the given rejected storage value.
+    #[inline]
+    pub const fn new(storage: S) -> Self {
+        FixedBitsError(storage)
+    }
+
+    /// Returns a reference to the rejected storage value.
+    #[inline]
+    pub const fn storage(&self) -> &S {
+        &self.0
+    }
+
+    /// Returns the rejected storage value.
+    #[inline]
+    pub fn into_storage(self) -> S {
+        self.0
+    }
+}
+
+impl<S> fmt::Display for FixedBitsError<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("fixed bits don't have their declared values")
+    }
+}
+
+impl<S: fmt::Debug> core::error::Error for FixedBitsError<S> {}
+
+/// The error type returned by fallible conversions between bitfields using the `Unpacked` option
+/// and their unpacked forms, when a field's value can't be converted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FieldConversionError {
+    field: &'static str,
+}
+
+impl FieldConversionError {
+    /// Creates a new error for the field with the given name.
+    #[inline]
+    pub const fn new(field: &'static str) -> Self {
+        FieldConversionError { field }
+    }
+
+    /// Returns the name of the field whose value couldn't be converted.
+    #[inline]
+    pub const fn field(&self) -> &'static str {
+        self.field
+    }
+}
+
+impl fmt::Display for FieldConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the value of field `{}` couldn't be converted",
+            self.field
+        )
+    }
+}
+
+impl core::error::Error for FieldConversionError {}
+
+/// The error type returned by [`TryBits`](crate::TryBits), [`TrySetBits`](crate::TrySetBits) and
+/// [`DynBits`](crate::DynBits)-family implementations, when the accessed bit range is invalid: out
+/// of bounds for the storage or, for ranges only known at run time, empty or wider than the
+/// accessed value's type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BitRangeError {
+    start: usize,
+    end: usize,
+    storage_bits: usize,
+}
+
+impl BitRangeError {
+    /// Creates a new error for the given `start..end` bit range, accessed in storage that's
+    /// `storage_bits` bits wide.
+    #[inline]
+    pub const fn new(start: usize, end: usize, storage_bits: usize) -> Self {
+        BitRangeError {
+            start,
+            end,
+            storage_bits,
+        }
+    }
+
+    /// Returns the start of the accessed bit range.
+    #[inline]
+    pub const fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the end of the accessed bit range (excluded).
+    #[inline]
+    pub const fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the width in bits of the storage that was accessed.
+    #[inline]
+    pub const fn storage_bits(&self) -> usize {
+        self.storage_bits
+    }
+}
+
+impl fmt::Display for BitRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid bit range {}..{} for {}-bit storage",
+            self.start, self.end, self.storage_bits
+        )
+    }
+}
+
+impl core::error::Error for BitRangeError {}
@@ -7,8 +7,9 @@ bitfield! {
     /// A bitfield showcasing how to specify bit ranges.
     #[derive(Clone, Copy, PartialEq, Eq)]
     pub struct BitRanges(pub u32): Debug, FromStorage, IntoStorage, DerefStorage {
-        // A single field spanning the entire bitfield, using an unbounded range:
-        pub whole_bitfield: u32 @ ..,                 // Bits 0 to 31
+        // A single field spanning the entire bitfield, using an unbounded range. As it overlaps
+        // with all other fields, it has to be marked as an alias:
+        pub whole_bitfield: u32 [alias] @ ..,         // Bits 0 to 31
 
         // Multi-bit field, specified using an inclusive range:
         pub inclusive_range: u8 @ 0..=3,              // Bits 0 to 3
@@ -52,7 +53,7 @@ bitfield! {
 
 bitfield! {
     /// A bitfield showcasing how to specify access restrictions.
-    pub struct AccessRestrictions(pub u8): Debug, FromStorage, IntoStorage, DerefStorage {
+    pub struct AccessRestrictions(pub u8): Debug, FromStorage, IntoStorage, DerefStorage, Views {
         // By specifying `read_only` (or `ro`), only `AccessRestrictions::read_only_flag` will be
         // generated (no setters):
         pub read_only_flag: bool [read_only] @ 0,
@@ -74,9 +75,10 @@ bitfield! {
 
 bitfield! {
     /// A bitfield showcasing how to use nested bitfields.
-    pub struct NestedBitfields(pub u16): Debug, FromStorage, IntoStorage, DerefStorage {
-        // By specifying `read_only` (or `ro`), only `NestedBitfields::read_only_nested` will be
-        // generated (no setters and no mutable reference access):
+    pub struct NestedBitfields(pub u16): Debug, FromStorage, IntoStorage, DerefStorage, Views {
+        // By specifying `read_only` (or `ro`), only `NestedBitfields::read_only_nested` and
+        // `NestedBitfields::read_only_nested_ref` will be generated (no setters and no mutable
+        // reference access):
         pub read_only_nested: nested AccessRestrictions [read_only] @ 0; 3,
         // Equivalent:
         // pub read_only_nested: nested AccessRestrictions [ro] @ 0; 3,
@@ -90,7 +92,8 @@ bitfield! {
 
         // All accessors will be generated without any explicit access restrictions:
         // - `NestedBitfields::read_write_nested`
-        // - `NestedBitfields::read_write_nested_mut`
+        // - `NestedBitfields::read_write_nested_ref` (with the `Views` option)
+        // - `NestedBitfields::read_write_nested_mut` (with the `Views` option)
         // - `NestedBitfields::with_read_write_nested`
         // - `NestedBitfields::set_read_write_nested`
         pub read_write_nested: nested AccessRestrictions @ 8; 3,
@@ -100,6 +103,9 @@ bitfield! {
 bitfield! {
     /// A bitfield showcasing various kinds of field type conversions.
     pub struct FieldTypeConversions(pub u16): Debug, FromStorage, IntoStorage, DerefStorage {
+        // Fields showcasing different conversions for the same bits are marked as aliases of the
+        // first one declared for those bits.
+
         // Infallible conversions
 
         // Will:
@@ -125,12 +131,14 @@ bitfield! {
         // Will:
         // - Return a `U8WithParity` on reads, calling `<U8WithParity as From<u8>>::from`
         // - Take a `u8` for writes
-        pub read_as_u8_with_parity_fn: u8 [get_fn U8WithParity::from -> U8WithParity] @ 0..=3,
+        pub read_as_u8_with_parity_fn: u8 [
+            get_fn U8WithParity::from -> U8WithParity, alias
+        ] @ 0..=3,
 
         // Will:
         // - Return a `u8` on reads
         // - Take a `U8WithParity` for writes, calling `<U8WithParity as Into<u8>>::into`
-        pub write_as_u8_with_parity_fn: u8 [set_fn U8WithParity::into(U8WithParity)] @ 4..=7,
+        pub write_as_u8_with_parity_fn: u8 [set_fn U8WithParity::into(U8WithParity), alias] @ 4..=7,
 
 
         // Unsafe/unchecked conversions
@@ -139,20 +147,20 @@ bitfield! {
         // - Return a `NonZeroU8` on reads, marking them as `unsafe` and calling
         //   `<NonZeroU8 as UnsafeFrom<u8>>::unsafe_from`
         // - Take a `u8` for writes
-        pub unsafe_read_as_non_zero_u8: u8 [unsafe_get NonZeroU8] @ 0..=3,
+        pub unsafe_read_as_non_zero_u8: u8 [unsafe_get NonZeroU8, alias] @ 0..=3,
 
         // Will:
         // - Return a `u8` on reads
         // - Take a `U16` for writes, marking them as `unsafe` and calling
         //   `<U16 as UnsafeInto<u8>>::unsafe_into`
-        pub unsafe_write_as_u16: u8 [unsafe_set U16] @ 4..=7,
+        pub unsafe_write_as_u16: u8 [unsafe_set U16, alias] @ 4..=7,
 
         // Will:
         // - Return a `SpuriouslyFailingU8` on reads, marking them as `unsafe` and calling
         //   `<SpuriouslyFailingU8 as UnsafeFrom<u8>>::unsafe_from`
         // - Take a `SpuriouslyFailingU8` for writes, marking them as `unsafe` and calling
         //   `<SpuriouslyFailingU8 as UnsafeInto<u8>>::unsafe_into`
-        pub unsafe_as_spuriously_failing: u8 [unsafe_both SpuriouslyFailingU8] @ 8..=11,
+        pub unsafe_as_spuriously_failing: u8 [unsafe_both SpuriouslyFailingU8, alias] @ 8..=11,
         // Equivalent to:
         // pub unsafe_as_spuriously_failing: u8
         //  [unsafe_get SpuriouslyFailingU8, unsafe_set SpuriouslyFailingU8] @ 8..=11,
@@ -173,14 +181,14 @@ bitfield! {
         //   `<NonZeroU8 as UnsafeFrom<u8>>::unsafe_from`
         // - Take a `u8` for writes
         pub unsafe_read_as_non_zero_u8_fn: u8 [
-            unsafe_get_fn NonZeroU8::unsafe_from -> NonZeroU8
+            unsafe_get_fn NonZeroU8::unsafe_from -> NonZeroU8, alias
         ] @ 0..=3,
 
         // Will:
         // - Return a `u8` on reads
         // - Take a `U16` for writes, marking them as `unsafe` and calling
         //   `<U16 as UnsafeInto<u8>>::unsafe_into`
-        pub unsafe_write_as_u16_fn: u8 [unsafe_set_fn U16::unsafe_into(U16)] @ 4..=7,
+        pub unsafe_write_as_u16_fn: u8 [unsafe_set_fn U16::unsafe_into(U16), alias] @ 4..=7,
 
 
         // Unsafe/unchecked conversions with safe accessors
@@ -189,20 +197,22 @@ bitfield! {
         // - Return a `NonZeroU8` on reads, unsafely calling
         //   `<NonZeroU8 as UnsafeFrom<u8>>::unsafe_from`
         // - Take a `u8` for writes
-        pub unsafe_read_as_non_zero_u8_safe_acc: u8 [unsafe_get! NonZeroU8] @ 0..=3,
+        pub unsafe_read_as_non_zero_u8_safe_acc: u8 [unsafe_get! NonZeroU8, alias] @ 0..=3,
 
         // Will:
         // - Return a `u8` on reads
         // - Take a `U16` for writes, unsafely calling
         //   `<U16 as UnsafeInto<u8>>::unsafe_into`
-        pub unsafe_write_as_u16_safe_acc: u8 [unsafe_set! U16] @ 4..=7,
+        pub unsafe_write_as_u16_safe_acc: u8 [unsafe_set! U16, alias] @ 4..=7,
 
         // Will:
         // - Return a `SpuriouslyFailingU8` on reads, unsafely calling
         //   `<SpuriouslyFailingU8 as UnsafeFrom<u8>>::unsafe_from`
         // - Take a `SpuriouslyFailingU8` for writes, unsafely calling
         //   `<SpuriouslyFailingU8 as UnsafeInto<u8>>::unsafe_into`
-        pub unsafe_as_spuriously_failing_safe_acc: u8 [unsafe_both! SpuriouslyFailingU8] @ 8..=11,
+        pub unsafe_as_spuriously_failing_safe_acc: u8 [
+            unsafe_both! SpuriouslyFailingU8, alias
+        ] @ 8..=11,
         // Equivalent to:
         // pub unsafe_as_spuriously_failing_safe_acc: u8
         //  [unsafe_get! SpuriouslyFailingU8, unsafe_set! SpuriouslyFailingU8] @ 8..=11,
@@ -211,7 +221,7 @@ bitfield! {
         // - Return a `NonZeroU8` on reads, unsafely calling
         //   `<NonZeroU8 as UnsafeFrom<u8>>::unsafe_from`
         // - Take a `NonZeroU8` for writes, calling `<NonZeroU8 as Into<u8>>::into`
-        pub unsafe_as_non_zero_u8_safe_acc: u8 [unsafe! NonZeroU8] @ 12..=15,
+        pub unsafe_as_non_zero_u8_safe_acc: u8 [unsafe! NonZeroU8, alias] @ 12..=15,
         // Equivalent to:
         // pub unsafe_as_non_zero_u8_safe_acc: u8 [unsafe_get! NonZeroU8, set NonZeroU8] @ 12..=15,
 
@@ -223,13 +233,15 @@ bitfield! {
         //   `<NonZeroU8 as UnsafeFrom<u8>>::unsafe_from`
         // - Take a `u8` for writes
         pub unsafe_read_as_non_zero_u8_fn_safe_acc: u8 [
-            unsafe_get_fn! NonZeroU8::unsafe_from -> NonZeroU8
+            unsafe_get_fn! NonZeroU8::unsafe_from -> NonZeroU8, alias
         ] @ 0..=3,
 
         // Will:
         // - Return a `u8` on reads
         // - Take a `U16` for writes, unsafely calling `<U16 as UnsafeInto<u8>>::unsafe_into`
-        pub unsafe_write_as_u16_fn_safe_acc: u8 [unsafe_set_fn! U16::unsafe_into(U16)] @ 4..=7,
+        pub unsafe_write_as_u16_fn_safe_acc: u8 [
+            unsafe_set_fn! U16::unsafe_into(U16), alias
+        ] @ 4..=7,
 
 
         // Fallible conversions
@@ -238,20 +250,20 @@ bitfield! {
         // - Return a `Result<NonZeroU8, TryFromIntError>` on reads, calling
         //   `<NonZeroU8 as TryFrom<u8>>::try_from`
         // - Take a `u8` for writes
-        pub try_read_as_non_zero_u8: u8 [try_get NonZeroU8] @ 0..=3,
+        pub try_read_as_non_zero_u8: u8 [try_get NonZeroU8, alias] @ 0..=3,
 
         // Will:
         // - Return a `u8` on reads
         // - Take a `U16` for writes, returning `Result<(), TryFromIntError>` and calling
         //   `<U16 as TryInto<u8>>::try_into`
-        pub try_write_as_u16: u8 [try_set U16] @ 4..=7,
+        pub try_write_as_u16: u8 [try_set U16, alias] @ 4..=7,
 
         // Will:
         // - Return a `Result<SpuriouslyFailingU8, ()>` on reads, calling
         //   `<SpuriouslyFailingU8 as TryFrom<u8>>::try_from`
         // - Take a `SpuriouslyFailingU8` for writes, returning `Result<(), ()>` and calling
         //   `<SpuriouslyFailingU8 as TryInto<u8>>::try_into`
-        pub try_both_as_spuriously_failing: u8 [try_both SpuriouslyFailingU8] @ 8..=11,
+        pub try_both_as_spuriously_failing: u8 [try_both SpuriouslyFailingU8, alias] @ 8..=11,
         // Equivalent to:
         // pub try_both_as_spuriously_failing: u8
         //  [try_get SpuriouslyFailingU8, try_set SpuriouslyFailingU8] @ 8..=11,
@@ -260,7 +272,7 @@ bitfield! {
         // - Return a `Result<NonZeroU8, TryFromIntError>` on reads, calling
         //   `<NonZeroU8 as TryFrom<u8>>::try_from`
         // - Take a `NonZeroU8` for writes, calling `<NonZeroU8 as Into<u8>>::into`
-        pub try_as_non_zero_u8: u8 [try NonZeroU8] @ 12..=15,
+        pub try_as_non_zero_u8: u8 [try NonZeroU8, alias] @ 12..=15,
         // Equivalent to:
         // pub try_as_non_zero_u8: u8 [try_get NonZeroU8, set NonZeroU8] @ 12..=15,
 
@@ -272,7 +284,7 @@ bitfield! {
         //   `<NonZeroU8 as TryFrom<u8>>::try_from`
         // - Take a `u8` for writes
         pub try_read_as_non_zero_u8_fn: u8 [
-            try_get_fn NonZeroU8::try_from -> Result<NonZeroU8, TryFromIntError>
+            try_get_fn NonZeroU8::try_from -> Result<NonZeroU8, TryFromIntError>, alias
         ] @ 0..=3,
 
         // Will:
@@ -280,7 +292,7 @@ bitfield! {
         // - Take a `U16` for writes, returning `Result<(), TryFromIntError>` and calling
         //   `<U16 as TryInto<u8>>::try_into`
         pub try_write_as_u16_fn: u8 [
-            try_set_fn U16::try_into(U16) -> Result<u8, TryFromIntError>
+            try_set_fn U16::try_into(U16) -> Result<u8, TryFromIntError>, alias
         ] @ 4..=7,
 
 
@@ -290,20 +302,20 @@ bitfield! {
         // - Return a `NonZeroU8` on reads, calling `<NonZeroU8 as TryFrom<u8>>::try_from` and
         //   unwrapping the result
         // - Take a `u8` for writes
-        pub unwrap_read_as_non_zero_u8: u8 [unwrap_get NonZeroU8] @ 0..=3,
+        pub unwrap_read_as_non_zero_u8: u8 [unwrap_get NonZeroU8, alias] @ 0..=3,
 
         // Will:
         // - Return a `u8` on reads
         // - Take a `U16` for writes, returning `()`, calling `<U16 as TryInto<u8>>::try_into` and
         //   unwrapping the result
-        pub unwrap_write_as_u16: u8 [unwrap_set U16] @ 4..=7,
+        pub unwrap_write_as_u16: u8 [unwrap_set U16, alias] @ 4..=7,
 
         // Will:
         // - Return a `SpuriouslyFailingU8` on reads, calling
         //   `<SpuriouslyFailingU8 as TryFrom<u8>>::try_from` and unwrapping the result
         // - Take a `SpuriouslyFailingU8` for writes, returning `()`, calling
         //   `<SpuriouslyFailingU8 as TryInto<u8>>::try_into` and unwrapping the result
-        pub unwrap_both_as_spuriously_failing: u8 [unwrap_both SpuriouslyFailingU8] @ 8..=11,
+        pub unwrap_both_as_spuriously_failing: u8 [unwrap_both SpuriouslyFailingU8, alias] @ 8..=11,
         // Equivalent to:
         // pub try_both_as_spuriously_failing: u8
         //  [try_get SpuriouslyFailingU8, try_set SpuriouslyFailingU8] @ 8..=11,
@@ -312,7 +324,7 @@ bitfield! {
         // - Return a `NonZeroU8` on reads, calling `<NonZeroU8 as TryFrom<u8>>::try_from` and
         //   unwrapping the result
         // - Take a `NonZeroU8` for writes, calling `<NonZeroU8 as Into<u8>>::into`
-        pub unwrap_as_non_zero_u8: u8 [unwrap NonZeroU8] @ 12..=15,
+        pub unwrap_as_non_zero_u8: u8 [unwrap NonZeroU8, alias] @ 12..=15,
         // Equivalent to:
         // pub unwrap_as_non_zero_u8: u8 [unwrap_get NonZeroU8, set NonZeroU8] @ 12..=15,
 
@@ -323,13 +335,15 @@ bitfield! {
         // - Return a `NonZeroU8` on reads, calling `<NonZeroU8 as TryFrom<u8>>::try_from` and
         //   unwrapping the result
         // - Take a `u8` for writes
-        pub unwrap_read_as_non_zero_u8_fn: u8 [unwrap_get_fn NonZeroU8::try_from -> NonZeroU8] @ 0..=3,
+        pub unwrap_read_as_non_zero_u8_fn: u8 [
+            unwrap_get_fn NonZeroU8::try_from -> NonZeroU8, alias
+        ] @ 0..=3,
 
         // Will:
         // - Return a `u8` on reads
         // - Take a `U16` for writes, returning `()`, calling `<U16 as TryInto<u8>>::try_into` and
         //   unwrapping the result
-        pub unwrap_write_as_u16_fn: u8 [unwrap_set_fn U16::try_into(U16)] @ 4..=7,
+        pub unwrap_write_as_u16_fn: u8 [unwrap_set_fn U16::try_into(U16), alias] @ 4..=7,
     }
 }
 
@@ -5,7 +5,7 @@
 #![cfg_attr(all(any(doc, test), feature = "nightly"), feature(trivial_bounds))]
 #![warn(clippy::all)]
 #![cfg_attr(
-    all(any(doc, test), feature = "gce"),
+    all(doc, feature = "gce"),
     feature(generic_const_exprs),
     expect(incomplete_features)
 )]
@@ -28,9 +28,52 @@ macro_rules! const_trait {
     };
 }
 
+/// Checks at compile time (after monomorphization) that the `$start..$end` bit range is non-empty,
+/// fits in a value of `$value_bits` bits and, if specified, in storage of `$storage_bits` bits.
+///
+/// The `write` form doesn't check the range's width, as written values are sign- or zero-extended
+/// to it.
+macro_rules! check_bit_range {
+    (write $start: ident, $end: ident $(, $storage_bits: expr)?) => {
+        const {
+            assert!($start < $end, "bit ranges must not be empty");
+            $(assert!(
+                $end <= $storage_bits,
+                "bit range is out of bounds for the storage type"
+            );)?
+        }
+    };
+    ($start: ident, $end: ident, $value_bits: expr $(, $storage_bits: expr)?) => {
+        const {
+            assert!($start < $end, "bit ranges must not be empty");
+            assert!(
+                $end - $start <= $value_bits,
+                "bit range is wider than the value type"
+            );
+            $(assert!(
+                $end <= $storage_bits,
+                "bit range is out of bounds for the storage type"
+            );)?
+        }
+    };
+}
+
+/// Checks at compile time (after monomorphization) that `$bit` is in bounds for storage of
+/// `$storage_bits` bits.
+macro_rules! check_bit_index {
+    ($bit: ident, $storage_bits: expr) => {
+        const {
+            assert!(
+                $bit < $storage_bits,
+                "bit index is out of bounds for the storage type"
+            );
+        }
+    };
+}
+
 #[doc(hidden)]
 pub mod __private {
-    pub use static_assertions;
+    use core::sync::atomic::Ordering;
 
     #[inline(always)]
     pub const fn min(a: usize, b: usize) -> usize {
@@ -50,6 +93,109 @@ pub mod __private {
         }
     }
 
+    /// Returns the `start..=end` bit range as a `Range`, for `dyn` bit ranges of the `bits!` family
+    /// of macros; if `end` is `usize::MAX`, the returned range is empty, so that accesses fail.
+    #[inline(always)]
+    pub const fn dyn_range_inclusive(start: usize, end: usize) -> core::ops::Range<usize> {
+        start..end.wrapping_add(1)
+    }
+
+    /// Returns the bit range starting at `start` and `length` bits long, for `dyn` bit ranges of
+    /// the `bits!` family of macros; if its end overflows, the returned range is reversed, so that
+    /// accesses fail.
+    #[inline(always)]
+    pub const fn dyn_range_offset_and_length(
+        start: usize,
+        length: usize,
+    ) -> core::ops::Range<usize> {
+        start..start.wrapping_add(length)
+    }
+
+    /// Returns the ordering to use for the loads performed by compare-and-swap loops using the
+    /// given ordering for their stores, for atomic bitfield operations.
+    #[inline(always)]
+    pub const fn failure_ordering(order: Ordering) -> Ordering {
+        match order {
+            Ordering::Release => Ordering::Relaxed,
+            Ordering::AcqRel => Ordering::Acquire,
+            order => order,
+        }
+    }
+
+    /// Returns the range of bits of a nested bitfield view, given the range of its parent view,
+    /// the range of the field inside the parent and the width of the nested bitfield's storage;
+    /// the range is clamped to the parent's, so bits outside of it read as zero and aren't written.
+    #[inline(always)]
+    pub const fn nested_view_range(
+        parent: core::ops::Range<usize>,
+        field: core::ops::Range<usize>,
+        storage_bits: usize,
+    ) -> core::ops::Range<usize> {
+        let start = min(parent.start + field.start, parent.end);
+        let end = min(
+            min(parent.start + field.end, parent.end),
+            start + storage_bits,
+        );
+        start..end
+    }
+
+    /// Creates shared views of bitfields using the `Views` option, for nested field accessors.
+    pub trait NestedView<'a, S: ?Sized> {
+        fn __from_storage(storage: &'a S, range: core::ops::Range<usize>) -> Self;
+    }
+
+    /// Creates mutable views of bitfields using the `Views` option, for nested field accessors.
+    pub trait NestedMutView<'a, S: ?Sized> {
+        fn __from_storage(storage: &'a mut S, range: core::ops::Range<usize>) -> Self;
+    }
+
+    /// Converts the results of fallible accessors into options, for conversions between bitfields
+    /// and their unpacked forms.
+    pub trait IntoOption {
+        type Output;
+
+        fn into_option(self) -> Option<Self::Output>;
+    }
+
+    impl<T> IntoOption for Option<T> {
+        type Output = T;
+
+        #[inline(always)]
+        fn into_option(self) -> Option<T> {
+            self
+        }
+    }
+
+    impl<T, E> IntoOption for Result<T, E> {
+        type Output = T;
+
+        #[inline(always)]
+        fn into_option(self) -> Option<T> {
+            self.ok()
+        }
+    }
+
+    /// The resolved layout of a bitfield field, as declared (i.e. before any MSB-0 translation),
+    /// checked by `assert_layout!`, along with its bits in the storage with LSB-0 numbering, used
+    /// by generated code.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct FieldLayout {
+        pub start: usize,
+        pub end: usize,
+        pub lsb0_start: usize,
+        pub lsb0_end: usize,
+        pub len: usize,
+        pub is_nested: bool,
+        pub is_readable: bool,
+        pub is_writable: bool,
+    }
+
+    pub struct ArrayIndex<const INDEX: usize, const LEN: usize>;
+
+    impl<const INDEX: usize, const LEN: usize> ArrayIndex<INDEX, LEN> {
+        pub const CHECK: () = assert!(INDEX < LEN, "index out of bounds for field array");
+    }
+
     #[cfg(feature = "gce")]
     const_trait! {
         pub trait NestedBitfield<'a, S> {
@@ -76,6 +222,11 @@ pub mod __private {
 #[doc = include_str!("../usage_examples/bitfield.md")]
 pub use macros::bitfield;
 
+/// Checks at compile time that the fields of a bitfield struct have the given bit ranges and
+/// access modes.
+#[doc = include_str!("../usage_examples/assert_layout.md")]
+pub use macros::assert_layout;
+
 /// Reads a single field from an anonymous bitfield, without creating a bitfield struct.
 #[doc = include_str!("../usage_examples/bits.md")]
 pub use macros::bits;
@@ -88,6 +239,11 @@ pub use macros::with_bits;
 #[doc = include_str!("../usage_examples/set_bits.md")]
 pub use macros::set_bits;
 
+/// Defines a register map, providing typed accessors for bitfield registers through a pluggable
+/// access backend.
+#[doc = include_str!("../usage_examples/register_map.md")]
+pub use macros::register_map;
+
 /// A derive macro to implement any applicable conversion traits between an enum and the builtin
 /// integer and boolean types corresponding to variant discriminants.
 #[doc = include_str!("../usage_examples/conv_raw.md")]
@@ -102,6 +258,15 @@ pub use macros::UnwrapBits;
 
 mod conv;
 pub use conv::*;
+mod endian;
+pub use endian::*;
+mod error;
+pub use error::*;
+mod packed;
+pub use packed::*;
+mod reflect;
+pub mod register_map;
+pub use reflect::*;
 mod traits;
 pub use traits::*;
 
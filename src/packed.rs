@@ -0,0 +1,535 @@
+use crate::{DynBits, DynSetBits};
+use core::{fmt, iter::FusedIterator, marker::PhantomData, mem::size_of, ops::Range};
+
+/// An element type that can be stored in bit-packed containers.
+///
+/// Implemented for all primitive integer types; elements of signed types are sign-extended when
+/// read, like bitfield fields.
+pub trait PackedElement: Copy {
+    #[doc(hidden)]
+    fn __from_raw(raw: u128, bits: usize) -> Self;
+
+    #[doc(hidden)]
+    fn __into_raw(self) -> u128;
+}
+
+macro_rules! impl_packed_element {
+    ($($ty: ty => $unsigned: ty),*) => {
+        $(
+            impl PackedElement for $ty {
+                #[inline]
+                fn __from_raw(raw: u128, bits: usize) -> Self {
+                    let shift = <$ty>::BITS as usize - bits;
+                    (raw as $ty) << shift >> shift
+                }
+
+                #[inline]
+                fn __into_raw(self) -> u128 {
+                    self as $unsigned as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_packed_element!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+);
+
+/// A storage type that can back bit-packed containers.
+///
+/// Blanket-implemented for all types supporting run-time bit ranges of `u64` and `u128` values,
+/// including integer arrays and slices and their endian wrappers.
+pub trait PackedStorage: DynBits<u64> + DynBits<u128> + DynSetBits<u64> + DynSetBits<u128> {}
+
+impl<S: ?Sized + DynBits<u64> + DynBits<u128> + DynSetBits<u64> + DynSetBits<u128>> PackedStorage
+    for S
+{
+}
+
+#[inline]
+fn element_range(index: usize, len: usize, bits: usize) -> Range<usize> {
+    assert!(
+        index < len,
+        "index out of bounds: the len is {len} but the index is {index}"
+    );
+    index * bits..(index + 1) * bits
+}
+
+#[inline]
+fn get_raw<S: ?Sized + PackedStorage>(storage: &S, range: Range<usize>) -> u128 {
+    match <S as DynBits<u128>>::dyn_bits(storage, range) {
+        Ok(raw) => raw,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[inline]
+fn set_raw<S: ?Sized + PackedStorage>(storage: &mut S, range: Range<usize>, raw: u128) {
+    if let Err(err) = <S as DynSetBits<u128>>::dyn_set_bits(storage, range, raw) {
+        panic!("{}", err);
+    }
+}
+
+/// Writes `values` to consecutive elements of `storage` starting at the first one, buffering up
+/// to 64 bits at a time for elements that fit in a `u64`.
+///
+/// Iterators can't be checked for their length without consuming them, so too many values are
+/// only detected, and panicked on, after the first `len` have been written.
+fn write_from_iter<T: PackedElement, const BITS: usize, S: ?Sized + PackedStorage>(
+    storage: &mut S,
+    len: usize,
+    values: impl IntoIterator<Item = T>,
+) {
+    let mask = u128::MAX >> (128 - BITS);
+    let mut values = values.into_iter();
+    if BITS > 64 {
+        for (i, value) in values.by_ref().take(len).enumerate() {
+            set_raw(storage, i * BITS..(i + 1) * BITS, value.__into_raw());
+        }
+    } else {
+        let mut start = 0;
+        let mut buf = 0_u128;
+        let mut buf_bits = 0;
+        for value in values.by_ref().take(len) {
+            buf |= (value.__into_raw() & mask) << buf_bits;
+            buf_bits += BITS;
+            if buf_bits >= 64 {
+                if let Err(err) = storage.dyn_set_bits(start..start + 64, buf as u64) {
+                    panic!("{}", err);
+                }
+                start += 64;
+                buf >>= 64;
+                buf_bits -= 64;
+            }
+        }
+        if buf_bits != 0 {
+            if let Err(err) = storage.dyn_set_bits(start..start + buf_bits, buf as u64) {
+                panic!("{}", err);
+            }
+        }
+    }
+    assert!(
+        values.next().is_none(),
+        "too many elements for a bit-packed container of length {len}"
+    );
+}
+
+/// An iterator over the elements of a bit-packed container.
+///
+/// Storage is read 64 bits at a time, each read yielding all of the elements it contains.
+pub struct Iter<'a, T, const BITS: usize, S: ?Sized> {
+    storage: &'a S,
+    index: usize,
+    len: usize,
+    buf: u128,
+    buf_bits: usize,
+    _element: PhantomData<T>,
+}
+
+impl<'a, T, const BITS: usize, S: ?Sized> Iter<'a, T, BITS, S> {
+    #[inline]
+    fn new(storage: &'a S, len: usize) -> Self {
+        Iter {
+            storage,
+            index: 0,
+            len,
+            buf: 0,
+            buf_bits: 0,
+            _element: PhantomData,
+        }
+    }
+}
+
+impl<T, const BITS: usize, S: ?Sized> Clone for Iter<'_, T, BITS, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const BITS: usize, S: ?Sized> Copy for Iter<'_, T, BITS, S> {}
+
+impl<T: PackedElement, const BITS: usize, S: ?Sized + PackedStorage> Iterator
+    for Iter<'_, T, BITS, S>
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.index >= self.len {
+            return None;
+        }
+        let start = self.index * BITS;
+        let raw = if BITS > 64 {
+            get_raw(self.storage, start..start + BITS)
+        } else {
+            if self.buf_bits < BITS {
+                // The buffer holds the bits from `start` onwards, less than a full element
+                let read_start = start + self.buf_bits;
+                let read_end = (read_start + 64).min(self.len * BITS);
+                let read = match <S as DynBits<u64>>::dyn_bits(self.storage, read_start..read_end) {
+                    Ok(read) => read,
+                    Err(err) => panic!("{}", err),
+                };
+                self.buf |= (read as u128) << self.buf_bits;
+                self.buf_bits += read_end - read_start;
+            }
+            let raw = self.buf & (u128::MAX >> (128 - BITS));
+            self.buf >>= BITS;
+            self.buf_bits -= BITS;
+            raw
+        };
+        self.index += 1;
+        Some(T::__from_raw(raw, BITS))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<T: PackedElement, const BITS: usize, S: ?Sized + PackedStorage> ExactSizeIterator
+    for Iter<'_, T, BITS, S>
+{
+}
+
+impl<T: PackedElement, const BITS: usize, S: ?Sized + PackedStorage> FusedIterator
+    for Iter<'_, T, BITS, S>
+{
+}
+
+macro_rules! impl_packed_accessors {
+    ($storage: ty) => {
+        /// Returns the element at `index`.
+        ///
+        /// # Panics
+        /// Panics if `index` is out of bounds.
+        #[inline]
+        pub fn get(&self, index: usize) -> T {
+            T::__from_raw(
+                get_raw(&self.storage, element_range(index, self.len(), BITS)),
+                BITS,
+            )
+        }
+
+        /// Returns the element at `index`, or `None` if `index` is out of bounds.
+        #[inline]
+        pub fn get_checked(&self, index: usize) -> Option<T> {
+            if index < self.len() {
+                Some(self.get(index))
+            } else {
+                None
+            }
+        }
+
+        /// Returns the element at `index`, converted to `U` through [`From`].
+        ///
+        /// # Panics
+        /// Panics if `index` is out of bounds.
+        #[inline]
+        pub fn get_as<U: From<T>>(&self, index: usize) -> U {
+            U::from(self.get(index))
+        }
+
+        /// Returns the element at `index`, converted to `U` through [`TryFrom`].
+        ///
+        /// # Panics
+        /// Panics if `index` is out of bounds.
+        #[inline]
+        pub fn try_get_as<U: TryFrom<T>>(&self, index: usize) -> Result<U, U::Error> {
+            U::try_from(self.get(index))
+        }
+
+        /// Sets the element at `index` to `value`, truncated to `BITS` bits.
+        ///
+        /// # Panics
+        /// Panics if `index` is out of bounds.
+        #[inline]
+        pub fn set(&mut self, index: usize, value: T) {
+            let range = element_range(index, self.len(), BITS);
+            set_raw(&mut self.storage, range, value.__into_raw());
+        }
+
+        /// Sets the element at `index` to `value` converted through [`Into`].
+        ///
+        /// # Panics
+        /// Panics if `index` is out of bounds.
+        #[inline]
+        pub fn set_from<U: Into<T>>(&mut self, index: usize, value: U) {
+            self.set(index, value.into());
+        }
+
+        /// Sets the element at `index` to `value` converted through [`TryInto`], leaving it
+        /// unchanged if the conversion fails.
+        ///
+        /// # Panics
+        /// Panics if `index` is out of bounds.
+        #[inline]
+        pub fn try_set_from<U: TryInto<T>>(
+            &mut self,
+            index: usize,
+            value: U,
+        ) -> Result<(), U::Error> {
+            self.set(index, value.try_into()?);
+            Ok(())
+        }
+
+        /// Returns an iterator over the elements.
+        #[inline]
+        pub fn iter(&self) -> Iter<'_, T, BITS, $storage> {
+            Iter::new(&self.storage, self.len())
+        }
+
+        /// Overwrites the first elements with the ones yielded by `values`, in order.
+        ///
+        /// # Panics
+        /// Panics if `values` yields more elements than the container holds; as this can only be
+        /// detected once it yields one more, all elements will have been overwritten by then.
+        #[inline]
+        pub fn copy_from_iter(&mut self, values: impl IntoIterator<Item = T>) {
+            let len = self.len();
+            write_from_iter::<T, BITS, $storage>(&mut self.storage, len, values);
+        }
+    };
+}
+
+/// A fixed-length array of `N` elements of type `T`, each stored in `BITS` bits of `S`.
+///
+/// `S` is the backing storage, usually an integer array such as `[u8; 5]` or `[u64; 2]`; elements
+/// are packed LSB-first, element `i` occupying bits `i * BITS..(i + 1) * BITS` and crossing word
+/// boundaries as needed. Storage bits past the last element are left untouched.
+///
+/// Checked at compile time, `BITS` must be non-zero and at most the bit width of `T`, and `S` must
+/// have room for `N * BITS` bits.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct BitPackedArray<T, const BITS: usize, const N: usize, S> {
+    storage: S,
+    _element: PhantomData<T>,
+}
+
+impl<T, const BITS: usize, const N: usize, S> BitPackedArray<T, BITS, N, S> {
+    const CHECK: () = {
+        assert!(BITS != 0, "bit-packed elements must not be empty");
+        assert!(
+            BITS <= size_of::<T>() << 3,
+            "bit-packed elements are wider than the element type"
+        );
+        assert!(
+            N * BITS <= size_of::<S>() << 3,
+            "storage is too small for the bit-packed array"
+        );
+    };
+
+    /// Wraps `storage`, interpreting its contents as packed elements.
+    #[inline]
+    pub const fn from_storage(storage: S) -> Self {
+        let () = Self::CHECK;
+        BitPackedArray {
+            storage,
+            _element: PhantomData,
+        }
+    }
+
+    /// Returns the backing storage.
+    #[inline]
+    pub fn into_storage(self) -> S {
+        self.storage
+    }
+
+    /// Returns a reference to the backing storage.
+    #[inline]
+    pub const fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Returns a mutable reference to the backing storage.
+    #[inline]
+    pub fn storage_mut(&mut self) -> &mut S {
+        &mut self.storage
+    }
+
+    /// Returns the number of elements, `N`.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the array holds no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+}
+
+impl<T: PackedElement, const BITS: usize, const N: usize, S: PackedStorage>
+    BitPackedArray<T, BITS, N, S>
+{
+    impl_packed_accessors!(S);
+}
+
+impl<T, const BITS: usize, const N: usize, S: Default> Default for BitPackedArray<T, BITS, N, S> {
+    #[inline]
+    fn default() -> Self {
+        Self::from_storage(S::default())
+    }
+}
+
+impl<T: PackedElement, const BITS: usize, const N: usize, S: PackedStorage + Default>
+    FromIterator<T> for BitPackedArray<T, BITS, N, S>
+{
+    /// Creates an array from the elements yielded by `iter`, leaving any remaining elements zeroed.
+    ///
+    /// # Panics
+    /// Panics if `iter` yields more than `N` elements.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut result = Self::from_storage(S::default());
+        result.copy_from_iter(iter);
+        result
+    }
+}
+
+impl<'a, T: PackedElement, const BITS: usize, const N: usize, S: PackedStorage> IntoIterator
+    for &'a BitPackedArray<T, BITS, N, S>
+{
+    type Item = T;
+    type IntoIter = Iter<'a, T, BITS, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PackedElement + fmt::Debug, const BITS: usize, const N: usize, S: PackedStorage> fmt::Debug
+    for BitPackedArray<T, BITS, N, S>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PackedElement + PartialEq, const BITS: usize, const N: usize, S: PackedStorage> PartialEq
+    for BitPackedArray<T, BITS, N, S>
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PackedElement + Eq, const BITS: usize, const N: usize, S: PackedStorage> Eq
+    for BitPackedArray<T, BITS, N, S>
+{
+}
+
+/// A dynamically-sized slice of elements of type `T`, each stored in `BITS` bits of a `[W]` slice.
+///
+/// Elements are packed like in [`BitPackedArray`], and the slice holds as many of them as fit in
+/// the backing words; it's only ever used behind a reference, created with
+/// [`from_slice`](Self::from_slice) or [`from_mut_slice`](Self::from_mut_slice).
+///
+/// Checked at compile time, `BITS` must be non-zero and at most the bit width of `T`.
+#[repr(transparent)]
+pub struct BitPackedSlice<T, const BITS: usize, W> {
+    _element: PhantomData<T>,
+    storage: [W],
+}
+
+impl<T, const BITS: usize, W> BitPackedSlice<T, BITS, W> {
+    const CHECK: () = {
+        assert!(BITS != 0, "bit-packed elements must not be empty");
+        assert!(
+            BITS <= size_of::<T>() << 3,
+            "bit-packed elements are wider than the element type"
+        );
+    };
+
+    /// Reinterprets a slice of words as a bit-packed slice.
+    #[inline]
+    pub const fn from_slice(storage: &[W]) -> &Self {
+        let () = Self::CHECK;
+        // SAFETY: `Self` is `#[repr(transparent)]` over `[W]`.
+        unsafe { &*(storage as *const [W] as *const Self) }
+    }
+
+    /// Reinterprets a mutable slice of words as a mutable bit-packed slice.
+    #[inline]
+    pub fn from_mut_slice(storage: &mut [W]) -> &mut Self {
+        let () = Self::CHECK;
+        // SAFETY: `Self` is `#[repr(transparent)]` over `[W]`.
+        unsafe { &mut *(storage as *mut [W] as *mut Self) }
+    }
+
+    /// Returns the backing storage.
+    #[inline]
+    pub const fn storage(&self) -> &[W] {
+        &self.storage
+    }
+
+    /// Returns the backing storage mutably.
+    #[inline]
+    pub fn storage_mut(&mut self) -> &mut [W] {
+        &mut self.storage
+    }
+
+    /// Returns the number of elements that fit in the backing storage.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.storage.len() * (size_of::<W>() << 3) / BITS
+    }
+
+    /// Returns `true` if the slice holds no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T: PackedElement, const BITS: usize, W> BitPackedSlice<T, BITS, W>
+where
+    [W]: PackedStorage,
+{
+    impl_packed_accessors!([W]);
+}
+
+impl<'a, T: PackedElement, const BITS: usize, W> IntoIterator for &'a BitPackedSlice<T, BITS, W>
+where
+    [W]: PackedStorage,
+{
+    type Item = T;
+    type IntoIter = Iter<'a, T, BITS, [W]>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PackedElement + fmt::Debug, const BITS: usize, W> fmt::Debug for BitPackedSlice<T, BITS, W>
+where
+    [W]: PackedStorage,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PackedElement + PartialEq, const BITS: usize, W> PartialEq for BitPackedSlice<T, BITS, W>
+where
+    [W]: PackedStorage,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PackedElement + Eq, const BITS: usize, W> Eq for BitPackedSlice<T, BITS, W> where
+    [W]: PackedStorage
+{
+}
@@ -0,0 +1,130 @@
+use crate::Bitfield;
+use core::ops::Range;
+
+/// The access mode of a bitfield field, as described by [`FieldInfo`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FieldAccess {
+    /// The field has both getters and setters.
+    ReadWrite,
+    /// The field only has getters.
+    ReadOnly,
+    /// The field only has setters.
+    WriteOnly,
+}
+
+impl FieldAccess {
+    /// Returns whether the field has getters.
+    #[inline]
+    pub const fn is_readable(self) -> bool {
+        matches!(self, FieldAccess::ReadWrite | FieldAccess::ReadOnly)
+    }
+
+    /// Returns whether the field has setters.
+    #[inline]
+    pub const fn is_writable(self) -> bool {
+        matches!(self, FieldAccess::ReadWrite | FieldAccess::WriteOnly)
+    }
+}
+
+/// Runtime information about a field of a bitfield implementing [`Reflect`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// The field's name.
+    pub name: &'static str,
+    /// The range of bits occupied by the field in the bitfield's storage, using LSB-0 numbering.
+    /// For field arrays, this covers all elements.
+    pub bits: Range<usize>,
+    /// The number of elements of the field, if it's a field array, or 1 otherwise.
+    pub len: usize,
+    /// The field's access mode.
+    pub access: FieldAccess,
+    /// The field's declared type, as written in the bitfield declaration.
+    pub ty: &'static str,
+    /// The field's documentation, taken from its doc comments.
+    pub doc: &'static str,
+    /// The fields of the field's type, if it's a nested bitfield field, with bit ranges relative
+    /// to the nested bitfield.
+    pub nested: Option<&'static [FieldInfo]>,
+}
+
+/// Trait implemented by bitfields using the `Reflect` option, providing runtime information about
+/// their fields and access to their raw values by index or by name.
+///
+/// Raw values are the field's bits as an unsigned integer, before any conversion; they can be
+/// read and written regardless of the field's access mode, and values written to a field are
+/// truncated to its width. Fields wider than 128 bits can't be reflected.
+pub trait Reflect: Bitfield {
+    /// Information about every field of the bitfield, except reserved and fixed bits, in
+    /// declaration order.
+    const FIELDS: &'static [FieldInfo];
+
+    #[doc(hidden)]
+    fn __storage(&self) -> &Self::Storage;
+
+    #[doc(hidden)]
+    fn __storage_mut(&mut self) -> &mut Self::Storage;
+
+    #[doc(hidden)]
+    fn __get_raw(storage: &Self::Storage, index: usize, path: Option<&str>) -> Option<u128>;
+
+    #[doc(hidden)]
+    fn __set_raw(
+        storage: &mut Self::Storage,
+        index: usize,
+        path: Option<&str>,
+        value: u128,
+    ) -> bool;
+
+    #[doc(hidden)]
+    fn __get_raw_by_name(storage: &Self::Storage, path: &str) -> Option<u128> {
+        let (name, rest) = split_path(path);
+        Self::__get_raw(storage, Self::field_index(name)?, rest)
+    }
+
+    #[doc(hidden)]
+    fn __set_raw_by_name(storage: &mut Self::Storage, path: &str, value: u128) -> bool {
+        let (name, rest) = split_path(path);
+        match Self::field_index(name) {
+            Some(index) => Self::__set_raw(storage, index, rest, value),
+            None => false,
+        }
+    }
+
+    /// Returns the index in [`FIELDS`](Self::FIELDS) of the field with the given name, if any.
+    fn field_index(name: &str) -> Option<usize> {
+        Self::FIELDS.iter().position(|field| field.name == name)
+    }
+
+    /// Returns the raw value of the field with the given index in [`FIELDS`](Self::FIELDS), or
+    /// `None` if it's out of bounds.
+    fn get_raw(&self, index: usize) -> Option<u128> {
+        Self::__get_raw(self.__storage(), index, None)
+    }
+
+    /// Sets the raw value of the field with the given index in [`FIELDS`](Self::FIELDS),
+    /// returning `false` if it's out of bounds.
+    fn set_raw(&mut self, index: usize, value: u128) -> bool {
+        Self::__set_raw(self.__storage_mut(), index, None, value)
+    }
+
+    /// Returns the raw value of the field with the given path, made of field names separated by
+    /// `.` to access fields of nested bitfields (i.e. `mode.sub`), or `None` if there's no such
+    /// field.
+    fn get_raw_by_name(&self, path: &str) -> Option<u128> {
+        Self::__get_raw_by_name(self.__storage(), path)
+    }
+
+    /// Sets the raw value of the field with the given path, made of field names separated by `.`
+    /// to access fields of nested bitfields (i.e. `mode.sub`), returning `false` if there's no such
+    /// field.
+    fn set_raw_by_name(&mut self, path: &str, value: u128) -> bool {
+        Self::__set_raw_by_name(self.__storage_mut(), path, value)
+    }
+}
+
+fn split_path(path: &str) -> (&str, Option<&str>) {
+    match path.split_once('.') {
+        Some((name, rest)) => (name, Some(rest)),
+        None => (path, None),
+    }
+}
@@ -0,0 +1,260 @@
+//! Access backends for register maps declared with [`register_map!`](crate::register_map!).
+
+/// A backend through which the registers of a [`register_map!`](crate::register_map!) are
+/// accessed, such as raw memory-mapped I/O, a bus device driver or an in-memory mock.
+///
+/// Addresses are byte offsets, obtained by adding a register's offset to the base address the
+/// register map was created with.
+pub trait RegisterAccess<S> {
+    /// Reads the register of storage type `S` at the given address.
+    fn read(&mut self, addr: usize) -> S;
+
+    /// Writes the given value to the register of storage type `S` at the given address.
+    fn write(&mut self, addr: usize, value: S);
+}
+
+/// A backend performing volatile accesses to memory-mapped registers, with addresses interpreted
+/// as pointers.
+#[derive(Clone, Copy, Debug)]
+pub struct Mmio(());
+
+impl Mmio {
+    /// Creates a new MMIO backend.
+    ///
+    /// # Safety
+    ///
+    /// Every address accessed through it must be properly aligned and valid for volatile reads
+    /// and writes of the accessed register's storage type.
+    #[inline]
+    pub const unsafe fn new() -> Self {
+        Mmio(())
+    }
+}
+
+impl<S> RegisterAccess<S> for Mmio {
+    #[inline]
+    fn read(&mut self, addr: usize) -> S {
+        // SAFETY: The address is valid for volatile reads as required by `Mmio::new`.
+        unsafe { (addr as *const S).read_volatile() }
+    }
+
+    #[inline]
+    fn write(&mut self, addr: usize, value: S) {
+        // SAFETY: The address is valid for volatile writes as required by `Mmio::new`.
+        unsafe { (addr as *mut S).write_volatile(value) }
+    }
+}
+
+/// The kind of a register access recorded by a [`MockBackend`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AccessKind {
+    /// A register read.
+    Read,
+    /// A register write.
+    Write,
+}
+
+/// A register access recorded by a [`MockBackend`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Access {
+    /// Whether the access was a read or a write.
+    pub kind: AccessKind,
+    /// The address of the accessed register.
+    pub addr: usize,
+    /// The size of the accessed register, in bytes.
+    pub size: usize,
+    /// The value that was read or written, zero-extended.
+    pub value: u128,
+}
+
+impl Access {
+    /// Returns a read access of a `size`-byte register at `addr` that returned `value`.
+    #[inline]
+    pub const fn read(addr: usize, size: usize, value: u128) -> Self {
+        Access {
+            kind: AccessKind::Read,
+            addr,
+            size,
+            value,
+        }
+    }
+
+    /// Returns a write access of `value` to a `size`-byte register at `addr`.
+    #[inline]
+    pub const fn write(addr: usize, size: usize, value: u128) -> Self {
+        Access {
+            kind: AccessKind::Write,
+            addr,
+            size,
+            value,
+        }
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Integer types that can be stored in the memory of a [`MockBackend`].
+pub trait MockValue: Copy + sealed::Sealed {
+    #[doc(hidden)]
+    const SIZE: usize;
+    #[doc(hidden)]
+    fn load(bytes: &[u8]) -> Self;
+    #[doc(hidden)]
+    fn store(self, bytes: &mut [u8]);
+    #[doc(hidden)]
+    fn to_u128(self) -> u128;
+}
+
+macro_rules! impl_mock_value {
+    ($($ty: ident ($u_ty: ident)),*) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl MockValue for $ty {
+                const SIZE: usize = ::core::mem::size_of::<$ty>();
+
+                #[inline]
+                fn load(bytes: &[u8]) -> Self {
+                    let mut value = [0; Self::SIZE];
+                    value.copy_from_slice(bytes);
+                    <$ty>::from_le_bytes(value)
+                }
+
+                #[inline]
+                fn store(self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&self.to_le_bytes());
+                }
+
+                #[inline]
+                fn to_u128(self) -> u128 {
+                    self as $u_ty as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_mock_value!(
+    u8(u8),
+    u16(u16),
+    u32(u32),
+    u64(u64),
+    u128(u128),
+    usize(usize),
+    i8(u8),
+    i16(u16),
+    i32(u32),
+    i64(u64),
+    i128(u128),
+    isize(usize)
+);
+
+/// An in-memory register access backend, for testing drivers without hardware.
+///
+/// Registers are stored in `SIZE` bytes of little-endian memory starting at address 0, and the
+/// first `LOG` accesses are recorded in order; later ones are only counted, and can be recorded
+/// again after calling [`clear_accesses`](Self::clear_accesses).
+#[derive(Clone, Debug)]
+pub struct MockBackend<const SIZE: usize, const LOG: usize = 64> {
+    memory: [u8; SIZE],
+    accesses: [Access; LOG],
+    accesses_len: usize,
+    dropped_accesses: usize,
+}
+
+impl<const SIZE: usize, const LOG: usize> MockBackend<SIZE, LOG> {
+    /// Creates a new mock backend with zero-filled memory.
+    #[inline]
+    pub const fn new() -> Self {
+        MockBackend {
+            memory: [0; SIZE],
+            accesses: [Access::read(0, 0, 0); LOG],
+            accesses_len: 0,
+            dropped_accesses: 0,
+        }
+    }
+
+    /// Returns the backend's memory.
+    #[inline]
+    pub fn memory(&self) -> &[u8; SIZE] {
+        &self.memory
+    }
+
+    /// Returns the backend's memory mutably; changes made through it aren't recorded.
+    #[inline]
+    pub fn memory_mut(&mut self) -> &mut [u8; SIZE] {
+        &mut self.memory
+    }
+
+    /// Reads the value at the given address without recording an access.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value doesn't fit inside the backend's memory.
+    #[inline]
+    pub fn peek<S: MockValue>(&self, addr: usize) -> S {
+        S::load(&self.memory[addr..addr + S::SIZE])
+    }
+
+    /// Writes the given value to the given address without recording an access.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value doesn't fit inside the backend's memory.
+    #[inline]
+    pub fn poke<S: MockValue>(&mut self, addr: usize, value: S) {
+        value.store(&mut self.memory[addr..addr + S::SIZE]);
+    }
+
+    /// Returns the recorded accesses, in order.
+    #[inline]
+    pub fn accesses(&self) -> &[Access] {
+        &self.accesses[..self.accesses_len]
+    }
+
+    /// Returns the number of accesses that weren't recorded because the log was full.
+    #[inline]
+    pub fn dropped_accesses(&self) -> usize {
+        self.dropped_accesses
+    }
+
+    /// Clears the recorded accesses.
+    #[inline]
+    pub fn clear_accesses(&mut self) {
+        self.accesses_len = 0;
+        self.dropped_accesses = 0;
+    }
+
+    fn record(&mut self, access: Access) {
+        if self.accesses_len < LOG {
+            self.accesses[self.accesses_len] = access;
+            self.accesses_len += 1;
+        } else {
+            self.dropped_accesses += 1;
+        }
+    }
+}
+
+impl<const SIZE: usize, const LOG: usize> Default for MockBackend<SIZE, LOG> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: MockValue, const SIZE: usize, const LOG: usize> RegisterAccess<S>
+    for MockBackend<SIZE, LOG>
+{
+    fn read(&mut self, addr: usize) -> S {
+        let value = self.peek::<S>(addr);
+        self.record(Access::read(addr, S::SIZE, value.to_u128()));
+        value
+    }
+
+    fn write(&mut self, addr: usize, value: S) {
+        self.poke(addr, value);
+        self.record(Access::write(addr, S::SIZE, value.to_u128()));
+    }
+}
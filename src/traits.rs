@@ -1,10 +1,80 @@
 mod arr_impls;
+mod atomic_impls;
 mod int_impls;
 
+use crate::BitRangeError;
+use core::{ops::Range, sync::atomic::Ordering};
+
 /// Trait implemented by all bitfields generated by the crate in order to know their storage type.
 pub trait Bitfield {
     /// This bitfield's storage type.
     type Storage;
+
+    /// Returns the value to write back to the hardware after reading this one.
+    ///
+    /// Bitfields with write-1-to-clear or write-1-to-set fields or using the `WriteZero` option
+    /// clear the bits that mustn't be written back, through their inherent `to_write` function;
+    /// other bitfields return `self` unchanged.
+    #[inline]
+    fn to_write(self) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+}
+
+/// Trait implemented by bitfields declared with separate `read` and `write` field sections, in
+/// order to know the type used to build values to be written to their storage.
+pub trait ReadWriteBitfield: Bitfield {
+    /// The type of this bitfield's write layout.
+    type Write: Bitfield<Storage = Self::Storage>;
+}
+
+/// Trait implemented by bitfields using the `Unpacked` option, in order to know the type of their
+/// unpacked form.
+pub trait Unpack: Bitfield {
+    /// The unpacked form of this bitfield, with one struct field per bitfield field.
+    type Unpacked;
+}
+
+/// Trait implemented by the field descriptors generated for bitfields using the `Descriptors`
+/// option, allowing a field of a bitfield of type `B` to be passed around as a value and accessed
+/// generically.
+///
+/// Descriptors call the field's accessors, so they perform the same conversions.
+pub trait FieldDescriptor<B: Bitfield>: Copy {
+    /// The type of the field's values, as returned by its getter and taken by its setters.
+    type Value;
+
+    /// The field's name.
+    const NAME: &'static str;
+
+    /// The range of bits occupied by the field in the bitfield's storage, using LSB-0 numbering.
+    const RANGE: Range<usize>;
+
+    /// The width in bits of the field.
+    const BITS: usize = Self::RANGE.end - Self::RANGE.start;
+
+    /// Returns the value of the field in `bitfield`.
+    fn get(self, bitfield: &B) -> Self::Value;
+
+    /// Sets the value of the field in `bitfield` in place.
+    fn set(self, bitfield: &mut B, value: Self::Value);
+
+    /// Returns `bitfield` with the field set to the given value.
+    #[must_use]
+    fn with(self, bitfield: B, value: Self::Value) -> B;
+}
+
+/// Trait implemented by bitfields using the `Views` option, in order to know the types of their
+/// borrowed views, which access them in place in a range of bits of a value of type `S`.
+pub trait Viewable: Bitfield {
+    /// The type of this bitfield's shared views.
+    type Ref<'a, S: ?Sized + 'a>: crate::__private::NestedView<'a, S>;
+
+    /// The type of this bitfield's mutable views.
+    type Mut<'a, S: ?Sized + 'a>: crate::__private::NestedMutView<'a, S>;
 }
 
 #[cfg(feature = "gce")]
@@ -44,6 +114,9 @@ const_trait! {
     /// Read a range of bits inside a value.
     pub trait Bits<T> {
         /// Read `self`'s `START..END` bit range (with `END` excluded) as a value of type `T`.
+        ///
+        /// The range is checked at compile time: it must be non-empty, in bounds for fixed-size
+        /// storage and no wider than `T`.
         fn bits<const START: usize, const END: usize>(&self) -> T;
     }
 }
@@ -54,6 +127,10 @@ const_trait! {
         #[must_use]
         /// Returns `self` with the the `START..END` bit range (with `END` excluded) set to the given
         /// value of type `T`.
+        ///
+        /// The range is checked at compile time like with [`Bits`], except that it can be wider
+        /// than `T`, in which case the value is sign- or zero-extended to its width; the
+        /// [`TrySetBits`], [`DynWithBits`] and [`DynSetBits`] write paths behave the same way.
         fn with_bits<const START: usize, const END: usize>(self, value: T) -> Self;
     }
 }
@@ -63,10 +140,73 @@ const_trait! {
     pub trait SetBits<T> {
         /// Sets `self`'s `START..END` bit range (with `END` excluded) to the given value of type `T`
         /// in place.
+        ///
+        /// As with [`WithBits`], ranges wider than `T` are set to the value sign- or zero-extended
+        /// to their width.
         fn set_bits<const START: usize, const END: usize>(&mut self, value: T);
     }
 }
 
+const_trait! {
+    /// Read a range of bits inside a value whose size is only known at run time, such as a slice,
+    /// checking that the range is in bounds.
+    pub trait TryBits<T> {
+        /// Read `self`'s `START..END` bit range (with `END` excluded) as a value of type `T`, or
+        /// return a [`BitRangeError`] if it's out of bounds.
+        fn try_bits<const START: usize, const END: usize>(&self) -> Result<T, BitRangeError>;
+    }
+}
+
+const_trait! {
+    /// Modify a range of bits inside a value whose size is only known at run time, such as a
+    /// slice, in place, checking that the range is in bounds.
+    pub trait TrySetBits<T> {
+        /// Sets `self`'s `START..END` bit range (with `END` excluded) to the given value of type `T`
+        /// in place, or returns a [`BitRangeError`] without modifying `self` if it's out of
+        /// bounds.
+        ///
+        /// As with [`SetBits`], ranges wider than `T` are set to the value sign- or zero-extended
+        /// to their width.
+        fn try_set_bits<const START: usize, const END: usize>(
+            &mut self,
+            value: T,
+        ) -> Result<(), BitRangeError>;
+    }
+}
+
+const_trait! {
+    /// Read a range of bits inside a value, with the range only known at run time.
+    pub trait DynBits<T> {
+        /// Read `self`'s `range` of bits as a value of type `T`, or return a [`BitRangeError`] if
+        /// the range is empty, wider than `T` or out of bounds.
+        fn dyn_bits(&self, range: Range<usize>) -> Result<T, BitRangeError>;
+    }
+}
+
+const_trait! {
+    /// Return a value with a range of bits modified, with the range only known at run time.
+    pub trait DynWithBits<T>: Sized {
+        /// Returns `self` with the `range` of bits set to the given value of type `T`, or a
+        /// [`BitRangeError`] if the range is empty or out of bounds.
+        ///
+        /// Like with [`WithBits`], ranges wider than `T` are set to the value sign- or
+        /// zero-extended to their width.
+        fn dyn_with_bits(self, range: Range<usize>, value: T) -> Result<Self, BitRangeError>;
+    }
+}
+
+const_trait! {
+    /// Modify a range of bits inside a value in place, with the range only known at run time.
+    pub trait DynSetBits<T> {
+        /// Sets `self`'s `range` of bits to the given value of type `T` in place, or returns a
+        /// [`BitRangeError`] without modifying `self` if the range is empty or out of bounds.
+        ///
+        /// Like with [`SetBits`], ranges wider than `T` are set to the value sign- or zero-extended
+        /// to their width.
+        fn dyn_set_bits(&mut self, range: Range<usize>, value: T) -> Result<(), BitRangeError>;
+    }
+}
+
 const_trait! {
     /// Read a single bit inside a value.
     pub trait Bit {
@@ -92,3 +232,18 @@ const_trait! {
         fn set_bit<const BIT: usize>(&mut self, value: bool);
     }
 }
+
+/// Atomically modify a range of bits inside a value through a shared reference.
+pub trait AtomicSetBits<T> {
+    /// Atomically sets `self`'s `START..END` bit range (with `END` excluded) to the given value of
+    /// type `T`, leaving concurrent modifications of other bits intact; `order` is used for the
+    /// store, as in `fetch_update`.
+    fn atomic_set_bits<const START: usize, const END: usize>(&self, value: T, order: Ordering);
+}
+
+/// Atomically modify a single bit inside a value through a shared reference.
+pub trait AtomicSetBit {
+    /// Atomically sets `self`'s specified bit to 1 if `value` is `true`, and 0 otherwise, leaving
+    /// concurrent modifications of other bits intact.
+    fn atomic_set_bit<const BIT: usize>(&self, value: bool, order: Ordering);
+}
@@ -1,4 +1,9 @@
-use super::{Bit, Bits, SetBit, SetBits, WithBit, WithBits};
+use super::{
+    Bit, Bits, DynBits, DynSetBits, DynWithBits, SetBit, SetBits, TryBits, TrySetBits, WithBit,
+    WithBits,
+};
+use crate::BitRangeError;
+use core::ops::Range;
 
 macro_rules! impl_bits_for_int_type {
     ($storage: ident ($storage_u: ident), $value: ident ($value_u: ident) $(, $const:tt)?) => {
@@ -9,12 +14,26 @@ macro_rules! impl_bits_for_int_type {
             const V_BITS: usize = <$value>::BITS as usize;
             const V_SHIFT: u32 = <$value>::BITS.trailing_zeros();
 
+            /// Shifts `value` right by `shift` bits, which may be its width or more for ranges
+            /// wider than the value type, sign- or zero-extending it.
+            #[inline(always)]
+            const fn extended_shr(value: $value, shift: usize) -> $value {
+                if shift < V_BITS {
+                    value >> shift
+                } else {
+                    value >> (V_BITS - 1) >> 1
+                }
+            }
+
+            #[inline(always)]
+            const fn slice_bits(slice: &[$storage]) -> usize {
+                slice.len().saturating_mul(<$storage>::BITS as usize)
+            }
+
             impl<const N: usize> $($const)* Bits<$value> for [$storage; N] {
                 #[inline]
                 fn bits<const START: usize, const END: usize>(&self) -> $value {
-                    if START >= END {
-                        return 0;
-                    }
+                    check_bit_range!(START, END, V_BITS, N << S_SHIFT);
                     let bits = if START >> S_SHIFT == (END - 1) >> S_SHIFT {
                         (self[START >> S_SHIFT] >> (START & S_MASK)) as $value
                     } else {
@@ -47,9 +66,7 @@ macro_rules! impl_bits_for_int_type {
             impl<const N: usize> $($const)* SetBits<$value> for [$storage; N] {
                 #[inline]
                 fn set_bits<const START: usize, const END: usize>(&mut self, value: $value) {
-                    if START >= END {
-                        return;
-                    }
+                    check_bit_range!(write START, END, N << S_SHIFT);
                     if START >> S_SHIFT == (END - 1) >> S_SHIFT {
                         let i = START >> S_SHIFT;
                         let written_bits = END - START;
@@ -66,7 +83,8 @@ macro_rules! impl_bits_for_int_type {
                             let mask = ((1 as $storage) << (written_bits - 1) << 1).wrapping_sub(1)
                                 << (start & S_MASK);
                             self[i] = (self[i] & !mask)
-                                | (((value >> (start - START)) as $storage) << (start & S_MASK)
+                                | ((extended_shr(value, start - START) as $storage)
+                                    << (start & S_MASK)
                                     & mask);
                             i += 1;
                         }
@@ -77,9 +95,7 @@ macro_rules! impl_bits_for_int_type {
             impl $($const)* Bits<$value> for [$storage] {
                 #[inline]
                 fn bits<const START: usize, const END: usize>(&self) -> $value {
-                    if START >= END {
-                        return 0;
-                    }
+                    check_bit_range!(START, END, V_BITS);
                     let bits = if START >> S_SHIFT == (END - 1) >> S_SHIFT {
                         (self[START >> S_SHIFT] >> (START & S_MASK)) as $value
                     } else {
@@ -101,9 +117,7 @@ macro_rules! impl_bits_for_int_type {
             impl $($const)* SetBits<$value> for [$storage] {
                 #[inline]
                 fn set_bits<const START: usize, const END: usize>(&mut self, value: $value) {
-                    if START >= END {
-                        return;
-                    }
+                    check_bit_range!(write START, END);
                     if START >> S_SHIFT == (END - 1) >> S_SHIFT {
                         let i = START >> S_SHIFT;
                         let written_bits = END - START;
@@ -120,7 +134,8 @@ macro_rules! impl_bits_for_int_type {
                             let mask = ((1 as $storage) << (written_bits - 1) << 1).wrapping_sub(1)
                                 << (start & S_MASK);
                             self[i] = (self[i] & !mask)
-                                | (((value >> (start - START)) as $storage) << (start & S_MASK)
+                                | ((extended_shr(value, start - START) as $storage)
+                                    << (start & S_MASK)
                                     & mask);
                             i += 1;
                         }
@@ -128,12 +143,123 @@ macro_rules! impl_bits_for_int_type {
                 }
             }
 
+            impl $($const)* TryBits<$value> for [$storage] {
+                #[inline]
+                fn try_bits<const START: usize, const END: usize>(
+                    &self,
+                ) -> Result<$value, BitRangeError> {
+                    if (END - 1) >> S_SHIFT >= self.len() {
+                        return Err(BitRangeError::new(START, END, slice_bits(self)));
+                    }
+                    Ok(self.bits::<START, END>())
+                }
+            }
+
+            impl $($const)* TrySetBits<$value> for [$storage] {
+                #[inline]
+                fn try_set_bits<const START: usize, const END: usize>(
+                    &mut self,
+                    value: $value,
+                ) -> Result<(), BitRangeError> {
+                    if (END - 1) >> S_SHIFT >= self.len() {
+                        return Err(BitRangeError::new(START, END, slice_bits(self)));
+                    }
+                    self.set_bits::<START, END>(value);
+                    Ok(())
+                }
+            }
+
+            impl $($const)* DynBits<$value> for [$storage] {
+                #[inline]
+                fn dyn_bits(&self, range: Range<usize>) -> Result<$value, BitRangeError> {
+                    let Range { start, end } = range;
+                    let storage_bits = slice_bits(self);
+                    if start >= end || end > storage_bits || end - start > V_BITS {
+                        return Err(BitRangeError::new(start, end, storage_bits));
+                    }
+                    let bits = if start >> S_SHIFT == (end - 1) >> S_SHIFT {
+                        (self[start >> S_SHIFT] >> (start & S_MASK)) as $value
+                    } else {
+                        let mut bits: $value = 0;
+                        let mut i = start >> S_SHIFT;
+                        while i <= (end - 1) >> S_SHIFT {
+                            let start_ = $crate::__private::max(start, i << S_SHIFT);
+                            bits |= ((self[i] as $storage_u >> (start_ & S_MASK)) as $value)
+                                << (start_ - start);
+                            i += 1;
+                        }
+                        bits
+                    };
+                    let read_bits = end - start;
+                    Ok(bits << (V_BITS - read_bits) >> (V_BITS - read_bits))
+                }
+            }
+
+            impl $($const)* DynSetBits<$value> for [$storage] {
+                #[inline]
+                fn dyn_set_bits(
+                    &mut self,
+                    range: Range<usize>,
+                    value: $value,
+                ) -> Result<(), BitRangeError> {
+                    let Range { start, end } = range;
+                    let storage_bits = slice_bits(self);
+                    if start >= end || end > storage_bits {
+                        return Err(BitRangeError::new(start, end, storage_bits));
+                    }
+                    let mut i = start >> S_SHIFT;
+                    while i <= (end - 1) >> S_SHIFT {
+                        let start_ = $crate::__private::max(start, i << S_SHIFT);
+                        let end_ = $crate::__private::min(end, (i + 1) << S_SHIFT);
+                        let written_bits = end_ - start_;
+                        let mask = ((1 as $storage) << (written_bits - 1) << 1).wrapping_sub(1)
+                            << (start_ & S_MASK);
+                        self[i] = (self[i] & !mask)
+                            | ((extended_shr(value, start_ - start) as $storage)
+                                << (start_ & S_MASK)
+                                & mask);
+                        i += 1;
+                    }
+                    Ok(())
+                }
+            }
+
+            impl<const N: usize> $($const)* DynBits<$value> for [$storage; N] {
+                #[inline]
+                fn dyn_bits(&self, range: Range<usize>) -> Result<$value, BitRangeError> {
+                    (self as &[$storage]).dyn_bits(range)
+                }
+            }
+
+            impl<const N: usize> $($const)* DynWithBits<$value> for [$storage; N] {
+                #[inline]
+                fn dyn_with_bits(
+                    mut self,
+                    range: Range<usize>,
+                    value: $value,
+                ) -> Result<Self, BitRangeError> {
+                    match (&mut self as &mut [$storage]).dyn_set_bits(range, value) {
+                        Ok(()) => Ok(self),
+                        Err(err) => Err(err),
+                    }
+                }
+            }
+
+            impl<const N: usize> $($const)* DynSetBits<$value> for [$storage; N] {
+                #[inline]
+                fn dyn_set_bits(
+                    &mut self,
+                    range: Range<usize>,
+                    value: $value,
+                ) -> Result<(), BitRangeError> {
+                    (self as &mut [$storage]).dyn_set_bits(range, value)
+                }
+            }
+
             impl<const M: usize> $($const)* Bits<[$value; M]> for $storage {
                 #[inline]
                 fn bits<const START: usize, const END: usize>(&self) -> [$value; M] {
-                    if START >= END {
-                        return [0; M];
-                    }
+                    check_bit_range!(START, END, M << V_SHIFT, <$storage>::BITS as usize);
                     let mut result = [0; M];
                     let mut i = 0;
                     while i <= (END - START - 1) >> V_SHIFT {
@@ -154,9 +280,7 @@ macro_rules! impl_bits_for_int_type {
                     mut self,
                     value: [$value; M],
                 ) -> Self {
-                    if START >= END {
-                        return self;
-                    }
+                    check_bit_range!(START, END, M << V_SHIFT, <$storage>::BITS as usize);
                     let mut i = 0;
                     while i <= (END - START - 1) >> V_SHIFT {
                         let start = START + (i << V_SHIFT);
@@ -181,9 +305,7 @@ macro_rules! impl_bits_for_int_type {
             impl<const M: usize, const N: usize> $($const)* Bits<[$value; M]> for [$storage; N] {
                 #[inline]
                 fn bits<const START: usize, const END: usize>(&self) -> [$value; M] {
-                    if START >= END {
-                        return [0; M];
-                    }
+                    check_bit_range!(START, END, M << V_SHIFT, N << S_SHIFT);
                     let mut result = [0; M];
                     let mut i = 0;
                     while i <= (END - START - 1) >> V_SHIFT {
@@ -226,9 +348,7 @@ macro_rules! impl_bits_for_int_type {
             impl<const M: usize, const N: usize> $($const)* SetBits<[$value; M]> for [$storage; N] {
                 #[inline]
                 fn set_bits<const START: usize, const END: usize>(&mut self, value: [$value; M]) {
-                    if START >= END {
-                        return;
-                    }
+                    check_bit_range!(START, END, M << V_SHIFT, N << S_SHIFT);
                     let mut i = 0;
                     while i <= (END - START - 1) >> V_SHIFT {
                         let start = START + (i << V_SHIFT);
@@ -264,9 +384,7 @@ macro_rules! impl_bits_for_int_type {
             impl<const M: usize> $($const)* Bits<[$value; M]> for [$storage] {
                 #[inline]
                 fn bits<const START: usize, const END: usize>(&self) -> [$value; M] {
-                    if START >= END {
-                        return [0; M];
-                    }
+                    check_bit_range!(START, END, M << V_SHIFT);
                     let mut result = [0; M];
                     let mut i = 0;
                     while i <= (END - START - 1) >> V_SHIFT {
@@ -296,9 +414,7 @@ macro_rules! impl_bits_for_int_type {
             impl<const M: usize> $($const)* SetBits<[$value; M]> for [$storage] {
                 #[inline]
                 fn set_bits<const START: usize, const END: usize>(&mut self, value: [$value; M]) {
-                    if START >= END {
-                        return;
-                    }
+                    check_bit_range!(START, END, M << V_SHIFT);
                     let mut i = 0;
                     while i <= (END - START - 1) >> V_SHIFT {
                         let start = START + (i << V_SHIFT);
@@ -330,6 +446,32 @@ macro_rules! impl_bits_for_int_type {
                     }
                 }
             }
+
+            impl<const M: usize> $($const)* TryBits<[$value; M]> for [$storage] {
+                #[inline]
+                fn try_bits<const START: usize, const END: usize>(
+                    &self,
+                ) -> Result<[$value; M], BitRangeError> {
+                    if (END - 1) >> S_SHIFT >= self.len() {
+                        return Err(BitRangeError::new(START, END, slice_bits(self)));
+                    }
+                    Ok(self.bits::<START, END>())
+                }
+            }
+
+            impl<const M: usize> $($const)* TrySetBits<[$value; M]> for [$storage] {
+                #[inline]
+                fn try_set_bits<const START: usize, const END: usize>(
+                    &mut self,
+                    value: [$value; M],
+                ) -> Result<(), BitRangeError> {
+                    if (END - 1) >> S_SHIFT >= self.len() {
+                        return Err(BitRangeError::new(START, END, slice_bits(self)));
+                    }
+                    self.set_bits::<START, END>(value);
+                    Ok(())
+                }
+            }
         }
     };
 }
@@ -398,6 +540,7 @@ macro_rules! impl_bit_for_arr_int_type {
             impl<const N: usize> $($const)* Bit for [$t; N] {
                 #[inline]
                 fn bit<const BIT: usize>(&self) -> bool {
+                    check_bit_index!(BIT, N << SHIFT);
                     self[BIT >> SHIFT] & 1 << (BIT & MASK) != 0
                 }
             }
@@ -413,8 +556,9 @@ macro_rules! impl_bit_for_arr_int_type {
             impl<const N: usize> $($const)* SetBit for [$t; N] {
                 #[inline]
                 fn set_bit<const BIT: usize>(&mut self, value: bool) {
+                    check_bit_index!(BIT, N << SHIFT);
                     self[BIT >> SHIFT] =
-                        (self[BIT >> SHIFT] & !(1 << (BIT & MASK))) | (value as $t) << BIT;
+                        (self[BIT >> SHIFT] & !(1 << (BIT & MASK))) | (value as $t) << (BIT & MASK);
                 }
             }
 
@@ -429,7 +573,7 @@ macro_rules! impl_bit_for_arr_int_type {
                 #[inline]
                 fn set_bit<const BIT: usize>(&mut self, value: bool) {
                     self[BIT >> SHIFT] =
-                        (self[BIT >> SHIFT] & !(1 << (BIT & MASK))) | (value as $t) << BIT;
+                        (self[BIT >> SHIFT] & !(1 << (BIT & MASK))) | (value as $t) << (BIT & MASK);
                 }
             }
         }
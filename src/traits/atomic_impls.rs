@@ -0,0 +1,122 @@
+use super::{AtomicSetBit, AtomicSetBits, Bit, Bits, SetBit, SetBits, WithBit, WithBits};
+use crate::__private::failure_ordering;
+use core::sync::atomic::Ordering;
+
+macro_rules! impl_bits_for_atomic_type {
+    ($atomic: ident, $storage: ty, $value: ty) => {
+        impl Bits<$value> for $atomic {
+            #[inline]
+            fn bits<const START: usize, const END: usize>(&self) -> $value {
+                <$storage as Bits<$value>>::bits::<START, END>(&self.load(Ordering::SeqCst))
+            }
+        }
+
+        impl WithBits<$value> for $atomic {
+            #[inline]
+            fn with_bits<const START: usize, const END: usize>(self, value: $value) -> Self {
+                $atomic::new(<$storage as WithBits<$value>>::with_bits::<START, END>(
+                    self.into_inner(),
+                    value,
+                ))
+            }
+        }
+
+        impl SetBits<$value> for $atomic {
+            #[inline]
+            fn set_bits<const START: usize, const END: usize>(&mut self, value: $value) {
+                let storage = self.get_mut();
+                *storage = <$storage as WithBits<$value>>::with_bits::<START, END>(*storage, value);
+            }
+        }
+
+        impl AtomicSetBits<$value> for $atomic {
+            #[inline]
+            fn atomic_set_bits<const START: usize, const END: usize>(
+                &self,
+                value: $value,
+                order: Ordering,
+            ) {
+                let _ = self.fetch_update(order, failure_ordering(order), |storage| {
+                    Some(<$storage as WithBits<$value>>::with_bits::<START, END>(
+                        storage, value,
+                    ))
+                });
+            }
+        }
+    };
+}
+
+macro_rules! impl_bits_for_atomic_types {
+    ($(($atomic: ident, $storage: ident, $bits: literal)),*) => {
+        $(
+            #[cfg(target_has_atomic = $bits)]
+            mod $storage {
+                use super::*;
+                use core::sync::atomic::$atomic;
+
+                impl_bits_for_atomic_type!($atomic, $storage, u8);
+                impl_bits_for_atomic_type!($atomic, $storage, u16);
+                impl_bits_for_atomic_type!($atomic, $storage, u32);
+                impl_bits_for_atomic_type!($atomic, $storage, u64);
+                impl_bits_for_atomic_type!($atomic, $storage, u128);
+                impl_bits_for_atomic_type!($atomic, $storage, usize);
+                impl_bits_for_atomic_type!($atomic, $storage, i8);
+                impl_bits_for_atomic_type!($atomic, $storage, i16);
+                impl_bits_for_atomic_type!($atomic, $storage, i32);
+                impl_bits_for_atomic_type!($atomic, $storage, i64);
+                impl_bits_for_atomic_type!($atomic, $storage, i128);
+                impl_bits_for_atomic_type!($atomic, $storage, isize);
+
+                impl Bit for $atomic {
+                    #[inline]
+                    fn bit<const BIT: usize>(&self) -> bool {
+                        <$storage as Bit>::bit::<BIT>(&self.load(Ordering::SeqCst))
+                    }
+                }
+
+                impl WithBit for $atomic {
+                    #[inline]
+                    fn with_bit<const BIT: usize>(self, value: bool) -> Self {
+                        $atomic::new(<$storage as WithBit>::with_bit::<BIT>(
+                            self.into_inner(),
+                            value,
+                        ))
+                    }
+                }
+
+                impl SetBit for $atomic {
+                    #[inline]
+                    fn set_bit<const BIT: usize>(&mut self, value: bool) {
+                        let storage = self.get_mut();
+                        *storage = <$storage as WithBit>::with_bit::<BIT>(*storage, value);
+                    }
+                }
+
+                impl AtomicSetBit for $atomic {
+                    #[inline]
+                    fn atomic_set_bit<const BIT: usize>(&self, value: bool, order: Ordering) {
+                        let mask = <$storage as WithBit>::with_bit::<BIT>(0, true);
+                        if value {
+                            self.fetch_or(mask, order);
+                        } else {
+                            self.fetch_and(!mask, order);
+                        }
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_bits_for_atomic_types!(
+    (AtomicU8, u8, "8"),
+    (AtomicU16, u16, "16"),
+    (AtomicU32, u32, "32"),
+    (AtomicU64, u64, "64"),
+    (AtomicUsize, usize, "ptr"),
+    (AtomicI8, i8, "8"),
+    (AtomicI16, i16, "16"),
+    (AtomicI32, i32, "32"),
+    (AtomicI64, i64, "64"),
+    (AtomicIsize, isize, "ptr")
+);
@@ -1,14 +1,14 @@
-use super::{Bit, Bits, SetBit, SetBits, WithBit, WithBits};
+use super::{Bit, Bits, DynBits, DynSetBits, DynWithBits, SetBit, SetBits, WithBit, WithBits};
+use crate::BitRangeError;
+use core::ops::Range;
 
 macro_rules! impl_bits_for_int_type {
     ($storage: ty, $value: ty $(, $const:tt)?) => {
         impl $($const)* Bits<$value> for $storage {
             #[inline]
             fn bits<const START: usize, const END: usize>(&self) -> $value {
-                if START >= END {
-                    return 0;
-                }
                 const VALUE_BITS: usize = <$value>::BITS as usize;
+                check_bit_range!(START, END, VALUE_BITS, <$storage>::BITS as usize);
                 let read_bits = END - START;
                 ((*self >> START) as $value) << (VALUE_BITS - read_bits) >> (VALUE_BITS - read_bits)
             }
@@ -17,9 +17,7 @@ macro_rules! impl_bits_for_int_type {
         impl $($const)* WithBits<$value> for $storage {
             #[inline]
             fn with_bits<const START: usize, const END: usize>(self, value: $value) -> Self {
-                if START >= END {
-                    return self;
-                }
+                check_bit_range!(write START, END, <$storage>::BITS as usize);
                 let written_bits = END - START;
                 let mask = ((1 as $storage) << (written_bits - 1) << 1).wrapping_sub(1) << START;
                 (self & !mask) | ((value as $storage) << START & mask)
@@ -29,12 +27,59 @@ macro_rules! impl_bits_for_int_type {
         impl $($const)* SetBits<$value> for $storage {
             #[inline]
             fn set_bits<const START: usize, const END: usize>(&mut self, value: $value) {
-                if START >= END {
-                    return;
-                }
                 *self = self.with_bits::<START, END>(value);
             }
         }
+
+        impl $($const)* DynBits<$value> for $storage {
+            #[inline]
+            fn dyn_bits(&self, range: Range<usize>) -> Result<$value, BitRangeError> {
+                const STORAGE_BITS: usize = <$storage>::BITS as usize;
+                const VALUE_BITS: usize = <$value>::BITS as usize;
+                let Range { start, end } = range;
+                if start >= end || end > STORAGE_BITS || end - start > VALUE_BITS {
+                    return Err(BitRangeError::new(start, end, STORAGE_BITS));
+                }
+                let read_bits = end - start;
+                Ok(((*self >> start) as $value) << (VALUE_BITS - read_bits)
+                    >> (VALUE_BITS - read_bits))
+            }
+        }
+
+        impl $($const)* DynWithBits<$value> for $storage {
+            #[inline]
+            fn dyn_with_bits(
+                self,
+                range: Range<usize>,
+                value: $value,
+            ) -> Result<Self, BitRangeError> {
+                const STORAGE_BITS: usize = <$storage>::BITS as usize;
+                let Range { start, end } = range;
+                if start >= end || end > STORAGE_BITS {
+                    return Err(BitRangeError::new(start, end, STORAGE_BITS));
+                }
+                let written_bits = end - start;
+                let mask = ((1 as $storage) << (written_bits - 1) << 1).wrapping_sub(1) << start;
+                Ok((self & !mask) | ((value as $storage) << start & mask))
+            }
+        }
+
+        impl $($const)* DynSetBits<$value> for $storage {
+            #[inline]
+            fn dyn_set_bits(
+                &mut self,
+                range: Range<usize>,
+                value: $value,
+            ) -> Result<(), BitRangeError> {
+                match self.dyn_with_bits(range, value) {
+                    Ok(new_value) => {
+                        *self = new_value;
+                        Ok(())
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+        }
     };
 }
 
@@ -71,6 +116,7 @@ macro_rules! impl_bit_for_int_type {
         impl $($const)* Bit for $t {
             #[inline]
             fn bit<const BIT: usize>(&self) -> bool {
+                check_bit_index!(BIT, <$t>::BITS as usize);
                 *self & 1 << BIT != 0
             }
         }
@@ -78,6 +124,7 @@ macro_rules! impl_bit_for_int_type {
         impl $($const)* WithBit for $t {
             #[inline]
             fn with_bit<const BIT: usize>(self, value: bool) -> Self {
+                check_bit_index!(BIT, <$t>::BITS as usize);
                 (self & !(1 << BIT)) | (value as $t) << BIT
             }
         }
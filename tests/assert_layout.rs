@@ -0,0 +1,75 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::{assert_layout, bitfield};
+
+bitfield! {
+    pub struct Inner(pub u8) {
+        pub value: u8 @ 0..8,
+    }
+}
+
+bitfield! {
+    pub struct Ctrl(pub u32): Msb0 {
+        pub raw: u32 [alias] @ ..,
+        pub mode: u8 @ 0..4,
+        pub enable: bool @ 4,
+        pub busy: bool [ro] @ above,
+        pub irq: bool [w1c] @ 6,
+        pub data: u8 [write_only] @ 8..=15,
+        pub prio: u8 [4] @ 16; 2,
+        pub inner: nested Inner [read_only] @ 24..32,
+    }
+}
+
+bitfield! {
+    pub struct Command(pub u16) {
+        read {
+            pub busy: bool @ 0,
+            pub level: u8 @ 8..16,
+        }
+        write {
+            pub start: bool @ 0,
+            pub reset: bool @ 15,
+            pub lanes: u8 [2] @ below; 4,
+        }
+    }
+}
+
+bitfield! {
+    pub struct Generic<T>(pub u16) {
+        pub value: u8 @ 0..8,
+    }
+}
+
+assert_layout!(Ctrl {
+    raw @ ..,
+    mode @ 0..4,
+    enable @ 4,
+    busy [read_only] @ above,
+    irq @ 6,
+    data [wo] @ 8..16,
+    prio [4] @ 16; 2,
+    nested inner [ro] @ 24..32,
+});
+
+assert_layout!(Command {
+    busy [ro] @ 0,
+    level [ro] @ 8..16,
+});
+
+assert_layout!(CommandWrite {
+    start [wo] @ 0,
+    reset [wo] @ 15,
+    lanes [2] [wo] @ below; 4,
+});
+
+assert_layout!(Generic<u8> { value @ 0..(u8::BITS as usize) });
+
+#[test]
+fn layouts() {
+    assert_eq!(CommandWrite(0).with_lanes(0, 0xF).0, 0x0780);
+}
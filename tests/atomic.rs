@@ -0,0 +1,198 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use core::sync::atomic::{AtomicU32, AtomicU8, Ordering};
+use proc_bitfield::{
+    bitfield, bits, set_bits, with_bits, AtomicSetBit, AtomicSetBits, Bits, SetBits,
+};
+use std::sync::Arc;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Off,
+    Idle,
+    Run,
+    Halt,
+}
+
+impl From<u8> for Mode {
+    fn from(value: u8) -> Self {
+        [Mode::Off, Mode::Idle, Mode::Run, Mode::Halt][value as usize & 3]
+    }
+}
+
+impl From<Mode> for u8 {
+    fn from(value: Mode) -> Self {
+        value as u8
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Status(pub u32): Atomic, Cell {
+        pub ready: bool @ 0,
+        pub mode: u8 [get Mode, set Mode] @ 1..3,
+        pub count: u16 @ 8..24,
+        pub error: bool [w1c] @ 24,
+        pub lanes: u8 [2] @ 28; 2,
+        pub id: u8 [read_only] @ 26..28,
+    }
+}
+
+#[test]
+fn atomic_whole_value() {
+    let status = AtomicStatus::new(Status(0x0000_0005));
+    assert_eq!(status.load(Ordering::Relaxed), Status(5));
+    status.store(Status(0x100), Ordering::Relaxed);
+    assert_eq!(status.swap(Status(0x200), Ordering::Relaxed), Status(0x100));
+    assert_eq!(
+        status.compare_exchange(
+            Status(0x100),
+            Status(0),
+            Ordering::Relaxed,
+            Ordering::Relaxed
+        ),
+        Err(Status(0x200))
+    );
+    assert_eq!(
+        status.compare_exchange(
+            Status(0x200),
+            Status(1),
+            Ordering::Relaxed,
+            Ordering::Relaxed
+        ),
+        Ok(Status(0x200))
+    );
+    let previous = status.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |status| {
+        status.with_count(status.count() + 1)
+    });
+    assert_eq!(previous, Status(1));
+    assert_eq!(status.as_atomic().load(Ordering::Relaxed), 0x101);
+    assert_eq!(status.into_inner(), Status(0x101));
+}
+
+#[test]
+fn atomic_fields() {
+    let status = AtomicStatus::new(Status(0xC000_0000));
+    assert_eq!(
+        status.fetch_set_mode(Mode::Run, Ordering::Relaxed),
+        Status(0xC000_0000)
+    );
+    assert_eq!(status.swap_count(0x1234, Ordering::Relaxed), 0);
+    assert!(!status.swap_ready(true, Ordering::Relaxed));
+    assert_eq!(status.load(Ordering::Relaxed), Status(0xC012_3405));
+
+    assert_eq!(
+        status.compare_exchange_mode(Mode::Idle, Mode::Halt, Ordering::Relaxed, Ordering::Relaxed),
+        Err(Mode::Run)
+    );
+    assert_eq!(
+        status.compare_exchange_mode(Mode::Run, Mode::Halt, Ordering::Relaxed, Ordering::Relaxed),
+        Ok(Mode::Run)
+    );
+    // Only the field's bits are changed
+    assert_eq!(status.load(Ordering::Relaxed), Status(0xC012_3407));
+}
+
+#[test]
+fn atomic_fields_concurrent() {
+    let status = Arc::new(AtomicStatus::new(Status(0)));
+    let threads = (0..4)
+        .map(|i| {
+            let status = Arc::clone(&status);
+            std::thread::spawn(move || {
+                for _ in 0..1000 {
+                    if i == 0 {
+                        status.fetch_update(Ordering::AcqRel, Ordering::Acquire, |status| {
+                            status.with_count(status.count() + 1)
+                        });
+                    } else {
+                        let ready = status.load(Ordering::Acquire).ready();
+                        status.fetch_set_ready(!ready, Ordering::AcqRel);
+                        status.fetch_set_mode(Mode::from(i), Ordering::AcqRel);
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    // Updates to other fields never overwrite the count
+    assert_eq!(status.load(Ordering::Relaxed).count(), 1000);
+}
+
+#[test]
+fn cell() {
+    let status = StatusCell::new(Status(0x0100_0000));
+    let status_ref = &status;
+    status_ref.set_ready(true);
+    status_ref.set_mode(Mode::Halt);
+    status_ref.set_lanes(1, 2);
+    status_ref.clear_error();
+    assert_eq!(status.get(), Status(0x8100_0007));
+    // As for the bitfield's own setters, write-1-to-clear bits are only written by `clear_x`
+    status.update(|status| status.with_count(0xABCD));
+    assert_eq!(status.replace(Status(0)), Status(0x80AB_CD07));
+    status.set(Status(1));
+    assert_eq!(status.into_inner(), Status(1));
+}
+
+#[test]
+fn atomic_storage_bits() {
+    let mut storage = AtomicU32::new(0x1234);
+    assert_eq!(bits!(storage, u8 @ 4..12), 0x23);
+    assert!(bits!(storage, 2));
+    set_bits!(storage, u8 @ 12..16 = 0xA);
+    set_bits!(storage, 0 = true);
+    assert_eq!(storage.load(Ordering::Relaxed), 0xA235);
+    let storage = with_bits!(storage, u16 @ 16..32 = 0xFFFF);
+    assert_eq!(storage.into_inner(), 0xFFFF_A235);
+
+    let mut storage = AtomicU8::new(0xFF);
+    SetBits::<i8>::set_bits::<0, 4>(&mut storage, 0);
+    assert_eq!(Bits::<i8>::bits::<4, 8>(&storage), -1);
+}
+
+#[test]
+fn atomic_storage_shared_writes() {
+    let storage = AtomicU32::new(0x1234);
+    storage.atomic_set_bits::<12, 16>(0xA_u8, Ordering::Relaxed);
+    storage.atomic_set_bit::<0>(true, Ordering::Relaxed);
+    storage.atomic_set_bit::<2>(false, Ordering::Relaxed);
+    assert_eq!(storage.load(Ordering::Relaxed), 0xA231);
+
+    let storage = Arc::new(AtomicU32::new(0));
+    let flags = Arc::new(AtomicU8::new(0));
+    let threads = (0..4)
+        .map(|i| {
+            let storage = Arc::clone(&storage);
+            let flags = Arc::clone(&flags);
+            std::thread::spawn(move || {
+                for n in 0..1000_u32 {
+                    match i {
+                        0 => storage.atomic_set_bits::<0, 8>(n as u8, Ordering::AcqRel),
+                        1 => storage.atomic_set_bits::<8, 16>(!n as u8, Ordering::AcqRel),
+                        2 => storage.atomic_set_bits::<16, 24>(n as i8 ^ 0x55, Ordering::AcqRel),
+                        _ => storage.atomic_set_bits::<24, 32>(n as u16, Ordering::AcqRel),
+                    }
+                    match i {
+                        0 => flags.atomic_set_bit::<0>(n % 2 == 1, Ordering::AcqRel),
+                        1 => flags.atomic_set_bit::<3>(n % 2 == 1, Ordering::AcqRel),
+                        2 => flags.atomic_set_bit::<5>(n % 2 == 0, Ordering::AcqRel),
+                        _ => flags.atomic_set_bit::<7>(n % 2 == 1, Ordering::AcqRel),
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+    // Each thread's last write to its own bits is kept
+    assert_eq!(storage.load(Ordering::Relaxed), 0xE7B2_18E7);
+    assert_eq!(flags.load(Ordering::Relaxed), 0x89);
+}
@@ -0,0 +1,124 @@
+use proc_bitfield::{BigEndian, BitPackedArray, BitPackedSlice};
+
+#[test]
+fn array_get_set() {
+    let mut array = BitPackedArray::<u8, 3, 10, [u8; 4]>::from_storage([0; 4]);
+    assert_eq!(array.len(), 10);
+    array.set(0, 5);
+    array.set(2, 7);
+    // Crosses the boundary between the first two bytes
+    array.set(5, 0b110);
+    // Truncated to 3 bits
+    array.set(9, 0xFA);
+    assert_eq!(array.into_storage(), [0xC5, 0x01, 0x03, 0x10]);
+    assert_eq!(array.get(0), 5);
+    assert_eq!(array.get(2), 7);
+    assert_eq!(array.get(5), 6);
+    assert_eq!(array.get(9), 2);
+    assert_eq!(array.get_checked(9), Some(2));
+    assert_eq!(array.get_checked(10), None);
+
+    // Signed elements are sign-extended
+    let array = BitPackedArray::<i8, 3, 10, [u8; 4]>::from_storage(array.into_storage());
+    assert_eq!(array.get(0), -3);
+    assert_eq!(array.get(9), 2);
+}
+
+#[test]
+#[should_panic = "index out of bounds: the len is 10 but the index is 10"]
+fn array_out_of_bounds() {
+    BitPackedArray::<u8, 3, 10, [u8; 4]>::default().get(10);
+}
+
+#[test]
+fn array_iter() {
+    let values = (0..40_u32).map(|i| i * 0x1357 % 0x800);
+    let array: BitPackedArray<u32, 11, 40, [u16; 28]> = values.clone().collect();
+    assert!(array.iter().eq(values.clone()));
+    assert!((0..40).map(|i| array.get(i)).eq(values));
+    assert_eq!(array.iter().skip(37).len(), 3);
+
+    let mut array = BitPackedArray::<u8, 4, 5, [u8; 3]>::from_storage([0xFF; 3]);
+    array.copy_from_iter([1, 2, 3]);
+    assert_eq!(array.into_storage(), [0x21, 0xF3, 0xFF]);
+    assert_eq!(format!("{array:?}"), "[1, 2, 3, 15, 15]");
+
+    let wide: BitPackedArray<i128, 100, 3, [u64; 5]> = [-1, 2, -3].into_iter().collect();
+    assert!(wide.iter().eq([-1, 2, -3]));
+}
+
+#[test]
+fn copy_from_too_long_iter() {
+    // Extra elements are only detected after the container has been filled
+    let mut array = BitPackedArray::<u8, 4, 3, [u8; 2]>::from_storage([0xFF; 2]);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        array.copy_from_iter([1, 2, 3, 4]);
+    }));
+    assert_eq!(
+        *result.unwrap_err().downcast::<String>().unwrap(),
+        "too many elements for a bit-packed container of length 3"
+    );
+    assert_eq!(array.into_storage(), [0x21, 0xF3]);
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Level {
+    Low,
+    High,
+}
+
+impl TryFrom<u8> for Level {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Level::Low),
+            1 => Ok(Level::High),
+            _ => Err(value),
+        }
+    }
+}
+
+impl From<Level> for u8 {
+    fn from(level: Level) -> u8 {
+        level as u8
+    }
+}
+
+#[test]
+fn conversions() {
+    let mut array = BitPackedArray::<u8, 2, 4, u8>::from_storage(0);
+    array.set_from(0, Level::High);
+    array.set(1, 3);
+    assert_eq!(array.try_get_as::<Level>(0), Ok(Level::High));
+    assert_eq!(array.try_get_as::<Level>(1), Err(3));
+    assert_eq!(array.get_as::<u32>(1), 3);
+    assert_eq!(array.try_set_from(2, 1_u32), Ok(()));
+    assert!(array.try_set_from(3, 0x100_u32).is_err());
+    assert_eq!(array.into_storage(), 0x1D);
+}
+
+#[test]
+fn slices() {
+    let mut words = [0_u16; 3];
+    let slice = BitPackedSlice::<u8, 5, u16>::from_mut_slice(&mut words);
+    assert_eq!(slice.len(), 9);
+    for i in 0..slice.len() {
+        slice.set(i, i as u8 * 3);
+    }
+    assert!(slice.iter().eq((0..9).map(|i| i * 3)));
+    slice.copy_from_iter([31, 30]);
+    assert_eq!(words, [0x9BDF, 0x9EC4, 0x18AC]);
+
+    let slice = BitPackedSlice::<i16, 10, u16>::from_slice(&words[1..]);
+    assert_eq!(slice.len(), 3);
+    assert_eq!(slice.get(1), -217);
+}
+
+#[test]
+fn endian_storage() {
+    let mut array = BitPackedArray::<u16, 12, 2, BigEndian<[u8; 3]>>::default();
+    array.set(0, 0xABC);
+    array.set(1, 0x123);
+    assert_eq!(array.storage().0, [0x12, 0x3A, 0xBC]);
+}
@@ -0,0 +1,93 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::bitfield;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Prio(u8);
+
+impl Prio {
+    const fn from_raw(raw: u8) -> Self {
+        Prio(raw)
+    }
+
+    const fn into_raw(self) -> u8 {
+        self.0
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Ctrl(pub u32): const, New {
+        pub enable: bool @ 0,
+        pub irq: bool [w1c] @ 1,
+        pub offset: i8 @ 4..8,
+        pub prio: u8 [get_fn Prio::from_raw -> Prio, set_fn Prio::into_raw(Prio)] @ 8..12,
+        pub lanes: u8 [2] @ 16; 4,
+        pub status: u8 [read_only] @ 24..30,
+        _: fixed = 0b10 @ 30..32,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Signed(pub i16) {
+        pub low: u8 [const] @ 0..4,
+        pub high: i8 [const] @ 8..16,
+        pub prio: u8 [get Prio] @ 4..8,
+    }
+}
+
+impl From<u8> for Prio {
+    fn from(value: u8) -> Self {
+        Prio(value)
+    }
+}
+
+const CTRL: Ctrl = Ctrl::new(true, -3, Prio(5), [0xA, 0x3])
+    .with_clear_irq()
+    .with_lanes(1, 0xC);
+const OFFSET: i8 = CTRL.offset();
+const TABLE: [Ctrl; 4] = {
+    let mut table = [Ctrl(0); 4];
+    let mut i = 0;
+    while i < table.len() {
+        table[i].set_prio(Prio(i as u8));
+        table[i].set_enable(i & 1 != 0);
+        i += 1;
+    }
+    table
+};
+
+#[test]
+fn const_contexts() {
+    assert_eq!(CTRL.0, 0x80CA_05D1);
+    assert_eq!(OFFSET, -3);
+    assert_eq!(CTRL.prio(), Prio(5));
+    assert_eq!(CTRL.lanes_at::<1>(), 0xC);
+    assert_eq!(
+        TABLE.map(|ctrl| ctrl.0),
+        [0x8000_0000, 0x8000_0101, 0x8000_0200, 0x8000_0301]
+    );
+
+    const SIGNED: Signed = Signed(0).with_high(-2).with_low(5);
+    assert_eq!(SIGNED.0, -0x01FB);
+    const HIGH: i8 = SIGNED.high();
+    assert_eq!(HIGH, -2);
+    assert_eq!(SIGNED.with_prio(3).prio(), Prio(3));
+}
+
+#[test]
+fn runtime_values() {
+    let mut ctrl = Ctrl(0xFFFF_FFFF);
+    assert_eq!(ctrl.status(), 0x3F);
+    assert_eq!(ctrl.offset(), -1);
+    ctrl.set_offset(7);
+    assert_eq!(ctrl.offset(), 7);
+    // Fixed bits are forced to their values and write-1-to-clear bits aren't written back
+    assert_eq!(ctrl.0, 0xBFFF_FF7D);
+    assert_eq!(ctrl.with_enable(false).0, 0xBFFF_FF7C);
+}
@@ -0,0 +1,124 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::{bitfield, Bits, FieldConversionError, SetBits, WithBits};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Off,
+    On,
+    Auto,
+    Invalid,
+}
+
+impl From<u8> for Mode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Mode::Off,
+            1 => Mode::On,
+            2 => Mode::Auto,
+            _ => Mode::Invalid,
+        }
+    }
+}
+
+impl TryFrom<Mode> for u8 {
+    type Error = ();
+
+    fn try_from(value: Mode) -> Result<Self, ()> {
+        match value {
+            Mode::Invalid => Err(()),
+            _ => Ok(value as u8),
+        }
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Sub(pub u8): New {
+        pub low: u8 @ 0..4,
+        pub high: u8 @ 4..8,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Ctrl(pub u32): New, Builder {
+        pub enable: bool @ 0,
+        pub status: u8 [read_only] @ 4..8,
+        pub irq: bool [w1c] @ 2,
+        pub prio: u8 [2] @ 8; 2,
+        pub sub: nested Sub @ 16..24,
+        _: fixed = 0b11 @ 30..32,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Config(pub u16): New, Builder {
+        pub mode: u8 [get Mode, try_set Mode] @ 0..2,
+        pub level: u8 @ 4..8,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Command(pub u8): New, Builder {
+        read {
+            pub busy: bool @ 0,
+        }
+        write {
+            pub start: bool @ 0,
+            pub opcode: u8 @ 4..8,
+        }
+    }
+}
+
+bitfield! {
+    pub struct Generic<S>(pub S): New, Builder
+    where
+        S: Bits<u8> + WithBits<u8> + SetBits<u8>
+    {
+        pub low: u8 @ 0..4,
+    }
+}
+
+#[test]
+fn new() {
+    // Read-only fields and fields with side effects aren't taken
+    let ctrl = Ctrl::new(true, [3, 1], Sub::new(5, 0xA));
+    assert_eq!(ctrl.0, 0xC0A5_0701);
+
+    assert_eq!(Config::new(Mode::Auto, 7), Ok(Config(0x72)));
+    assert_eq!(
+        Config::new(Mode::Invalid, 7),
+        Err(FieldConversionError::new("mode"))
+    );
+
+    assert_eq!(CommandWrite::new(true, 3).0, 0x31);
+    assert_eq!(Generic::<u16>::new(0xF).0, 0xF);
+}
+
+#[test]
+fn builder() {
+    let ctrl = Ctrl::builder()
+        .sub(Sub::new(5, 0xA))
+        .enable(true)
+        .prio([3, 1])
+        .build();
+    assert_eq!(ctrl, Ctrl::new(true, [3, 1], Sub::new(5, 0xA)));
+
+    let config = Config::builder().level(7).mode(Mode::Auto).build();
+    assert_eq!(config, Ok(Config(0x72)));
+    let config = Config::builder().mode(Mode::Invalid).level(7).build();
+    assert_eq!(config, Err(FieldConversionError::new("mode")));
+
+    assert_eq!(
+        CommandWrite::builder().opcode(3).start(true).build().0,
+        0x31
+    );
+    assert_eq!(Generic::<u8>::builder().low(3).build().0, 3);
+}
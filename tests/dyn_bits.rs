@@ -0,0 +1,180 @@
+use proc_bitfield::{
+    bits, set_bits, with_bits, BigEndian, BitRangeError, DynBits, DynSetBits, DynWithBits,
+    LittleEndian, SetBits, WithBits,
+};
+
+#[test]
+fn dyn_ints_rw_ints() {
+    let value = 0xF234_u16;
+    assert_eq!(DynBits::<u8>::dyn_bits(&value, 4..8), Ok(3));
+    assert_eq!(DynBits::<i8>::dyn_bits(&value, 12..16), Ok(-1));
+    assert_eq!(DynBits::<u16>::dyn_bits(&value, 0..16), Ok(0xF234));
+    assert_eq!(
+        DynWithBits::<u8>::dyn_with_bits(value, 4..12, 0xAB),
+        Ok(0xFAB4)
+    );
+    assert_eq!(
+        DynWithBits::<i8>::dyn_with_bits(value, 0..4, -1),
+        Ok(0xF23F)
+    );
+
+    let mut value = value;
+    assert_eq!(
+        DynSetBits::<u32>::dyn_set_bits(&mut value, 8..16, 0x12),
+        Ok(())
+    );
+    assert_eq!(value, 0x1234);
+}
+
+#[test]
+fn dyn_int_arrays_rw_ints() {
+    let storage = [0x21_u8, 0x43, 0x65];
+    assert_eq!(DynBits::<u8>::dyn_bits(&storage, 4..12), Ok(0x32));
+    assert_eq!(DynBits::<u16>::dyn_bits(&storage, 8..24), Ok(0x6543));
+    assert_eq!(DynBits::<i8>::dyn_bits(&storage, 20..24), Ok(6));
+    assert_eq!(DynBits::<i8>::dyn_bits(&[0xF0_u8, 0], 4..8), Ok(-1));
+    assert_eq!(
+        DynWithBits::<u16>::dyn_with_bits(storage, 4..20, 0xABCD),
+        Ok([0xD1, 0xBC, 0x6A])
+    );
+
+    let mut storage = [0_u16; 2];
+    let slice = &mut storage[..];
+    assert_eq!(
+        DynSetBits::<u32>::dyn_set_bits(slice, 8..32, 0xAB_CDEF),
+        Ok(())
+    );
+    assert_eq!(DynBits::<u32>::dyn_bits(slice, 8..32), Ok(0xAB_CDEF));
+    assert_eq!(storage, [0xEF00, 0xABCD]);
+}
+
+#[test]
+fn dyn_endian_arrays_rw_ints() {
+    let be = BigEndian([0x12_u8, 0x34, 0x56]);
+    assert_eq!(DynBits::<u32>::dyn_bits(&be, 0..24), Ok(0x12_3456));
+    assert_eq!(DynBits::<u8>::dyn_bits(&be, 4..12), Ok(0x45));
+    assert_eq!(
+        DynWithBits::<u8>::dyn_with_bits(be, 16..24, 0xAB).map(|be| be.0),
+        Ok([0xAB, 0x34, 0x56])
+    );
+
+    let mut le = LittleEndian([0x12_u8, 0x34, 0x56]);
+    assert_eq!(DynBits::<u32>::dyn_bits(&le, 0..24), Ok(0x56_3412));
+    assert_eq!(
+        DynSetBits::<u16>::dyn_set_bits(&mut le, 4..20, 0xABCD),
+        Ok(())
+    );
+    assert_eq!(le.0, [0xD2, 0xBC, 0x5A]);
+}
+
+#[test]
+fn dyn_invalid_ranges() {
+    let mut value = 0x1234_u16;
+    // Out of bounds
+    assert_eq!(
+        DynBits::<u8>::dyn_bits(&value, 12..20),
+        Err(BitRangeError::new(12, 20, 16))
+    );
+    // Empty
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 8..4;
+    assert!(DynBits::<u8>::dyn_bits(&value, 4..4).is_err());
+    assert!(DynBits::<u8>::dyn_bits(&value, reversed).is_err());
+    // Wider than the value type, which is only an error for reads
+    assert!(DynBits::<u8>::dyn_bits(&value, 0..12).is_err());
+
+    // Failed writes leave the storage untouched
+    assert!(DynSetBits::<u8>::dyn_set_bits(&mut value, 12..20, 0xFF).is_err());
+    assert_eq!(value, 0x1234);
+    let mut storage = [0x21_u8, 0x43];
+    assert_eq!(
+        DynSetBits::<u8>::dyn_set_bits(&mut storage[..1], 4..12, 0xFF),
+        Err(BitRangeError::new(4, 12, 8))
+    );
+    assert_eq!(storage, [0x21, 0x43]);
+    assert!(DynSetBits::<u8>::dyn_set_bits(&mut BigEndian([0_u8; 2]), 0..17, 0).is_err());
+}
+
+#[test]
+fn dyn_wide_writes_extend() {
+    // Ranges wider than the value type are written like through `WithBits` and `SetBits`
+    let value = 0x1234_u16;
+    assert_eq!(
+        DynWithBits::<u8>::dyn_with_bits(value, 0..12, 0xAB),
+        Ok(WithBits::<u8>::with_bits::<0, 12>(value, 0xAB))
+    );
+    assert_eq!(
+        DynWithBits::<i8>::dyn_with_bits(value, 2..14, -2),
+        Ok(WithBits::<i8>::with_bits::<2, 14>(value, -2))
+    );
+    assert_eq!(
+        DynWithBits::<i8>::dyn_with_bits(value, 2..14, -2),
+        Ok(0x3FF8)
+    );
+
+    let storage = [0x21_u8, 0x43, 0x65];
+    assert_eq!(
+        DynWithBits::<i8>::dyn_with_bits(storage, 4..20, -3),
+        Ok(WithBits::<i8>::with_bits::<4, 20>(storage, -3))
+    );
+    assert_eq!(
+        DynWithBits::<u8>::dyn_with_bits(storage, 4..20, 0xCD),
+        Ok(WithBits::<u8>::with_bits::<4, 20>(storage, 0xCD))
+    );
+    let mut dyn_storage = storage;
+    let mut const_storage = storage;
+    assert_eq!(
+        DynSetBits::<i8>::dyn_set_bits(&mut dyn_storage[..], 2..22, -4),
+        Ok(())
+    );
+    SetBits::<i8>::set_bits::<2, 22>(&mut const_storage[..], -4);
+    assert_eq!(dyn_storage, const_storage);
+    assert_eq!(dyn_storage, [0xF1, 0xFF, 0x7F]);
+
+    let be = BigEndian([0x12_u8, 0x34, 0x56]);
+    assert_eq!(
+        DynWithBits::<i8>::dyn_with_bits(be, 4..20, -3).map(|be| be.0),
+        Ok(WithBits::<i8>::with_bits::<4, 20>(be, -3).0)
+    );
+    let mut dyn_le = LittleEndian([0x12_u8, 0x34, 0x56]);
+    let mut const_le = dyn_le;
+    assert_eq!(
+        DynSetBits::<u8>::dyn_set_bits(&mut dyn_le, 4..20, 0xAB),
+        Ok(())
+    );
+    SetBits::<u8>::set_bits::<4, 20>(&mut const_le, 0xAB);
+    assert_eq!(dyn_le.0, const_le.0);
+    assert_eq!(dyn_le.0, [0xB2, 0x0A, 0x50]);
+}
+
+#[test]
+fn dyn_macros() {
+    let value = 0x1234_u16;
+    let (start, end) = (4, 8);
+    assert_eq!(bits!(value, u8 @ dyn start..end), Ok(3));
+    assert_eq!(bits!(value, u8 @ dyn start..=7), Ok(3));
+    assert_eq!(bits!(value, u8 @ dyn start; 4), Ok(3));
+    assert_eq!(
+        bits!(value, u8 @ dyn 12..20),
+        Err(BitRangeError::new(12, 20, 16))
+    );
+    assert!(bits!(value, u8 @ dyn 12..=usize::MAX).is_err());
+    assert!(bits!(value, u8 @ dyn usize::MAX; 2).is_err());
+
+    assert_eq!(with_bits!(value, u8 @ dyn start..end = 0xF), Ok(0x12F4));
+    assert_eq!(with_bits!(value, dyn start; 4 = 0xF_u8), Ok(0x12F4));
+    assert_eq!(
+        with_bits!(value, dyn start; 4 = (end + 7) as u8),
+        Ok(0x12F4)
+    );
+
+    let mut storage = [0_u8; 3];
+    assert_eq!(set_bits!(storage, u16 @ dyn 4..20 = 0xABCD), Ok(()));
+    assert_eq!(storage, [0xD0, 0xBC, 0x0A]);
+    let range = 16..24;
+    assert_eq!(bits!(storage[..], u8 @ dyn range.clone()), Ok(0x0A));
+    assert_eq!(
+        set_bits!(storage[..2], u8 @ dyn range = 0xFF),
+        Err(BitRangeError::new(16, 24, 16))
+    );
+}
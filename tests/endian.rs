@@ -0,0 +1,119 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::{bitfield, BigEndian, Bits, LittleEndian, SetBits, WithBit};
+
+bitfield! {
+    /// An IPv4 header (without options), declared as drawn in RFC 791.
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    struct Ipv4Header(pub BigEndian<[u8; 20]>): Debug, Msb0 {
+        pub version: u8 @ 0..4,
+        pub ihl: u8 @ 4..8,
+        pub dscp: u8 @ 8..14,
+        pub ecn: u8 @ 14..16,
+        pub total_length: u16 @ 16..32,
+        pub identification: u16 @ 32..48,
+        pub dont_fragment: bool @ 49,
+        pub more_fragments: bool @ 50,
+        pub fragment_offset: u16 @ 51..64,
+        pub ttl: u8 @ 64..72,
+        pub protocol: u8 @ 72..80,
+        pub checksum: u16 @ 80..96,
+        pub src: u32 @ 96..128,
+        pub dst: u32 @ 128..160,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    struct Word(pub u32): Bytes {
+        pub low: u8 @ 0..8,
+        pub high: u8 @ 24..32,
+    }
+}
+
+const PACKET: [u8; 20] = [
+    0x45, 0x00, 0x00, 0x54, 0xAB, 0xCD, 0x40, 0x00, 0x40, 0x01, 0x12, 0x34, 0xC0, 0xA8, 0x00, 0x01,
+    0x08, 0x08, 0x08, 0x08,
+];
+
+#[test]
+fn big_endian_ipv4_header() {
+    let header = Ipv4Header(BigEndian(PACKET));
+    assert_eq!(header.version(), 4);
+    assert_eq!(header.ihl(), 5);
+    assert_eq!(header.dscp(), 0);
+    assert_eq!(header.total_length(), 0x54);
+    assert_eq!(header.identification(), 0xABCD);
+    assert!(header.dont_fragment());
+    assert!(!header.more_fragments());
+    assert_eq!(header.fragment_offset(), 0);
+    assert_eq!(header.ttl(), 64);
+    assert_eq!(header.protocol(), 1);
+    assert_eq!(header.checksum(), 0x1234);
+    assert_eq!(header.src(), 0xC0A8_0001);
+    assert_eq!(header.dst(), 0x0808_0808);
+
+    let header = header
+        .with_ttl(63)
+        .with_more_fragments(true)
+        .with_fragment_offset(0x1FFF)
+        .with_dst(0x0102_0304);
+    assert_eq!(header.0[6..10], [0x7F, 0xFF, 0x3F, 0x01]);
+    assert_eq!(header.0[16..20], [0x01, 0x02, 0x03, 0x04]);
+    assert_eq!(header.0[..6], PACKET[..6]);
+}
+
+#[test]
+fn big_endian_little_endian_storage() {
+    let be = BigEndian([0x12_u8, 0x34, 0x56]);
+    assert_eq!(Bits::<u32>::bits::<0, 24>(&be), 0x12_3456);
+    assert_eq!(Bits::<u8>::bits::<4, 12>(&be), 0x45);
+    assert_eq!(Bits::<i8>::bits::<0, 4>(&be), 6);
+    assert_eq!(Bits::<i8>::bits::<0, 4>(&BigEndian([0xF0_u8, 0x0A])), -6);
+    assert_eq!(be.with_bit::<23>(false).0, [0x12, 0x34, 0x56]);
+    assert_eq!(be.with_bit::<0>(true).0, [0x12, 0x34, 0x57]);
+
+    let le = LittleEndian([0x12_u8, 0x34, 0x56]);
+    assert_eq!(Bits::<u32>::bits::<0, 24>(&le), 0x56_3412);
+    assert_eq!(le.with_bit::<23>(true).0, [0x12, 0x34, 0xD6]);
+
+    let mut value = BigEndian([0_u8; 4]);
+    SetBits::<u16>::set_bits::<4, 20>(&mut value, 0xABCD);
+    assert_eq!(value.0, [0x00, 0x0A, 0xBC, 0xD0]);
+    let mut value = LittleEndian([0_u8; 4]);
+    SetBits::<u16>::set_bits::<4, 20>(&mut value, 0xABCD);
+    assert_eq!(value.0, [0xD0, 0xBC, 0x0A, 0x00]);
+
+    let mut bytes = PACKET;
+    let header = BigEndian::from_mut(&mut bytes);
+    SetBits::<u8>::set_bits::<152, 160>(header, 0x60);
+    assert_eq!(bytes[0], 0x60);
+}
+
+#[test]
+fn int_storage_bytes() {
+    let word = Word(0x1234_5678);
+    assert_eq!(word.to_be_bytes(), [0x12, 0x34, 0x56, 0x78]);
+    assert_eq!(word.to_le_bytes(), [0x78, 0x56, 0x34, 0x12]);
+    assert_eq!(word.to_ne_bytes(), 0x1234_5678_u32.to_ne_bytes());
+
+    assert_eq!(Word::from_be_bytes([0x12, 0x34, 0x56, 0x78]).high(), 0x12);
+    assert_eq!(Word::from_le_bytes([0x12, 0x34, 0x56, 0x78]).high(), 0x78);
+    assert_eq!(
+        Word::from_ne_bytes(0x1234_5678_u32.to_ne_bytes()).low(),
+        0x78
+    );
+}
+
+#[test]
+fn endian_write_wider_ranges() {
+    let mut value = BigEndian([0xFF_u8; 3]);
+    value.set_bits::<4, 20>(0x12_u8);
+    assert_eq!(value.0, [0xF0, 0x01, 0x2F]);
+    value.set_bits::<0, 24>(-2_i8);
+    assert_eq!(value.0, [0xFF, 0xFF, 0xFE]);
+}
@@ -0,0 +1,108 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::bitfield;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    Off,
+    Low,
+    High,
+}
+
+impl TryFrom<u8> for Mode {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Mode::Off),
+            1 => Ok(Mode::Low),
+            2 => Ok(Mode::High),
+            _ => Err(value),
+        }
+    }
+}
+
+impl From<Mode> for u8 {
+    fn from(value: Mode) -> Self {
+        value as u8
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    struct Priorities(pub u32): Debug {
+        pub prio: u8 [4] @ 0; 4,
+        pub enabled: bool [4] @ above,
+        pub mode: u8 [4] [try_get Mode, set Mode] @ 24; 2,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    struct Packed(pub u16) {
+        pub top: u8 @ 12..16,
+        pub slots: u8 [3] [read_only] @ below; 4,
+    }
+}
+
+#[test]
+fn field_arrays_basic_functionality() {
+    let value = Priorities(0x0000_4321);
+    assert_eq!(value.prio(0), 1);
+    assert_eq!(value.prio(3), 4);
+    assert_eq!(value.prio_checked(2), Some(3));
+    assert_eq!(value.prio_checked(4), None);
+    assert_eq!(value.prio_at::<1>(), 2);
+    assert!(value.prio_iter().eq([1, 2, 3, 4]));
+
+    let value = value.with_prio(1, 0xF).with_enabled(2, true);
+    assert_eq!(value.0, 0x0004_43F1);
+    assert!(value.enabled_iter().eq([false, false, true, false]));
+
+    let mut value = value;
+    value.set_prio_at::<3>(0);
+    value.set_enabled(0, true);
+    assert_eq!(value.0, 0x0005_03F1);
+}
+
+#[test]
+fn field_arrays_conversions() {
+    let mut value = Priorities(0);
+    value.set_mode(1, Mode::High);
+    value.set_mode(3, Mode::Low);
+    assert_eq!(value.0, 0x4800_0000);
+    assert_eq!(value.mode(1), Ok(Mode::High));
+    assert_eq!(value.mode(2), Ok(Mode::Off));
+
+    let value = value.with_mode_at::<0>(Mode::Low);
+    assert!(value
+        .mode_iter()
+        .eq([Ok(Mode::Low), Ok(Mode::High), Ok(Mode::Off), Ok(Mode::Low)]));
+    assert_eq!(Priorities(0x0300_0000).mode(0), Err(3));
+}
+
+#[test]
+fn field_arrays_packing() {
+    let value = Packed(0x4321);
+    assert_eq!(value.top(), 4);
+    assert!(value.slots_iter().eq([1, 2, 3]));
+}
+
+#[test]
+fn field_arrays_debug() {
+    assert_eq!(
+        format!("{:?}", Priorities(0x0000_0021)),
+        "Priorities { 0: 33, prio: [1, 2, 0, 0], enabled: [false, false, false, false], \
+         mode: [Ok(Off), Ok(Off), Ok(Off), Ok(Off)] }"
+    );
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds for field array `prio`")]
+fn field_arrays_out_of_bounds() {
+    Priorities(0).prio(4);
+}
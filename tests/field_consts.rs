@@ -0,0 +1,134 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::bitfield;
+
+bitfield! {
+    pub struct Inner(pub u8) {
+        pub value: u8 @ 0..8,
+    }
+}
+
+bitfield! {
+    pub struct Ctrl(pub u32): FieldConsts {
+        pub enable: bool @ 0,
+        pub mode: u8 @ 4..8,
+        pub status: u8 [read_only] @ 8..12,
+        pub data: u8 [write_only] @ 12..16,
+        pub prio: u8 [3] @ 16; 2,
+        _: reserved @ 22..24,
+        pub inner: nested Inner @ 24..32,
+    }
+}
+
+bitfield! {
+    pub struct Msb0Ctrl(pub u16): Msb0, FieldConsts {
+        pub top: u8 @ 0..4,
+        pub flag: bool @ 15,
+    }
+}
+
+bitfield! {
+    pub struct Header(pub [u8; 2]): FieldConsts {
+        pub version: u8 @ 4..8,
+        pub all: u16 [alias] @ ..,
+    }
+}
+
+bitfield! {
+    pub struct Signed(pub i16): FieldConsts {
+        pub low: u8 @ 0..8,
+        pub sign: bool @ 15,
+    }
+}
+
+// Without `FieldConsts`, field names can't collide with the struct's own constants
+bitfield! {
+    pub struct Plain(pub u8) {
+        pub writable: bool @ 0,
+        pub all_fields: bool @ 1,
+        pub w1c: bool [read_only] @ 2,
+    }
+}
+
+bitfield! {
+    pub struct Command(pub u8) {
+        read {
+            pub busy: bool @ 0,
+        }
+        write {
+            pub start: bool @ 0,
+            pub opcode: u8 @ 4..8,
+        }
+    }
+}
+
+const MODE_FIELD: u32 = 0x1234_5678 & Ctrl::MODE_MASK;
+
+#[test]
+fn field_consts() {
+    assert_eq!(Ctrl::ENABLE_MASK, 0x0000_0001);
+    assert_eq!(Ctrl::ENABLE_SHIFT, 0);
+    assert_eq!(Ctrl::ENABLE_RANGE, 0..1);
+    assert_eq!(Ctrl::ENABLE_BITS, 1);
+
+    assert_eq!(Ctrl::MODE_MASK, 0x0000_00F0);
+    assert_eq!(Ctrl::MODE_SHIFT, 4);
+    assert_eq!(Ctrl::MODE_RANGE, 4..8);
+    assert_eq!(Ctrl::MODE_BITS, 4);
+    assert_eq!(MODE_FIELD >> Ctrl::MODE_SHIFT, 7);
+
+    assert_eq!(Ctrl::PRIO_MASK, 0x003F_0000);
+    assert_eq!(Ctrl::PRIO_RANGE, 16..22);
+    assert_eq!(Ctrl::PRIO_BITS, 2);
+    assert_eq!(Ctrl::PRIO_LEN, 3);
+
+    assert_eq!(Ctrl::INNER_MASK, 0xFF00_0000);
+    assert_eq!(Ctrl::INNER_SHIFT, 24);
+    assert_eq!(Ctrl::INNER_BITS, 8);
+}
+
+#[test]
+fn msb0_field_consts() {
+    assert_eq!(Msb0Ctrl::TOP_MASK, 0xF000);
+    assert_eq!(Msb0Ctrl::TOP_SHIFT, 12);
+    assert_eq!(Msb0Ctrl::TOP_RANGE, 12..16);
+    assert_eq!(Msb0Ctrl::FLAG_MASK, 0x0001);
+    assert_eq!(Msb0Ctrl::FLAG_SHIFT, 0);
+}
+
+#[test]
+fn non_int_storage_field_consts() {
+    assert_eq!(Header::VERSION_SHIFT, 4);
+    assert_eq!(Header::VERSION_RANGE, 4..8);
+    assert_eq!(Header::ALL_RANGE, 0..16);
+    assert_eq!(Header::ALL_BITS, 16);
+}
+
+#[test]
+fn signed_storage_field_consts() {
+    let low_mask: i16 = Signed::LOW_MASK;
+    assert_eq!(low_mask, 0x00FF);
+    assert_eq!(Signed::SIGN_MASK, i16::MIN);
+    assert_eq!(Signed::ALL_FIELDS_MASK, i16::MIN | 0xFF);
+}
+
+#[test]
+fn struct_masks() {
+    assert_eq!(Ctrl::ALL_FIELDS_MASK, 0xFF3F_FFF1);
+    assert_eq!(Ctrl::WRITABLE_MASK, 0xFF3F_F0F1);
+    assert_eq!(Msb0Ctrl::ALL_FIELDS_MASK, 0xF001);
+
+    assert_eq!(Command::ALL_FIELDS_MASK, 0x01);
+    assert_eq!(Command::WRITABLE_MASK, 0);
+    assert_eq!(CommandWrite::ALL_FIELDS_MASK, 0xF1);
+    assert_eq!(CommandWrite::WRITABLE_MASK, 0xF1);
+
+    let plain = Plain(0x05);
+    assert!(plain.writable() && !plain.all_fields() && plain.w1c());
+    assert_eq!(Plain::WRITABLE_MASK, 0x03);
+    assert_eq!(Plain::ALL_FIELDS_MASK, 0x07);
+}
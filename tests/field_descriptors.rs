@@ -0,0 +1,116 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::{bitfield, Bitfield, Bits, FieldDescriptor, SetBits, WithBits};
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub struct Sub(pub u8): Descriptors {
+        pub low: u8 @ 0..4,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Level(u8);
+
+impl From<u8> for Level {
+    fn from(value: u8) -> Self {
+        Level(value)
+    }
+}
+
+impl From<Level> for u8 {
+    fn from(value: Level) -> Self {
+        value.0
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub struct Ctrl(pub u32): Descriptors, FieldConsts {
+        pub enable: bool @ 0,
+        pub ready: bool @ 1,
+        pub mode: u8 @ 4..8,
+        pub level: u8 [Level] @ 8..12,
+        pub status: u8 [read_only] @ 12..16,
+        pub irq: bool [w1c] @ 16,
+        pub prio: u8 [2] @ 18; 2,
+        pub sub: nested Sub @ 24..32,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub struct Flipped(pub u16): Descriptors, Msb0 {
+        pub top: u8 @ 0..4,
+    }
+}
+
+bitfield! {
+    pub struct Generic<S>(pub S): Descriptors
+    where
+        S: Bits<u8> + WithBits<u8> + SetBits<u8>
+    {
+        pub low: u8 @ 0..4,
+    }
+}
+
+fn toggle<B: Bitfield, F: FieldDescriptor<B, Value = bool>>(bitfield: &mut B, field: F) {
+    let value = field.get(bitfield);
+    field.set(bitfield, !value);
+}
+
+fn increment<B: Bitfield, F: FieldDescriptor<B, Value = u8>>(bitfield: B, field: F) -> B {
+    let value = field.get(&bitfield);
+    field.with(bitfield, value + 1)
+}
+
+#[test]
+fn generic_access() {
+    let mut ctrl = Ctrl(0);
+    toggle(&mut ctrl, Ctrl::ENABLE);
+    assert!(ctrl.enable());
+    toggle(&mut ctrl, Ctrl::READY);
+    toggle(&mut ctrl, Ctrl::ENABLE);
+    assert_eq!(ctrl.0, 0b10);
+
+    let ctrl = increment(increment(ctrl, Ctrl::MODE), Ctrl::MODE);
+    assert_eq!(ctrl.mode(), 2);
+
+    let mut generic = Generic(0xF0_u8, core::marker::PhantomData);
+    generic = increment(generic, Generic::<u8>::LOW);
+    assert_eq!(generic.0, 0xF1);
+}
+
+#[test]
+fn conversions_and_nested() {
+    let mut ctrl = Ctrl(0);
+    Ctrl::LEVEL.set(&mut ctrl, Level(5));
+    assert_eq!(Ctrl::LEVEL.get(&ctrl), Level(5));
+    assert_eq!(ctrl.0, 0x500);
+
+    let ctrl = Ctrl::SUB.with(ctrl, Sub(0xA7));
+    assert_eq!(ctrl.sub(), Sub(0xA7));
+    assert_eq!(Sub::LOW.get(&Ctrl::SUB.get(&ctrl)), 7);
+}
+
+#[test]
+fn constants() {
+    fn info<B: Bitfield, F: FieldDescriptor<B>>(
+        _: F,
+    ) -> (&'static str, core::ops::Range<usize>, usize) {
+        (F::NAME, F::RANGE, F::BITS)
+    }
+    assert_eq!(info(Ctrl::ENABLE), ("enable", 0..1, 1));
+    assert_eq!(info(Ctrl::MODE), ("mode", 4..8, 4));
+    assert_eq!(info(Ctrl::SUB), ("sub", 24..32, 8));
+    assert_eq!(info(Ctrl::MODE).1, Ctrl::MODE_RANGE);
+    assert_eq!(info(Flipped::TOP), ("top", 12..16, 4));
+
+    let mut flipped = Flipped(0);
+    Flipped::TOP.set(&mut flipped, 0xA);
+    assert_eq!(flipped.0, 0xA000);
+}
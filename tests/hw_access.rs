@@ -0,0 +1,155 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::bitfield;
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct IrqStatus(pub u32): Debug, Volatile {
+        pub enable: bool @ 0,
+        pub mode: u8 @ 1..4,
+        pub overrun: bool [w1c] @ 4,
+        pub channel_done: bool [8] [w1c] @ 8,
+        pub errors: u8 [w1c] @ 16..20,
+        pub start: bool [w1s] @ 24,
+        pub data_ready: bool [rc] @ 28,
+        pub busy: bool [read_only] @ 31,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Ctrl(pub u16): Debug, WriteZero {
+        pub enable: bool @ 0,
+        pub prescaler: u8 @ 4..8,
+        pub ready: bool [read_only] @ 8,
+        pub fifo_level: u8 [read_only] @ 12..16,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct ByteStatus(pub [u8; 2]): Debug {
+        pub enable: bool @ 0,
+        pub pending: bool [w1c] @ 8,
+    }
+}
+
+#[test]
+fn masks() {
+    assert_eq!(IrqStatus::W1C_MASK, 0x000F_FF10);
+    assert_eq!(IrqStatus::W1S_MASK, 0x0100_0000);
+    assert_eq!(IrqStatus::RC_MASK, 0x1000_0000);
+    assert_eq!(IrqStatus::WRITABLE_MASK, 0x0000_000F);
+
+    assert_eq!(Ctrl::WRITABLE_MASK, 0x00F1);
+}
+
+#[test]
+fn setters_zero_side_effecting_bits() {
+    let read = IrqStatus(0xFFFF_FFFF);
+    assert!(read.overrun());
+    assert!(read.channel_done(3));
+    assert_eq!(read.errors(), 0xF);
+    assert!(read.start());
+    assert!(read.data_ready());
+
+    // Writing back a modified value must neither acknowledge pending interrupts nor start a
+    // new transfer, but leaves other bits untouched
+    let write = read.with_mode(2);
+    assert_eq!(write.0, 0xFEF0_00E5);
+
+    let mut write = read;
+    write.set_enable(false);
+    assert_eq!(write.0, 0xFEF0_00EE);
+}
+
+#[test]
+fn side_effecting_setters() {
+    let value = IrqStatus(0)
+        .with_mode(1)
+        .with_clear_overrun()
+        .with_clear_channel_done(2)
+        .with_clear_channel_done_at::<7>()
+        .with_clear_errors(0b0101)
+        .with_start(true);
+    assert_eq!(value.0, 0x0105_8412);
+
+    let mut value = IrqStatus(0);
+    value.clear_overrun();
+    value.clear_channel_done(0);
+    value.clear_errors(0b1000);
+    value.set_start(true);
+    assert_eq!(value.0, 0x0108_0110);
+
+    // Side-effecting setters only touch their own bits
+    let value = IrqStatus(0x0000_0010).with_clear_errors(1);
+    assert_eq!(value.0, 0x0001_0010);
+}
+
+#[test]
+fn to_write() {
+    // Read values can be written back unchanged, or through side-effecting setters, once their
+    // side-effecting bits are cleared
+    let read = IrqStatus(0xFFFF_FFFF);
+    assert_eq!(read.to_write().0, 0xFEF0_00EF);
+    assert_eq!(read.to_write().with_clear_overrun().0, 0xFEF0_00FF);
+    assert_eq!(read.with_enable(false), read.to_write().with_enable(false));
+
+    // Other setters clear the bits written by side-effecting setters called before them
+    assert_eq!(
+        IrqStatus(0).with_enable(true).with_clear_overrun().0,
+        0x0000_0011
+    );
+    assert_eq!(
+        IrqStatus(0).with_clear_overrun().with_enable(true).0,
+        0x0000_0001
+    );
+
+    assert_eq!(Ctrl(0xF3A1).to_write().0, 0x00A1);
+}
+
+#[test]
+fn write_zero() {
+    let read = Ctrl(0xF3A1);
+    assert!(read.ready());
+    assert_eq!(read.fifo_level(), 0xF);
+
+    assert_eq!(read.with_prescaler(5).0, 0x0051);
+    let mut write = read;
+    write.set_enable(false);
+    assert_eq!(write.0, 0x00A0);
+}
+
+#[test]
+fn non_int_storage() {
+    let write = ByteStatus([0x00, 0x01]).with_enable(true);
+    assert_eq!(write.0, [0x01, 0x00]);
+    assert_eq!(ByteStatus([0; 2]).with_clear_pending().0, [0x00, 0x01]);
+}
+
+#[test]
+fn volatile() {
+    let storage = Box::into_raw(Box::new(0xFFFF_FFFF_u32));
+    let reg = unsafe { IrqStatusReg::new(storage) };
+
+    reg.modify(|value| value.with_enable(false));
+    assert_eq!(unsafe { *storage }, 0xFEF0_00EE);
+
+    reg.clear_overrun();
+    assert_eq!(unsafe { *storage }, 0x0000_0010);
+
+    reg.clear_channel_done(5);
+    assert_eq!(unsafe { *storage }, 0x0000_2000);
+
+    reg.clear_errors(0b11);
+    assert_eq!(unsafe { *storage }, 0x0003_0000);
+
+    reg.write_start(true);
+    assert_eq!(unsafe { *storage }, 0x0100_0000);
+
+    drop(unsafe { Box::from_raw(storage) });
+}
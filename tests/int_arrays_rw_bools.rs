@@ -0,0 +1,22 @@
+use proc_bitfield::{bits, set_bits, with_bits};
+
+#[test]
+fn int_arrays_rw_bools() {
+    assert!(bits!([0_u8, 0x10], 12));
+    assert!(!bits!([0xFF_u8, 0xEF], 12));
+
+    // Bits past the first element
+    assert_eq!(with_bits!([0_u8; 2], 12 = true), [0, 0x10]);
+    assert_eq!(with_bits!([0xFF_u8; 2], 12 = false), [0xFF, 0xEF]);
+    assert_eq!(with_bits!([0_i16; 3], 47 = true), [0, 0, i16::MIN]);
+}
+
+#[test]
+fn slices_rw_bools() {
+    let mut storage = [0_u16; 3];
+    set_bits!(storage[..], 37 = true);
+    assert_eq!(storage, [0, 0, 0x20]);
+    assert!(bits!(storage[..], 37));
+    set_bits!(storage[..], 37 = false);
+    assert_eq!(storage, [0; 3]);
+}
@@ -1,4 +1,4 @@
-use proc_bitfield::{bits, with_bits};
+use proc_bitfield::{bits, set_bits, with_bits};
 
 #[test]
 fn int_arrays_rw_ints_basic_functionality() {
@@ -100,3 +100,17 @@ fn int_arrays_write_ints_signs_crossing_boundaries() {
         [0xC0_u8 as i8, 3]
     );
 }
+
+#[test]
+fn int_arrays_write_ints_wider_ranges() {
+    // Values are sign- or zero-extended to the range's width, past the value type's own width
+    assert_eq!(
+        with_bits!([0xFF_u8; 3], u8 @ 4..24 = 0xAB),
+        [0xBF, 0x0A, 0x00]
+    );
+    assert_eq!(with_bits!([0_u8; 3], i8 @ 4..24 = -2), [0xE0, 0xFF, 0xFF]);
+    assert_eq!(with_bits!([0_u8; 3], u8 @ 8..24 = 0x80), [0, 0x80, 0]);
+    let mut slice = [0_u16; 3];
+    set_bits!(slice[..], i8 @ 8..40 = -1);
+    assert_eq!(slice, [0xFF00, 0xFFFF, 0x00FF]);
+}
@@ -1,4 +1,4 @@
-use proc_bitfield::{bits, with_bits};
+use proc_bitfield::{bits, set_bits, with_bits, Bits, WithBits};
 
 #[test]
 fn ints_rw_ints_basic_functionality() {
@@ -41,3 +41,33 @@ fn ints_write_ints_signs() {
     assert_eq!(with_bits!(0_u8, u16 @ 0..4 = 0xFFFF), 0xF);
     assert_eq!(with_bits!(0_i8, u16 @ 0..4 = 0xFFFF), 0xF);
 }
+
+#[test]
+fn ints_rw_generic_ints() {
+    fn nibble<T>(value: u16) -> T
+    where
+        u16: Bits<T>,
+    {
+        bits!(value, T @ 4..8)
+    }
+    fn with_nibble<T>(value: u16, nibble: T) -> u16
+    where
+        u16: WithBits<T>,
+    {
+        with_bits!(value, T @ 4..8 = nibble)
+    }
+
+    assert_eq!(nibble::<u8>(0x1234), 3);
+    assert_eq!(nibble::<i8>(0x12F4), -1);
+    assert_eq!(with_nibble::<u8>(0x1234, 0xA), 0x12A4);
+}
+
+#[test]
+fn ints_write_ints_wider_ranges() {
+    // Values are sign- or zero-extended to the range's width
+    assert_eq!(with_bits!(0_u32, u8 @ 0..16 = 0xAB), 0x00AB);
+    assert_eq!(with_bits!(0_u32, i8 @ 4..20 = -2), 0xF_FFE0);
+    let mut value = 0xFFFF_FFFF_u32;
+    set_bits!(value, u8 @ 8..24 = 0x12);
+    assert_eq!(value, 0xFF00_12FF);
+}
@@ -0,0 +1,583 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+//! A large register file, used to keep an eye on the size of `bitfield!`'s expansion.
+
+use proc_bitfield::bitfield;
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Reg0(pub u64) {
+        pub flag0: bool @ 0,
+        pub flag1: bool @ 1,
+        pub flag2: bool @ 2,
+        pub flag3: bool @ 3,
+        pub flag4: bool @ 4,
+        pub flag5: bool @ 5,
+        pub flag6: bool @ 6,
+        pub flag7: bool @ 7,
+        pub flag8: bool @ 8,
+        pub flag9: bool @ 9,
+        pub flag10: bool @ 10,
+        pub flag11: bool @ 11,
+        pub flag12: bool @ 12,
+        pub flag13: bool @ 13,
+        pub flag14: bool @ 14,
+        pub flag15: bool @ 15,
+        pub flag16: bool @ 16,
+        pub flag17: bool @ 17,
+        pub flag18: bool @ 18,
+        pub flag19: bool @ 19,
+        pub flag20: bool @ 20,
+        pub flag21: bool @ 21,
+        pub flag22: bool @ 22,
+        pub flag23: bool @ 23,
+        pub flag24: bool @ 24,
+        pub flag25: bool @ 25,
+        pub flag26: bool @ 26,
+        pub flag27: bool @ 27,
+        pub flag28: bool @ 28,
+        pub flag29: bool @ 29,
+        pub flag30: bool @ 30,
+        pub flag31: bool @ 31,
+        pub flag32: bool @ 32,
+        pub flag33: bool @ 33,
+        pub flag34: bool @ 34,
+        pub flag35: bool @ 35,
+        pub field0: u8 @ 36..38,
+        pub field1: u8 @ 38..40,
+        pub field2: u8 @ 40..42,
+        pub field3: u8 @ 42..44,
+        pub field4: u8 @ 44..46,
+        pub field5: u8 @ 46..48,
+        pub field6: u8 @ 48..50,
+        pub field7: u8 @ 50..52,
+        pub field8: u8 @ 52..54,
+        pub field9: u8 @ 54..56,
+        pub field10: u8 @ 56..58,
+        pub field11: u8 @ 58..60,
+        pub field12: u8 @ 60..62,
+        pub field13: u8 @ 62..64,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Reg1(pub u64) {
+        pub flag0: bool @ 0,
+        pub flag1: bool @ 1,
+        pub flag2: bool @ 2,
+        pub flag3: bool @ 3,
+        pub flag4: bool @ 4,
+        pub flag5: bool @ 5,
+        pub flag6: bool @ 6,
+        pub flag7: bool @ 7,
+        pub flag8: bool @ 8,
+        pub flag9: bool @ 9,
+        pub flag10: bool @ 10,
+        pub flag11: bool @ 11,
+        pub flag12: bool @ 12,
+        pub flag13: bool @ 13,
+        pub flag14: bool @ 14,
+        pub flag15: bool @ 15,
+        pub flag16: bool @ 16,
+        pub flag17: bool @ 17,
+        pub flag18: bool @ 18,
+        pub flag19: bool @ 19,
+        pub flag20: bool @ 20,
+        pub flag21: bool @ 21,
+        pub flag22: bool @ 22,
+        pub flag23: bool @ 23,
+        pub flag24: bool @ 24,
+        pub flag25: bool @ 25,
+        pub flag26: bool @ 26,
+        pub flag27: bool @ 27,
+        pub flag28: bool @ 28,
+        pub flag29: bool @ 29,
+        pub flag30: bool @ 30,
+        pub flag31: bool @ 31,
+        pub flag32: bool @ 32,
+        pub flag33: bool @ 33,
+        pub flag34: bool @ 34,
+        pub flag35: bool @ 35,
+        pub field0: u8 @ 36..38,
+        pub field1: u8 @ 38..40,
+        pub field2: u8 @ 40..42,
+        pub field3: u8 @ 42..44,
+        pub field4: u8 @ 44..46,
+        pub field5: u8 @ 46..48,
+        pub field6: u8 @ 48..50,
+        pub field7: u8 @ 50..52,
+        pub field8: u8 @ 52..54,
+        pub field9: u8 @ 54..56,
+        pub field10: u8 @ 56..58,
+        pub field11: u8 @ 58..60,
+        pub field12: u8 @ 60..62,
+        pub field13: u8 @ 62..64,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Reg2(pub u64) {
+        pub flag0: bool @ 0,
+        pub flag1: bool @ 1,
+        pub flag2: bool @ 2,
+        pub flag3: bool @ 3,
+        pub flag4: bool @ 4,
+        pub flag5: bool @ 5,
+        pub flag6: bool @ 6,
+        pub flag7: bool @ 7,
+        pub flag8: bool @ 8,
+        pub flag9: bool @ 9,
+        pub flag10: bool @ 10,
+        pub flag11: bool @ 11,
+        pub flag12: bool @ 12,
+        pub flag13: bool @ 13,
+        pub flag14: bool @ 14,
+        pub flag15: bool @ 15,
+        pub flag16: bool @ 16,
+        pub flag17: bool @ 17,
+        pub flag18: bool @ 18,
+        pub flag19: bool @ 19,
+        pub flag20: bool @ 20,
+        pub flag21: bool @ 21,
+        pub flag22: bool @ 22,
+        pub flag23: bool @ 23,
+        pub flag24: bool @ 24,
+        pub flag25: bool @ 25,
+        pub flag26: bool @ 26,
+        pub flag27: bool @ 27,
+        pub flag28: bool @ 28,
+        pub flag29: bool @ 29,
+        pub flag30: bool @ 30,
+        pub flag31: bool @ 31,
+        pub flag32: bool @ 32,
+        pub flag33: bool @ 33,
+        pub flag34: bool @ 34,
+        pub flag35: bool @ 35,
+        pub field0: u8 @ 36..38,
+        pub field1: u8 @ 38..40,
+        pub field2: u8 @ 40..42,
+        pub field3: u8 @ 42..44,
+        pub field4: u8 @ 44..46,
+        pub field5: u8 @ 46..48,
+        pub field6: u8 @ 48..50,
+        pub field7: u8 @ 50..52,
+        pub field8: u8 @ 52..54,
+        pub field9: u8 @ 54..56,
+        pub field10: u8 @ 56..58,
+        pub field11: u8 @ 58..60,
+        pub field12: u8 @ 60..62,
+        pub field13: u8 @ 62..64,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Reg3(pub u64) {
+        pub flag0: bool @ 0,
+        pub flag1: bool @ 1,
+        pub flag2: bool @ 2,
+        pub flag3: bool @ 3,
+        pub flag4: bool @ 4,
+        pub flag5: bool @ 5,
+        pub flag6: bool @ 6,
+        pub flag7: bool @ 7,
+        pub flag8: bool @ 8,
+        pub flag9: bool @ 9,
+        pub flag10: bool @ 10,
+        pub flag11: bool @ 11,
+        pub flag12: bool @ 12,
+        pub flag13: bool @ 13,
+        pub flag14: bool @ 14,
+        pub flag15: bool @ 15,
+        pub flag16: bool @ 16,
+        pub flag17: bool @ 17,
+        pub flag18: bool @ 18,
+        pub flag19: bool @ 19,
+        pub flag20: bool @ 20,
+        pub flag21: bool @ 21,
+        pub flag22: bool @ 22,
+        pub flag23: bool @ 23,
+        pub flag24: bool @ 24,
+        pub flag25: bool @ 25,
+        pub flag26: bool @ 26,
+        pub flag27: bool @ 27,
+        pub flag28: bool @ 28,
+        pub flag29: bool @ 29,
+        pub flag30: bool @ 30,
+        pub flag31: bool @ 31,
+        pub flag32: bool @ 32,
+        pub flag33: bool @ 33,
+        pub flag34: bool @ 34,
+        pub flag35: bool @ 35,
+        pub field0: u8 @ 36..38,
+        pub field1: u8 @ 38..40,
+        pub field2: u8 @ 40..42,
+        pub field3: u8 @ 42..44,
+        pub field4: u8 @ 44..46,
+        pub field5: u8 @ 46..48,
+        pub field6: u8 @ 48..50,
+        pub field7: u8 @ 50..52,
+        pub field8: u8 @ 52..54,
+        pub field9: u8 @ 54..56,
+        pub field10: u8 @ 56..58,
+        pub field11: u8 @ 58..60,
+        pub field12: u8 @ 60..62,
+        pub field13: u8 @ 62..64,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Reg4(pub u64) {
+        pub flag0: bool @ 0,
+        pub flag1: bool @ 1,
+        pub flag2: bool @ 2,
+        pub flag3: bool @ 3,
+        pub flag4: bool @ 4,
+        pub flag5: bool @ 5,
+        pub flag6: bool @ 6,
+        pub flag7: bool @ 7,
+        pub flag8: bool @ 8,
+        pub flag9: bool @ 9,
+        pub flag10: bool @ 10,
+        pub flag11: bool @ 11,
+        pub flag12: bool @ 12,
+        pub flag13: bool @ 13,
+        pub flag14: bool @ 14,
+        pub flag15: bool @ 15,
+        pub flag16: bool @ 16,
+        pub flag17: bool @ 17,
+        pub flag18: bool @ 18,
+        pub flag19: bool @ 19,
+        pub flag20: bool @ 20,
+        pub flag21: bool @ 21,
+        pub flag22: bool @ 22,
+        pub flag23: bool @ 23,
+        pub flag24: bool @ 24,
+        pub flag25: bool @ 25,
+        pub flag26: bool @ 26,
+        pub flag27: bool @ 27,
+        pub flag28: bool @ 28,
+        pub flag29: bool @ 29,
+        pub flag30: bool @ 30,
+        pub flag31: bool @ 31,
+        pub flag32: bool @ 32,
+        pub flag33: bool @ 33,
+        pub flag34: bool @ 34,
+        pub flag35: bool @ 35,
+        pub field0: u8 @ 36..38,
+        pub field1: u8 @ 38..40,
+        pub field2: u8 @ 40..42,
+        pub field3: u8 @ 42..44,
+        pub field4: u8 @ 44..46,
+        pub field5: u8 @ 46..48,
+        pub field6: u8 @ 48..50,
+        pub field7: u8 @ 50..52,
+        pub field8: u8 @ 52..54,
+        pub field9: u8 @ 54..56,
+        pub field10: u8 @ 56..58,
+        pub field11: u8 @ 58..60,
+        pub field12: u8 @ 60..62,
+        pub field13: u8 @ 62..64,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Reg5(pub u64) {
+        pub flag0: bool @ 0,
+        pub flag1: bool @ 1,
+        pub flag2: bool @ 2,
+        pub flag3: bool @ 3,
+        pub flag4: bool @ 4,
+        pub flag5: bool @ 5,
+        pub flag6: bool @ 6,
+        pub flag7: bool @ 7,
+        pub flag8: bool @ 8,
+        pub flag9: bool @ 9,
+        pub flag10: bool @ 10,
+        pub flag11: bool @ 11,
+        pub flag12: bool @ 12,
+        pub flag13: bool @ 13,
+        pub flag14: bool @ 14,
+        pub flag15: bool @ 15,
+        pub flag16: bool @ 16,
+        pub flag17: bool @ 17,
+        pub flag18: bool @ 18,
+        pub flag19: bool @ 19,
+        pub flag20: bool @ 20,
+        pub flag21: bool @ 21,
+        pub flag22: bool @ 22,
+        pub flag23: bool @ 23,
+        pub flag24: bool @ 24,
+        pub flag25: bool @ 25,
+        pub flag26: bool @ 26,
+        pub flag27: bool @ 27,
+        pub flag28: bool @ 28,
+        pub flag29: bool @ 29,
+        pub flag30: bool @ 30,
+        pub flag31: bool @ 31,
+        pub flag32: bool @ 32,
+        pub flag33: bool @ 33,
+        pub flag34: bool @ 34,
+        pub flag35: bool @ 35,
+        pub field0: u8 @ 36..38,
+        pub field1: u8 @ 38..40,
+        pub field2: u8 @ 40..42,
+        pub field3: u8 @ 42..44,
+        pub field4: u8 @ 44..46,
+        pub field5: u8 @ 46..48,
+        pub field6: u8 @ 48..50,
+        pub field7: u8 @ 50..52,
+        pub field8: u8 @ 52..54,
+        pub field9: u8 @ 54..56,
+        pub field10: u8 @ 56..58,
+        pub field11: u8 @ 58..60,
+        pub field12: u8 @ 60..62,
+        pub field13: u8 @ 62..64,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Reg6(pub u64) {
+        pub flag0: bool @ 0,
+        pub flag1: bool @ 1,
+        pub flag2: bool @ 2,
+        pub flag3: bool @ 3,
+        pub flag4: bool @ 4,
+        pub flag5: bool @ 5,
+        pub flag6: bool @ 6,
+        pub flag7: bool @ 7,
+        pub flag8: bool @ 8,
+        pub flag9: bool @ 9,
+        pub flag10: bool @ 10,
+        pub flag11: bool @ 11,
+        pub flag12: bool @ 12,
+        pub flag13: bool @ 13,
+        pub flag14: bool @ 14,
+        pub flag15: bool @ 15,
+        pub flag16: bool @ 16,
+        pub flag17: bool @ 17,
+        pub flag18: bool @ 18,
+        pub flag19: bool @ 19,
+        pub flag20: bool @ 20,
+        pub flag21: bool @ 21,
+        pub flag22: bool @ 22,
+        pub flag23: bool @ 23,
+        pub flag24: bool @ 24,
+        pub flag25: bool @ 25,
+        pub flag26: bool @ 26,
+        pub flag27: bool @ 27,
+        pub flag28: bool @ 28,
+        pub flag29: bool @ 29,
+        pub flag30: bool @ 30,
+        pub flag31: bool @ 31,
+        pub flag32: bool @ 32,
+        pub flag33: bool @ 33,
+        pub flag34: bool @ 34,
+        pub flag35: bool @ 35,
+        pub field0: u8 @ 36..38,
+        pub field1: u8 @ 38..40,
+        pub field2: u8 @ 40..42,
+        pub field3: u8 @ 42..44,
+        pub field4: u8 @ 44..46,
+        pub field5: u8 @ 46..48,
+        pub field6: u8 @ 48..50,
+        pub field7: u8 @ 50..52,
+        pub field8: u8 @ 52..54,
+        pub field9: u8 @ 54..56,
+        pub field10: u8 @ 56..58,
+        pub field11: u8 @ 58..60,
+        pub field12: u8 @ 60..62,
+        pub field13: u8 @ 62..64,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Reg7(pub u64) {
+        pub flag0: bool @ 0,
+        pub flag1: bool @ 1,
+        pub flag2: bool @ 2,
+        pub flag3: bool @ 3,
+        pub flag4: bool @ 4,
+        pub flag5: bool @ 5,
+        pub flag6: bool @ 6,
+        pub flag7: bool @ 7,
+        pub flag8: bool @ 8,
+        pub flag9: bool @ 9,
+        pub flag10: bool @ 10,
+        pub flag11: bool @ 11,
+        pub flag12: bool @ 12,
+        pub flag13: bool @ 13,
+        pub flag14: bool @ 14,
+        pub flag15: bool @ 15,
+        pub flag16: bool @ 16,
+        pub flag17: bool @ 17,
+        pub flag18: bool @ 18,
+        pub flag19: bool @ 19,
+        pub flag20: bool @ 20,
+        pub flag21: bool @ 21,
+        pub flag22: bool @ 22,
+        pub flag23: bool @ 23,
+        pub flag24: bool @ 24,
+        pub flag25: bool @ 25,
+        pub flag26: bool @ 26,
+        pub flag27: bool @ 27,
+        pub flag28: bool @ 28,
+        pub flag29: bool @ 29,
+        pub flag30: bool @ 30,
+        pub flag31: bool @ 31,
+        pub flag32: bool @ 32,
+        pub flag33: bool @ 33,
+        pub flag34: bool @ 34,
+        pub flag35: bool @ 35,
+        pub field0: u8 @ 36..38,
+        pub field1: u8 @ 38..40,
+        pub field2: u8 @ 40..42,
+        pub field3: u8 @ 42..44,
+        pub field4: u8 @ 44..46,
+        pub field5: u8 @ 46..48,
+        pub field6: u8 @ 48..50,
+        pub field7: u8 @ 50..52,
+        pub field8: u8 @ 52..54,
+        pub field9: u8 @ 54..56,
+        pub field10: u8 @ 56..58,
+        pub field11: u8 @ 58..60,
+        pub field12: u8 @ 60..62,
+        pub field13: u8 @ 62..64,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Reg8(pub u64) {
+        pub flag0: bool @ 0,
+        pub flag1: bool @ 1,
+        pub flag2: bool @ 2,
+        pub flag3: bool @ 3,
+        pub flag4: bool @ 4,
+        pub flag5: bool @ 5,
+        pub flag6: bool @ 6,
+        pub flag7: bool @ 7,
+        pub flag8: bool @ 8,
+        pub flag9: bool @ 9,
+        pub flag10: bool @ 10,
+        pub flag11: bool @ 11,
+        pub flag12: bool @ 12,
+        pub flag13: bool @ 13,
+        pub flag14: bool @ 14,
+        pub flag15: bool @ 15,
+        pub flag16: bool @ 16,
+        pub flag17: bool @ 17,
+        pub flag18: bool @ 18,
+        pub flag19: bool @ 19,
+        pub flag20: bool @ 20,
+        pub flag21: bool @ 21,
+        pub flag22: bool @ 22,
+        pub flag23: bool @ 23,
+        pub flag24: bool @ 24,
+        pub flag25: bool @ 25,
+        pub flag26: bool @ 26,
+        pub flag27: bool @ 27,
+        pub flag28: bool @ 28,
+        pub flag29: bool @ 29,
+        pub flag30: bool @ 30,
+        pub flag31: bool @ 31,
+        pub flag32: bool @ 32,
+        pub flag33: bool @ 33,
+        pub flag34: bool @ 34,
+        pub flag35: bool @ 35,
+        pub field0: u8 @ 36..38,
+        pub field1: u8 @ 38..40,
+        pub field2: u8 @ 40..42,
+        pub field3: u8 @ 42..44,
+        pub field4: u8 @ 44..46,
+        pub field5: u8 @ 46..48,
+        pub field6: u8 @ 48..50,
+        pub field7: u8 @ 50..52,
+        pub field8: u8 @ 52..54,
+        pub field9: u8 @ 54..56,
+        pub field10: u8 @ 56..58,
+        pub field11: u8 @ 58..60,
+        pub field12: u8 @ 60..62,
+        pub field13: u8 @ 62..64,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Reg9(pub u64) {
+        pub flag0: bool @ 0,
+        pub flag1: bool @ 1,
+        pub flag2: bool @ 2,
+        pub flag3: bool @ 3,
+        pub flag4: bool @ 4,
+        pub flag5: bool @ 5,
+        pub flag6: bool @ 6,
+        pub flag7: bool @ 7,
+        pub flag8: bool @ 8,
+        pub flag9: bool @ 9,
+        pub flag10: bool @ 10,
+        pub flag11: bool @ 11,
+        pub flag12: bool @ 12,
+        pub flag13: bool @ 13,
+        pub flag14: bool @ 14,
+        pub flag15: bool @ 15,
+        pub flag16: bool @ 16,
+        pub flag17: bool @ 17,
+        pub flag18: bool @ 18,
+        pub flag19: bool @ 19,
+        pub flag20: bool @ 20,
+        pub flag21: bool @ 21,
+        pub flag22: bool @ 22,
+        pub flag23: bool @ 23,
+        pub flag24: bool @ 24,
+        pub flag25: bool @ 25,
+        pub flag26: bool @ 26,
+        pub flag27: bool @ 27,
+        pub flag28: bool @ 28,
+        pub flag29: bool @ 29,
+        pub flag30: bool @ 30,
+        pub flag31: bool @ 31,
+        pub flag32: bool @ 32,
+        pub flag33: bool @ 33,
+        pub flag34: bool @ 34,
+        pub flag35: bool @ 35,
+        pub field0: u8 @ 36..38,
+        pub field1: u8 @ 38..40,
+        pub field2: u8 @ 40..42,
+        pub field3: u8 @ 42..44,
+        pub field4: u8 @ 44..46,
+        pub field5: u8 @ 46..48,
+        pub field6: u8 @ 48..50,
+        pub field7: u8 @ 50..52,
+        pub field8: u8 @ 52..54,
+        pub field9: u8 @ 54..56,
+        pub field10: u8 @ 56..58,
+        pub field11: u8 @ 58..60,
+        pub field12: u8 @ 60..62,
+        pub field13: u8 @ 62..64,
+    }
+}
+
+#[test]
+fn accessors() {
+    let reg = Reg0(0).with_flag3(true).with_field13(2);
+    assert_eq!(reg.0, 0x8000_0000_0000_0008);
+    assert!(reg.flag3());
+    assert_eq!(reg.field13(), 2);
+
+    let mut reg = Reg9(u64::MAX);
+    reg.set_flag35(false);
+    reg.set_field0(1);
+    assert_eq!(reg.0, 0xFFFF_FFD7_FFFF_FFFF);
+    assert_eq!(reg.field0(), 1);
+}
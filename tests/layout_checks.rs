@@ -0,0 +1,72 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::bitfield;
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Instr(pub u32): Debug, Exhaustive {
+        pub raw: u32 [alias] @ ..,
+        pub opcode: u8 @ 0..8,
+        union {
+            pub imm: u32 @ 8..32,
+            pub reg: u8 @ 8..13,
+            pub offset: u32 @ 13..32,
+        },
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Ctrl(pub u16): Exhaustive, Msb0 {
+        pub enable: bool @ 0,
+        pub mode: u8 @ above; 3,
+        _: reserved @ 4..(4 + 4),
+        pub prio: u8 [2] @ 8; 4,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Wide(pub [u8; 2]): Exhaustive {
+        pub low: u8 @ 0..8,
+        pub high: u8 @ 8..(::core::mem::size_of::<u16>() << 3),
+        pub mid: u8 [alias] @ 4..12,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Split(pub u8): Exhaustive {
+        read {
+            pub ready: bool @ 0,
+            pub level: u8 @ 1..8,
+        }
+        write {
+            pub start: bool @ 0,
+            _: reserved @ 1..8,
+        }
+    }
+}
+
+#[test]
+fn aliases_and_unions() {
+    let instr = Instr(0x0000_2505);
+    assert_eq!(instr.raw(), 0x2505);
+    assert_eq!(instr.opcode(), 5);
+    assert_eq!(instr.imm(), 0x25);
+    assert_eq!(instr.reg(), 5);
+    assert_eq!(instr.offset(), 1);
+
+    assert_eq!(Wide([0x21, 0x43]).mid(), 0x32);
+}
+
+#[test]
+fn exhaustive_layouts() {
+    assert_eq!(Ctrl(0).with_enable(true).with_prio(1, 3).0, 0x8003);
+    assert!(Split(0x03).ready());
+    assert_eq!(SplitWrite(0).with_start(true).0, 1);
+}
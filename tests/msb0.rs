@@ -0,0 +1,63 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+#![allow(clippy::unnecessary_cast)]
+
+use proc_bitfield::{bitfield, bits, set_bits, with_bits};
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    struct Msr(pub u32): Debug, Msb0 {
+        pub sf: bool @ 0,
+        pub hv: bool @ above,
+        pub pr: bool @ 17,
+        pub fe: u8 @ 20..=23,
+        pub ri: bool @ 30,
+        pub le: bool @ above,
+        pub nibbles: u8 [2] @ 8; 4,
+        pub top: u8 @ 24; 4,
+        pub packed: u8 [alias] @ below; 2,
+    }
+}
+
+#[test]
+fn msb0_bitfield() {
+    let msr = Msr(0x8000_0000);
+    assert!(msr.sf());
+    assert!(!msr.hv());
+
+    assert_eq!(Msr(0).with_hv(true).0, 0x4000_0000);
+    assert_eq!(Msr(0).with_pr(true).0, 0x0000_4000);
+    assert_eq!(Msr(0).with_fe(0xA).0, 0x0000_0A00);
+    assert_eq!(Msr(0).with_ri(true).0, 0x0000_0002);
+    assert_eq!(Msr(0).with_le(true).0, 0x0000_0001);
+    assert_eq!(Msr(0x0000_0A00).fe(), 0xA);
+
+    let msr = Msr(0).with_nibbles(0, 0x1).with_nibbles(1, 0x2);
+    assert_eq!(msr.0, 0x0012_0000);
+    assert!(msr.nibbles_iter().eq([1, 2]));
+
+    assert_eq!(Msr(0).with_top(0xF).0, 0x0000_00F0);
+    assert_eq!(Msr(0).with_packed(0x3).0, 0x0000_0300);
+}
+
+#[test]
+fn msb0_bits_macros() {
+    assert!(bits!(msb0 0x8000 as u16, 0));
+    assert!(!bits!(msb0 0x8000 as u16, 15));
+    assert_eq!(bits!(msb0 0x1234 as u16, u16 @ 0..4), 0x1);
+    assert_eq!(bits!(msb0 0x1234 as u16, u8 @ 4..=11), 0x23);
+    assert_eq!(bits!(msb0 0x1234 as u16, u16 @ 12; 4), 0x4);
+
+    assert_eq!(with_bits!(msb0 0 as u16, 0..4 = 0xF_u16), 0xF000);
+    assert_eq!(with_bits!(msb0 0 as u16, 15 = true), 0x0001);
+
+    let mut value = 0_u32;
+    set_bits!(msb0 value as u32, 8..16 = 0xAB_u32);
+    assert_eq!(value, 0x00AB_0000);
+
+    let msb0 = 0x8000_u16;
+    assert!(bits!(msb0, 15));
+}
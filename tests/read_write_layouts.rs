@@ -0,0 +1,79 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::{bitfield, Bitfield, ReadWriteBitfield};
+
+bitfield! {
+    /// A command register that reads back as a status register.
+    #[derive(Clone, Copy, PartialEq, Eq, Default)]
+    pub struct Command(pub u32): Debug, FromStorage, IntoStorage, Volatile {
+        read {
+            pub busy: bool @ 0,
+            pub error: bool @ 1,
+            pub last_opcode: u8 @ 8..16,
+            pub fifo_level: u8 [2] @ 16..20,
+        }
+        write {
+            pub start: bool @ 0,
+            pub reset: bool @ 1,
+            pub opcode: u8 @ 8..16,
+            pub ack_error: bool [w1c] @ 31,
+        }
+    }
+}
+
+fn assert_same_storage<T: ReadWriteBitfield>()
+where
+    T::Write: Bitfield<Storage = u32>,
+{
+}
+
+#[test]
+fn read_and_write_layouts() {
+    assert_same_storage::<Command>();
+
+    let status = Command(0x0021_2A03);
+    assert!(status.busy());
+    assert!(status.error());
+    assert_eq!(status.last_opcode(), 0x2A);
+    assert_eq!(status.fifo_level(0), 1);
+    assert_eq!(status.fifo_level(1), 2);
+
+    let command = CommandWrite(0).with_opcode(0x15).with_start(true);
+    assert_eq!(command.0, 0x0000_1501);
+    assert_eq!(
+        CommandWrite::default().with_clear_ack_error().0,
+        0x8000_0000
+    );
+
+    assert_eq!(u32::from(CommandWrite::from(0x1234)), 0x1234);
+    assert_eq!(format!("{:?}", CommandWrite(0)), "CommandWrite { 0: 0 }");
+    assert_eq!(
+        format!("{:?}", Command(1)),
+        "Command { 0: 1, busy: true, error: false, last_opcode: 0, fifo_level: [0, 0] }"
+    );
+}
+
+#[test]
+fn volatile() {
+    let storage = Box::into_raw(Box::new(0x0000_2A01_u32));
+    let reg = unsafe { CommandReg::new(storage) };
+
+    assert_eq!(reg.read(), Command(0x0000_2A01));
+    assert!(reg.read_busy());
+    assert_eq!(reg.read_last_opcode(), 0x2A);
+
+    reg.write(CommandWrite(0).with_reset(true));
+    assert_eq!(unsafe { *storage }, 0x0000_0002);
+
+    reg.write_opcode(0x7F);
+    assert_eq!(unsafe { *storage }, 0x0000_7F00);
+
+    reg.clear_ack_error();
+    assert_eq!(unsafe { *storage }, 0x8000_0000);
+
+    drop(unsafe { Box::from_raw(storage) });
+}
@@ -0,0 +1,143 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::{bitfield, Bits, FieldAccess, FieldInfo, Reflect, SetBits, WithBits};
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Sub(pub u8): Reflect {
+        /// The low nibble.
+        pub low: u8 @ 0..4,
+        pub flag: bool [read_only] @ 7,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Ctrl(pub u32): Reflect {
+        /// Enables the device.
+        ///
+        /// Takes effect immediately.
+        pub enable: bool @ 0,
+        pub mode: u8 [write_only] @ 4..8,
+        pub prio: u8 [2] @ 8; 4,
+        _: reserved @ 18..24,
+        pub sub: nested Sub @ 24..32,
+        pub value: u8 [get Option<u8>] @ 16..18,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Wide(pub [u8; 3]): Reflect, Msb0 {
+        pub top: u16 @ 0..12,
+        pub bottom: u16 @ 12..24,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Command(pub u8): Reflect {
+        read {
+            pub busy: bool @ 0,
+        }
+        write {
+            pub start: bool @ 0,
+            pub opcode: u8 @ 4..8,
+        }
+    }
+}
+
+bitfield! {
+    pub struct Generic<S>(pub S): Reflect
+    where
+        S: Bits<u8> + WithBits<u8> + SetBits<u8>
+    {
+        pub low: u8 @ 0..4,
+        pub sub: nested Sub @ 8..16,
+    }
+}
+
+#[test]
+fn fields_table() {
+    assert_eq!(Ctrl::FIELDS.len(), 5);
+    assert_eq!(
+        Ctrl::FIELDS[0],
+        FieldInfo {
+            name: "enable",
+            bits: 0..1,
+            len: 1,
+            access: FieldAccess::ReadWrite,
+            ty: "bool",
+            doc: "Enables the device.\n\nTakes effect immediately.",
+            nested: None,
+        }
+    );
+    assert_eq!(Ctrl::FIELDS[1].access, FieldAccess::WriteOnly);
+    assert_eq!(Ctrl::FIELDS[2].bits, 8..16);
+    assert_eq!(Ctrl::FIELDS[2].len, 2);
+    assert_eq!(Ctrl::FIELDS[3].name, "sub");
+    assert_eq!(Ctrl::FIELDS[3].nested, Some(Sub::FIELDS));
+    assert_eq!(Ctrl::FIELDS[3].ty, "Sub");
+    assert_eq!(Ctrl::FIELDS[4].ty, "u8");
+
+    assert_eq!(Sub::FIELDS[0].doc, "The low nibble.");
+    assert_eq!(Sub::FIELDS[1].access, FieldAccess::ReadOnly);
+
+    assert_eq!(Wide::FIELDS[0].bits, 12..24);
+    assert_eq!(Wide::FIELDS[1].bits, 0..12);
+
+    assert_eq!(Command::FIELDS[0].access, FieldAccess::ReadOnly);
+    assert_eq!(CommandWrite::FIELDS[1].name, "opcode");
+    assert_eq!(CommandWrite::FIELDS[1].access, FieldAccess::WriteOnly);
+}
+
+#[test]
+fn raw_access_by_index() {
+    let mut ctrl = Ctrl(0x8700_1234);
+    assert_eq!(ctrl.get_raw(0), Some(0));
+    assert_eq!(ctrl.get_raw(1), Some(3));
+    assert_eq!(ctrl.get_raw(2), Some(0x12));
+    assert_eq!(ctrl.get_raw(3), Some(0x87));
+    assert_eq!(ctrl.get_raw(5), None);
+
+    assert!(ctrl.set_raw(1, 0x1F));
+    assert_eq!(ctrl.0, 0x8700_12F4);
+    assert!(!ctrl.set_raw(5, 0));
+    assert_eq!(Ctrl::field_index("sub"), Some(3));
+}
+
+#[test]
+fn raw_access_by_name() {
+    let mut ctrl = Ctrl(0x8700_0001);
+    assert_eq!(ctrl.get_raw_by_name("enable"), Some(1));
+    assert_eq!(ctrl.get_raw_by_name("sub"), Some(0x87));
+    assert_eq!(ctrl.get_raw_by_name("sub.low"), Some(7));
+    assert_eq!(ctrl.get_raw_by_name("sub.flag"), Some(1));
+    assert_eq!(ctrl.get_raw_by_name("sub.missing"), None);
+    assert_eq!(ctrl.get_raw_by_name("enable.low"), None);
+    assert_eq!(ctrl.get_raw_by_name("missing"), None);
+
+    assert!(ctrl.set_raw_by_name("sub.low", 0xA));
+    assert!(ctrl.set_raw_by_name("sub.flag", 0));
+    assert_eq!(ctrl.0, 0x0A00_0001);
+    assert!(!ctrl.set_raw_by_name("sub.missing", 0));
+    assert_eq!(ctrl.0, 0x0A00_0001);
+
+    let mut wide = Wide([0x12, 0x34, 0x56]);
+    assert_eq!(wide.get_raw_by_name("top"), Some(0x563));
+    assert!(wide.set_raw_by_name("bottom", 0xFED));
+    assert_eq!(wide.0, [0xED, 0x3F, 0x56]);
+}
+
+#[test]
+fn generic_storage() {
+    let mut value = Generic(0x8F05_u16, core::marker::PhantomData);
+    assert_eq!(Generic::<u16>::FIELDS.len(), 2);
+    assert_eq!(value.get_raw_by_name("sub.low"), Some(0xF));
+    assert!(value.set_raw_by_name("low", 3));
+    assert_eq!(value.0, 0x8F03);
+}
@@ -0,0 +1,138 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::{
+    bitfield, register_map,
+    register_map::{Access, MockBackend, RegisterAccess},
+};
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Control(pub u32): Debug, FromStorage, IntoStorage {
+        pub enable: bool @ 0,
+        pub mode: u8 @ 4..6,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Status(pub u16): Debug, FromStorage, IntoStorage {
+        pub busy: bool @ 0,
+        pub error: bool @ 1,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Data(pub u8): Debug, FromStorage, IntoStorage {
+        pub value: u8 @ ..,
+    }
+}
+
+register_map! {
+    pub struct Peripheral {
+        pub control: Control @ 0x00,
+        pub status: Status [read_only] @ 0x04,
+        pub command: Data [write_only] @ 0x06,
+        pub fifo: [Data; 4] @ 0x08,
+        pub channels: [Control; 2] @ 0x10; 8,
+    }
+}
+
+#[test]
+fn register_map_mock_backend() {
+    let mut peripheral = Peripheral::new(MockBackend::<0x20>::new(), 0);
+    assert_eq!(peripheral.base(), 0);
+
+    peripheral.write_control(Control(0).with_enable(true));
+    peripheral.modify_control(|control| control.with_mode(2));
+    assert_eq!(peripheral.read_control(), Control(0x21));
+
+    peripheral.backend_mut().poke::<u16>(0x04, 3);
+    assert!(peripheral.read_status().error());
+    peripheral.write_command(Data(0xAA));
+    peripheral.write_fifo(3, Data(0x55));
+    peripheral.write_channels(1, Control(0).with_mode(1));
+
+    let backend = peripheral.into_backend();
+    assert_eq!(
+        backend.accesses(),
+        [
+            Access::write(0x00, 4, 0x01),
+            Access::read(0x00, 4, 0x01),
+            Access::write(0x00, 4, 0x21),
+            Access::read(0x00, 4, 0x21),
+            Access::read(0x04, 2, 0x03),
+            Access::write(0x06, 1, 0xAA),
+            Access::write(0x0B, 1, 0x55),
+            Access::write(0x18, 4, 0x10),
+        ]
+    );
+    assert_eq!(backend.memory()[0x0B], 0x55);
+    assert_eq!(backend.peek::<u32>(0x18), 0x10);
+}
+
+#[test]
+fn register_map_base_address_and_log_capacity() {
+    let mut peripheral = Peripheral::new(MockBackend::<0x40, 2>::new(), 0x20);
+    peripheral.write_fifo(0, Data(1));
+    peripheral.write_fifo(1, Data(2));
+    peripheral.write_fifo(2, Data(3));
+    assert_eq!(
+        peripheral.backend().accesses(),
+        [Access::write(0x28, 1, 1), Access::write(0x29, 1, 2)]
+    );
+    assert_eq!(peripheral.backend().dropped_accesses(), 1);
+    assert_eq!(peripheral.backend().memory()[0x2A], 3);
+
+    peripheral.backend_mut().clear_accesses();
+    assert!(peripheral.backend().accesses().is_empty());
+}
+
+/// A backend only supporting byte accesses, like many I2C devices, where a register's bytes are
+/// transferred in big-endian order.
+#[derive(Default)]
+struct ByteDevice {
+    regs: [u8; 0x20],
+}
+
+impl RegisterAccess<u8> for ByteDevice {
+    fn read(&mut self, addr: usize) -> u8 {
+        self.regs[addr]
+    }
+
+    fn write(&mut self, addr: usize, value: u8) {
+        self.regs[addr] = value;
+    }
+}
+
+impl RegisterAccess<u16> for ByteDevice {
+    fn read(&mut self, addr: usize) -> u16 {
+        u16::from_be_bytes([self.regs[addr], self.regs[addr + 1]])
+    }
+
+    fn write(&mut self, addr: usize, value: u16) {
+        self.regs[addr..addr + 2].copy_from_slice(&value.to_be_bytes());
+    }
+}
+
+#[test]
+fn register_map_custom_backend() {
+    let mut peripheral = Peripheral::new(ByteDevice::default(), 0);
+    peripheral.write_command(Data(0x12));
+    peripheral.write_fifo(1, Data(0x34));
+    assert_eq!(peripheral.backend().regs[0x06], 0x12);
+    assert_eq!(peripheral.backend().regs[0x09], 0x34);
+
+    peripheral.backend_mut().regs[0x04..0x06].copy_from_slice(&[0x00, 0x01]);
+    assert!(peripheral.read_status().busy());
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds for register array `fifo`")]
+fn register_map_out_of_bounds() {
+    Peripheral::new(MockBackend::<0x20>::new(), 0).write_fifo(4, Data(0));
+}
@@ -0,0 +1,103 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::{bitfield, FixedBitsError};
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Ctrl(pub u32): Debug, FromStorage, Bytes, Volatile {
+        pub enable: bool @ 0,
+        pub mode: u8 @ 4..8,
+        _: reserved @ 12..16,
+        pub irq: bool [w1c] @ 16,
+        _: fixed = 1 @ 20,
+        _: fixed = 0b10 @ 30..32,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Status(pub u8): Debug, TryFromStorage {
+        pub code: u8 @ 0..4,
+        _: fixed = 0b11 @ 6..8,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Header(pub [u8; 2]): Debug, TryFromStorage {
+        pub version: u8 @ 0..4,
+        _: fixed = 0b101 @ 4..7,
+        _: reserved @ 7,
+        pub length: u8 @ 8..16,
+    }
+}
+
+#[test]
+fn masks() {
+    assert_eq!(Ctrl::RESERVED_MASK, 0x0000_F000);
+    assert_eq!(Ctrl::FIXED_MASK, 0xC010_0000);
+    assert_eq!(Ctrl::FIXED_VALUE, 0x8010_0000);
+    assert_eq!(Ctrl::WRITABLE_MASK, 0x0000_00F1);
+    assert_eq!(Ctrl::W1C_MASK, 0x0001_0000);
+
+    assert_eq!(Status::RESERVED_MASK, 0);
+    assert_eq!(Status::FIXED_MASK, 0xC0);
+    assert_eq!(Status::FIXED_VALUE, 0xC0);
+}
+
+#[test]
+fn setters_force_fixed_bits() {
+    assert_eq!(Ctrl(0).with_enable(true).0, 0x8010_0001);
+    assert_eq!(Ctrl(0).with_clear_irq().0, 0x8011_0000);
+    assert_eq!(Ctrl(0xFFFF_FFFF).with_mode(3).0, 0xBFFE_0F3F);
+
+    let mut ctrl = Ctrl(0x0000_F000);
+    ctrl.set_mode(3);
+    assert_eq!(ctrl.0, 0x8010_0030);
+
+    assert_eq!(Header([0xFF, 0xFF]).with_length(7).0, [0x5F, 0x07]);
+    assert_eq!(Status(0).with_code(5).0, 0xC5);
+}
+
+#[test]
+fn constructors_force_fixed_bits() {
+    assert_eq!(Ctrl::from(0xFFFF_FFFF).0, 0xBFFF_0FFF);
+    assert_eq!(Ctrl::from_be_bytes([0xFF; 4]).0, 0xBFFF_0FFF);
+    assert_eq!(Ctrl::from_le_bytes([0; 4]).0, 0x8010_0000);
+}
+
+#[test]
+fn try_from_storage() {
+    assert_eq!(Status::try_from(0xC5), Ok(Status(0xC5)));
+    assert_eq!(Status::try_from(0x45), Err(FixedBitsError::new(0x45)));
+    assert_eq!(Status::try_from(0x45).unwrap_err().into_storage(), 0x45);
+
+    assert_eq!(Header::try_from([0x53, 3]), Ok(Header([0x53, 3])));
+    // Reserved bits aren't checked, but are cleared
+    assert_eq!(Header::try_from([0xD3, 3]), Ok(Header([0x53, 3])));
+    assert_eq!(
+        Header::try_from([0x33, 0]),
+        Err(FixedBitsError::new([0x33, 0]))
+    );
+}
+
+#[test]
+fn volatile() {
+    let storage = Box::into_raw(Box::new(0xFFFF_FFFF_u32));
+    let reg = unsafe { CtrlReg::new(storage) };
+
+    assert_eq!(reg.read(), Ctrl(0xBFFF_0FFF));
+
+    // Write-1-to-clear bits aren't written back
+    reg.modify(|value| value);
+    assert_eq!(unsafe { *storage }, 0xBFFE_0FFF);
+
+    reg.write_enable(true);
+    assert_eq!(unsafe { *storage }, 0x8010_0001);
+
+    drop(unsafe { Box::from_raw(storage) });
+}
@@ -0,0 +1,75 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::bitfield;
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Ctrl(pub u16): Default = 0x1F00, Unpacked {
+        pub enable: bool @ 0,
+        pub mode: u8 = 3 @ 1..3,
+        pub offset: i8 = -2 @ 4..8,
+        pub prio: u8 [2] = 1 @ 8; 2,
+        pub status: u8 [read_only] @ 12..16,
+        _: fixed = 1 @ 3,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Header(pub u8): Msb0, New, Builder {
+        pub version: u8 = 2 @ 0..2,
+        pub flag: bool = true @ 2,
+        pub length: u8 @ 3..8,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Plain(pub u8): Default {
+        pub low: u8 @ 0..4,
+    }
+}
+
+#[test]
+fn reset_value() {
+    assert_eq!(Ctrl::RESET.0, 0x15EE);
+    assert_eq!(Ctrl::default(), Ctrl::RESET);
+    assert!(!Ctrl::RESET.enable());
+    assert_eq!(Ctrl::RESET.mode(), 3);
+    assert_eq!(Ctrl::RESET.offset(), -2);
+    assert_eq!(Ctrl::RESET.prio(1), 1);
+    assert_eq!(Ctrl::RESET.status(), 1);
+
+    assert_eq!(Header::RESET.0, 0xA0);
+    assert_eq!(Plain::default().0, 0);
+}
+
+#[test]
+fn reset_fns() {
+    let mut ctrl = Ctrl(0xFFFF);
+    ctrl.reset_mode();
+    assert_eq!(ctrl.0, 0xFFFF);
+    ctrl.reset_prio();
+    assert_eq!(ctrl.0, 0xF5FF);
+    ctrl.reset_enable();
+    assert_eq!(ctrl.0, 0xF5FE);
+}
+
+#[test]
+fn constructors_start_from_reset() {
+    assert_eq!(Header::new(1, false, 5).0, 0x45);
+    // Fields with default values don't have to be set
+    assert_eq!(Header::builder().length(5).build().0, 0xA5);
+    assert_eq!(Header::builder().flag(false).length(5).build().0, 0x85);
+}
+
+#[test]
+fn unpacked_starts_from_reset() {
+    // The read-only `status` field isn't packed, so it keeps its reset value
+    let unpacked = CtrlUnpacked::from(Ctrl(0));
+    assert_eq!(Ctrl::from(unpacked).0, 0x1008);
+}
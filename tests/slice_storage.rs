@@ -0,0 +1,90 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::bitfield;
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Flags(pub u8): Debug {
+        pub ack: bool @ 0,
+        pub syn: bool @ 1,
+        pub kind: u8 @ 4..8,
+    }
+}
+
+bitfield! {
+    #[derive(PartialEq, Eq)]
+    pub struct Header(pub [u8]): Debug, DerefStorage {
+        pub version: u8 [read_only] @ 0..4,
+        pub error: bool [w1c] @ 4,
+        _: reserved @ 5..8,
+        pub length: u16 @ 8..24,
+        pub flags: nested Flags @ 24..32,
+        pub checksum: u16 @ 36..48,
+        pub lanes: u8 [2] @ 48; 4,
+    }
+}
+
+bitfield! {
+    pub struct Words(pub [u32]) {
+        pub low: u32 @ 0..32,
+        pub straddling: u16 @ 56..72,
+    }
+}
+
+#[test]
+fn length_checks() {
+    assert_eq!(Header::MIN_LEN, 7);
+    assert_eq!(Words::MIN_LEN, 3);
+
+    let mut buf = [0_u8; 16];
+    assert!(Header::from_slice(&buf[..6]).is_none());
+    assert!(Header::from_mut_slice(&mut buf[..6]).is_none());
+    // Trailing bytes, such as a packet's payload, are still accessible through the storage
+    let header = Header::from_slice(&buf[..10]).unwrap();
+    assert_eq!(header.0.len(), 10);
+}
+
+#[test]
+fn in_place_accesses() {
+    let mut packet = [0x13, 0x34, 0x12, 0x21, 0xF0, 0x5A, 0xC7, 0xFF];
+    let header = Header::from_slice(&packet).unwrap();
+    assert_eq!(header.version(), 3);
+    assert!(header.error());
+    assert_eq!(header.length(), 0x1234);
+    assert_eq!(header.flags(), Flags(0x21));
+    assert_eq!(header.checksum(), 0x5AF);
+    assert_eq!(header.lanes(0), 7);
+    assert_eq!(header.lanes(1), 0xC);
+
+    let header = Header::from_mut_slice(&mut packet).unwrap();
+    header.set_length(0xBEEF);
+    header.set_flags(Flags(0).with_syn(true));
+    header.set_lanes(1, 1);
+    // Setters of other fields don't write back the w1c bit, and reserved bits are cleared
+    assert_eq!(packet, [0x03, 0xEF, 0xBE, 0x02, 0xF0, 0x5A, 0x17, 0xFF]);
+
+    let header = Header::from_mut_slice(&mut packet).unwrap();
+    header.clear_error();
+    assert_eq!(packet[0], 0x13);
+
+    let mut words = [0x1111_1111, 0x89AB_CDEF, 0x0123_4567];
+    let words_ = Words::from_mut_slice(&mut words).unwrap();
+    assert_eq!(words_.low(), 0x1111_1111);
+    assert_eq!(words_.straddling(), 0x6789);
+    words_.set_straddling(0xABCD);
+    assert_eq!(words, [0x1111_1111, 0xCDAB_CDEF, 0x0123_45AB]);
+}
+
+#[test]
+fn debug() {
+    let packet = [0x02, 0x01, 0x00, 0x10, 0x00, 0x00, 0x00];
+    assert_eq!(
+        format!("{:?}", Header::from_slice(&packet).unwrap()),
+        "Header { 0: [2, 1, 0, 16, 0, 0, 0], version: 2, error: false, length: 1, \
+         flags: Flags { 0: 16, ack: false, syn: false, kind: 1 }, checksum: 0, lanes: [0, 0] }"
+    );
+}
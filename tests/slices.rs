@@ -0,0 +1,59 @@
+use proc_bitfield::{bits, set_bits, BitRangeError, Bits, TryBits, TrySetBits};
+
+#[test]
+fn slices_rw_ints() {
+    let mut storage = [0x21_u8, 0x43, 0x65];
+    let slice = &mut storage[..];
+    assert_eq!(bits!(*slice, u8 @ 4; 8), 0x32);
+    assert_eq!(Bits::<u16>::bits::<8, 24>(slice), 0x6543);
+    set_bits!(*slice, u8 @ 12..20 = 0xA9);
+    assert_eq!(storage, [0x21, 0x93, 0x6A]);
+}
+
+#[test]
+fn slices_try_rw_ints() {
+    let mut storage = [0x21_u8, 0x43, 0x65];
+    let slice = &mut storage[..];
+    assert_eq!(TryBits::<u8>::try_bits::<16, 24>(slice), Ok(0x65));
+    assert_eq!(
+        TryBits::<u8>::try_bits::<20, 28>(slice),
+        Err(BitRangeError::new(20, 28, 24))
+    );
+    assert_eq!(
+        TryBits::<[u8; 2]>::try_bits::<4, 20>(slice),
+        Ok([0x32, 0x54])
+    );
+    assert!(TryBits::<[u8; 2]>::try_bits::<12, 28>(slice).is_err());
+
+    assert_eq!(TrySetBits::<u8>::try_set_bits::<20, 24>(slice, 0xF), Ok(()));
+    assert_eq!(
+        TrySetBits::<u16>::try_set_bits::<16, 32>(slice, 0xFFFF),
+        Err(BitRangeError::new(16, 32, 24))
+    );
+    assert_eq!(
+        TrySetBits::<[u8; 2]>::try_set_bits::<0, 12>(slice, [0xDC, 0xE]),
+        Ok(())
+    );
+    // Out-of-bounds writes leave the slice untouched
+    assert_eq!(storage, [0xDC, 0x4E, 0xF5]);
+
+    let empty: &mut [u32] = &mut [];
+    assert_eq!(
+        TryBits::<u8>::try_bits::<0, 1>(empty).err(),
+        Some(BitRangeError::new(0, 1, 0))
+    );
+    assert!(TrySetBits::<u8>::try_set_bits::<0, 1>(empty, 1).is_err());
+}
+
+#[test]
+fn bit_range_error() {
+    let error = BitRangeError::new(20, 28, 24);
+    assert_eq!(
+        (error.start(), error.end(), error.storage_bits()),
+        (20, 28, 24)
+    );
+    assert_eq!(
+        error.to_string(),
+        "invalid bit range 20..28 for 24-bit storage"
+    );
+}
@@ -0,0 +1,162 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::{bitfield, FieldConversionError, Unpack};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Off,
+    On,
+    Auto,
+}
+
+impl TryFrom<u8> for Mode {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Mode::Off),
+            1 => Ok(Mode::On),
+            2 => Ok(Mode::Auto),
+            _ => Err(value),
+        }
+    }
+}
+
+impl From<Mode> for u8 {
+    fn from(value: Mode) -> Self {
+        value as u8
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Even(u8);
+
+impl TryFrom<Even> for u8 {
+    type Error = ();
+
+    fn try_from(value: Even) -> Result<Self, ()> {
+        if value.0 & 1 == 0 {
+            Ok(value.0)
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl From<u8> for Even {
+    fn from(value: u8) -> Self {
+        Even(value)
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Sub(pub u8): Unpacked {
+        pub low: u8 @ 0..4,
+        pub high: u8 @ 4..8,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Ctrl(pub u32): Unpacked {
+        /// Enables the device.
+        pub enable: bool @ 0,
+        pub status: u8 [read_only] @ 4..8,
+        pub start: bool [write_only] @ 1,
+        pub irq: bool [w1c] @ 2,
+        pub prio: u8 [2] @ 8; 2,
+        pub sub: nested Sub @ 16..24,
+        _: fixed = 0b11 @ 30..32,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Config(pub u16): Unpacked {
+        pub mode: u8 [try Mode] @ 0..2,
+        pub even: u8 [get Even, try_set Even] @ 8..16,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Command(pub u8): Unpacked {
+        read {
+            pub busy: bool @ 0,
+        }
+        write {
+            pub start: bool @ 0,
+            pub opcode: u8 @ 4..8,
+        }
+    }
+}
+
+#[test]
+fn round_trip() {
+    let ctrl = Ctrl(0xC0A5_2351);
+    let unpacked = CtrlUnpacked::from(ctrl);
+    assert_eq!(
+        unpacked,
+        CtrlUnpacked {
+            enable: true,
+            status: 5,
+            start: None,
+            irq: false,
+            prio: [3, 0],
+            sub: SubUnpacked { low: 5, high: 0xA },
+        }
+    );
+
+    // Read-only fields and fields with side effects aren't written back
+    let ctrl = Ctrl::from(CtrlUnpacked {
+        start: Some(true),
+        ..unpacked
+    });
+    assert_eq!(ctrl.0, 0xC0A5_0303);
+}
+
+#[test]
+fn fallible_conversions() {
+    let unpacked = ConfigUnpacked::try_from(Config(0x0402)).unwrap();
+    assert_eq!(
+        unpacked,
+        ConfigUnpacked {
+            mode: Mode::Auto,
+            even: Even(4),
+        }
+    );
+    assert_eq!(Config::try_from(unpacked), Ok(Config(0x0402)));
+
+    let error = ConfigUnpacked::try_from(Config(0x0003)).unwrap_err();
+    assert_eq!(error.field(), "mode");
+    assert_eq!(
+        Config::try_from(ConfigUnpacked {
+            mode: Mode::On,
+            even: Even(3),
+        }),
+        Err(FieldConversionError::new("even"))
+    );
+}
+
+#[test]
+fn split_layouts() {
+    fn unpacked_of<B: Unpack>(_: B) -> core::marker::PhantomData<B::Unpacked> {
+        core::marker::PhantomData
+    }
+    let _: core::marker::PhantomData<CommandUnpacked> = unpacked_of(Command(0));
+
+    assert_eq!(
+        CommandUnpacked::from(Command(0xF1)),
+        CommandUnpacked { busy: true }
+    );
+    let write = CommandWrite::from(CommandWriteUnpacked {
+        start: Some(true),
+        opcode: Some(7),
+    });
+    assert_eq!(write.0, 0x71);
+}
@@ -0,0 +1,115 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::{bitfield, BitRangeError};
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Inner(pub u8): Views {
+        pub flag: bool @ 0,
+        pub value: u8 @ 1..5,
+        pub error: bool [w1c] @ 7,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Outer(pub u32): Views {
+        pub low: u8 @ 0..8,
+        pub inner: nested Inner @ 8..16,
+        pub short: nested Inner @ 16..20,
+        pub ro_inner: nested Inner [read_only] @ 24..32,
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Words(pub u16): Views {
+        pub lanes: u8 [2] @ 0; 4,
+        pub top: u8 @ 12..16,
+    }
+}
+
+// With the `gce` feature, the bitfield's own `x_ref()` and `x_mut()` accessors return its nested
+// bitfield types instead of views
+#[test]
+#[cfg(not(feature = "gce"))]
+fn nested_views() {
+    let mut outer = Outer(0);
+    outer.inner_mut().set_value(0xA);
+    outer.inner_mut().set_flag(true);
+    assert_eq!(outer.0, 0x0000_1500);
+    assert_eq!(outer.inner_ref().value(), 0xA);
+    assert_eq!(outer.inner_ref().get(), Inner(0x15));
+
+    let mut inner = outer.inner_mut();
+    inner.modify(|inner| inner.with_flag(false));
+    assert_eq!(inner.bit_range(), 8..16);
+    assert_eq!(inner.as_ref_view().get(), Inner(0x14));
+    assert_eq!(outer.0, 0x0000_1400);
+
+    // Writes go through the nested bitfield's setters, so write-1-to-clear bits are only written
+    // when clearing them
+    outer.0 |= 0x80 << 8;
+    outer.inner_mut().set_value(1);
+    assert_eq!(outer.0, 0x0000_0200);
+    outer.0 |= 0x80 << 8;
+    outer.inner_mut().clear_error();
+    assert_eq!(outer.0, 0x0000_8200);
+}
+
+#[test]
+#[cfg(not(feature = "gce"))]
+fn truncated_nested_views() {
+    // Only the low 4 bits of `short` are stored, like through its getter and setters
+    let mut outer = Outer(0);
+    outer.short_mut().set_value(0xF);
+    assert_eq!(outer.0, 0x000E_0000);
+    assert_eq!(outer.short_mut().value(), 0x7);
+    assert_eq!(outer.short_ref().get(), outer.short());
+    outer.short_mut().set(Inner(0xFF));
+    assert_eq!(outer.0, 0x000F_0000);
+    assert_eq!(outer.short_ref().bit_range(), 16..20);
+
+    let outer = Outer(0xAB00_0000);
+    assert_eq!(outer.ro_inner_ref().get(), Inner(0xAB));
+}
+
+#[test]
+fn views_of_views() {
+    let mut buf = [0u32; 4];
+    let mut outer = OuterMut::new(&mut buf[..], 64).unwrap();
+    outer.set_low(0x12);
+    outer.inner_mut().set_value(3);
+    assert_eq!(outer.inner_ref().bit_range(), 72..80);
+    assert_eq!(outer.get(), Outer(0x0000_0612));
+    assert_eq!(buf, [0, 0, 0x0000_0612, 0]);
+
+    let outer = OuterRef::new(&buf[..], 64).unwrap();
+    assert_eq!(outer.inner_ref().value(), 3);
+    assert_eq!(outer.inner(), Inner(6));
+    assert_eq!(
+        OuterRef::new(&buf[..], 100).err(),
+        Some(BitRangeError::new(100, 132, 128))
+    );
+}
+
+#[test]
+fn storage_views() {
+    let mut words = [0u16; 3];
+    WordsMut::from_storage(&mut words[1]).set_lanes(1, 0xC);
+    WordsMut::from_storage(&mut words[2]).set_top(5);
+    assert_eq!(words, [0, 0x00C0, 0x5000]);
+    assert_eq!(WordsRef::from_storage(&words[1]).lanes(1), 0xC);
+
+    // Unaligned views straddling words
+    let mut view = WordsMut::new(&mut words[..], 8).unwrap();
+    view.set_lanes(0, 0x3);
+    assert_eq!(words, [0x0300, 0x00C0, 0x5000]);
+    let view = WordsRef::new(&words[..], 8).unwrap();
+    assert_eq!(view.get(), Words(0xC003));
+    assert_eq!(view.top(), 0xC);
+}
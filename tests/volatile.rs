@@ -0,0 +1,87 @@
+#![cfg_attr(
+    feature = "gce",
+    feature(const_trait_impl, generic_const_exprs),
+    expect(incomplete_features)
+)]
+
+use proc_bitfield::bitfield;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Prescaler {
+    Div1,
+    Div8,
+    Div64,
+}
+
+impl TryFrom<u8> for Prescaler {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, u8> {
+        match value {
+            0 => Ok(Prescaler::Div1),
+            1 => Ok(Prescaler::Div8),
+            2 => Ok(Prescaler::Div64),
+            _ => Err(value),
+        }
+    }
+}
+
+impl From<Prescaler> for u8 {
+    fn from(value: Prescaler) -> Self {
+        value as u8
+    }
+}
+
+bitfield! {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct TimerCtrl(pub u32): Debug, Volatile {
+        pub enable: bool @ 0,
+        pub prescaler: u8 [try_both Prescaler] @ 1..3,
+        pub irq_enable: bool [write_only] @ 3,
+        pub busy: bool [read_only] @ 4,
+        pub channels: u8 [2] @ 8; 4,
+        pub reload: u16 @ 16..32,
+    }
+}
+
+#[test]
+fn volatile_read_write() {
+    let storage = Box::into_raw(Box::new(0_u32));
+    let reg = unsafe { TimerCtrlReg::new(storage) };
+    assert_eq!(reg.as_ptr(), storage);
+
+    reg.write(TimerCtrl(0).with_enable(true).with_reload(0x1234));
+    assert_eq!(unsafe { *storage }, 0x1234_0001);
+    assert_eq!(reg.read(), TimerCtrl(0x1234_0001));
+
+    reg.modify(|value| value.with_channels(1, 0xA));
+    assert_eq!(unsafe { *storage }, 0x1234_A001);
+
+    drop(unsafe { Box::from_raw(storage) });
+}
+
+#[test]
+fn volatile_field_accessors() {
+    let storage = Box::into_raw(Box::new(0x0000_0014_u32));
+    let reg = unsafe { TimerCtrlReg::new(storage) };
+
+    assert!(!reg.read_enable());
+    assert!(reg.read_busy());
+    assert_eq!(reg.read_prescaler(), Ok(Prescaler::Div64));
+    assert_eq!(reg.read_channels(0), 0);
+
+    // Single field writes don't read the register first, so other bits are written as zero
+    reg.write_reload(0xFFFF);
+    assert_eq!(unsafe { *storage }, 0xFFFF_0000);
+
+    reg.write_channels(1, 0x5);
+    assert_eq!(unsafe { *storage }, 0x0000_5000);
+
+    reg.write_irq_enable(true);
+    assert_eq!(unsafe { *storage }, 0x0000_0008);
+
+    assert_eq!(reg.write_prescaler(Prescaler::Div8), Ok(()));
+    assert_eq!(unsafe { *storage }, 0x0000_0002);
+
+    drop(unsafe { Box::from_raw(storage) });
+}